@@ -1,32 +1,171 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use config_better::Config as CBConfig;
 use thiserror::Error;
+use ugg_types::stats::StatSettings;
+
+mod session;
+mod settings;
+
+pub use session::Session;
+pub use settings::{
+    BUILD_ENV, KEYMAP_ENV, LOCALE_ENV, LOW_SAMPLE_SIZE_ENV, MODE_ENV, PATCH_ENV, PRIOR_GAMES_ENV,
+    Pool, RANK_ENV, REGION_ENV, ROLE_ENV, Setting, Settings, THEME_ENV, parse_patch,
+};
 
 #[derive(Error, Debug)]
 pub enum ConfigError {
     #[error("Could not create app directories.")]
     CouldNotMakeDirs,
+    #[error("Could not read {}: {source}", path.display())]
+    ReadFile {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Could not parse {}: {message}", path.display())]
+    ParseFile { path: PathBuf, message: String },
+    #[error("Invalid {key} \"{value}\" in {origin}, expected {expected}")]
+    InvalidValue {
+        key: &'static str,
+        value: String,
+        origin: String,
+        expected: String,
+    },
+    #[error("Could not write {}: {source}", path.display())]
+    WriteFile {
+        path: PathBuf,
+        source: std::io::Error,
+    },
 }
 
+/// Environment variable pointing at a config file to use instead of the
+/// default `config.toml`.
+pub const CONFIG_PATH_ENV: &str = "UGGO_CONFIG";
+
 #[derive(Clone)]
 pub struct Config {
     inner: CBConfig,
+    /// The config file's settings, kept apart so a session can be restored
+    /// between them and the environment.
+    file: Settings,
+    /// Settings from the environment and command line.
+    explicit: Settings,
+    settings: Settings,
+    session: Session,
 }
 
 impl Config {
+    /// Loads `config.toml` and the environment, without any overrides.
     pub fn new() -> Result<Self, ConfigError> {
+        Self::load(None, Settings::default())
+    }
+
+    /// Layers the config file, then the `UGGO_*` environment variables, then
+    /// `overrides`. An explicitly given file has to exist; the default one
+    /// doesn't.
+    pub fn load(path: Option<&Path>, overrides: Settings) -> Result<Self, ConfigError> {
         let config = CBConfig::new("uggo");
 
         config
             .create_all()
             .map_err(|_| ConfigError::CouldNotMakeDirs)?;
 
-        Ok(Self { inner: config })
+        let env_path = std::env::var_os(CONFIG_PATH_ENV)
+            .filter(|p| !p.is_empty())
+            .map(PathBuf::from);
+        let file = match path.map(Path::to_path_buf).or(env_path) {
+            Some(path) => Settings::from_file(&path, true)?,
+            None => Settings::from_file(&config.config.path.join("config.toml"), false)?,
+        };
+        let explicit = Settings::from_env()?.merge(overrides);
+
+        Ok(Self {
+            inner: config,
+            settings: file.clone().merge(explicit.clone()),
+            file,
+            explicit,
+            session: Session::default(),
+        })
+    }
+
+    /// Restores the last session on top of the config file. Environment
+    /// variables and flags still take priority, and so does any setting
+    /// changed in the config file since the session was saved.
+    pub fn restore_session(&mut self) {
+        if self.settings.restore_session == Some(false) {
+            return;
+        }
+        let (session, saved) = Session::load(&self.session_path());
+        self.settings = self
+            .file
+            .clone()
+            .merge(session.settings(&self.file, &saved))
+            .merge(self.explicit.clone());
+        self.session = session;
+    }
+
+    /// The restored session, empty unless [`Config::restore_session`] was
+    /// called.
+    #[must_use]
+    pub const fn session(&self) -> &Session {
+        &self.session
+    }
+
+    pub fn save_session(&self, session: &Session) -> Result<(), ConfigError> {
+        if self.settings.restore_session == Some(false) {
+            return Ok(());
+        }
+        session.store(&self.session_path(), &self.file)
+    }
+
+    fn session_path(&self) -> PathBuf {
+        self.inner.config.path.join("session.toml")
+    }
+
+    /// Where the default `config.toml` lives.
+    #[must_use]
+    pub fn file_path(&self) -> PathBuf {
+        self.inner.config.path.join("config.toml")
+    }
+
+    /// Every setting after layering.
+    #[must_use]
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
     }
 
     #[must_use]
     pub fn cache(&self) -> &PathBuf {
         &self.inner.cache.path
     }
+
+    /// The ddragon locale to use, e.g. `ko_KR` or `pt_BR`.
+    #[must_use]
+    pub fn locale(&self) -> Option<&str> {
+        self.settings.locale.as_deref()
+    }
+
+    /// Overviews with fewer games than this are flagged as low sample size.
+    #[must_use]
+    pub fn low_sample_size(&self) -> Option<i64> {
+        self.settings.low_sample_size
+    }
+
+    /// How many games of average results are blended into win rates before
+    /// sorting, so tiny samples don't float to the top.
+    #[must_use]
+    pub fn prior_games(&self) -> Option<f64> {
+        self.settings.prior_games
+    }
+
+    /// The win rate thresholds, with anything unset left at its default.
+    #[must_use]
+    pub fn stat_settings(&self) -> StatSettings {
+        let defaults = StatSettings::default();
+        StatSettings {
+            low_sample_size: self.low_sample_size().unwrap_or(defaults.low_sample_size),
+            prior_matches: self.prior_games().unwrap_or(defaults.prior_matches),
+            ..defaults
+        }
+    }
 }
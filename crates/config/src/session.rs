@@ -0,0 +1,208 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use ugg_types::mappings::{Build, Mode, Region, Role};
+
+use crate::{ConfigError, Setting, Settings};
+
+/// What was on screen when uggo last exited, restored on the next start.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Session {
+    /// The ddragon id of the selected champion, e.g. `MonkeyKing`.
+    pub champion: Option<String>,
+    pub mode: Option<Mode>,
+    pub region: Option<Region>,
+    pub role: Option<Role>,
+    pub build: Option<Build>,
+    /// The patch that was picked, only kept when it wasn't the latest one.
+    pub patch: Option<String>,
+    pub show_left_pane: Option<bool>,
+}
+
+/// The layout of `session.toml`.
+#[derive(Serialize, Deserialize, Default)]
+struct SessionFile {
+    champion: Option<String>,
+    mode: Option<String>,
+    region: Option<String>,
+    role: Option<String>,
+    build: Option<String>,
+    patch: Option<String>,
+    show_left_pane: Option<bool>,
+    /// The config file's values when the session was saved, to tell when
+    /// they've been changed since.
+    #[serde(default)]
+    config: ConfigFile,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct ConfigFile {
+    mode: Option<String>,
+    region: Option<String>,
+    role: Option<String>,
+    build: Option<String>,
+    show_left_pane: Option<bool>,
+}
+
+impl Session {
+    /// Reads a saved session. uggo writes this file itself, so anything that
+    /// can't be understood (say, after an upgrade) is dropped rather than
+    /// stopping uggo from starting.
+    #[must_use]
+    pub fn from_file(path: &Path) -> Self {
+        Self::load(path).0
+    }
+
+    /// Reads a saved session along with the config file's settings at the
+    /// time it was saved.
+    pub(crate) fn load(path: &Path) -> (Self, Settings) {
+        let file = std::fs::read_to_string(path)
+            .ok()
+            .and_then(|text| toml::from_str::<SessionFile>(&text).ok())
+            .unwrap_or_default();
+        let origin = path.display().to_string();
+        let non_empty = |value: Option<String>| value.filter(|v| !v.is_empty());
+        let mode =
+            |v: Option<String>| non_empty(v).and_then(|v| Mode::parse_setting(&v, &origin).ok());
+        let region =
+            |v: Option<String>| non_empty(v).and_then(|v| Region::parse_setting(&v, &origin).ok());
+        let role =
+            |v: Option<String>| non_empty(v).and_then(|v| Role::parse_setting(&v, &origin).ok());
+        let build =
+            |v: Option<String>| non_empty(v).and_then(|v| Build::parse_setting(&v, &origin).ok());
+
+        let session = Self {
+            champion: non_empty(file.champion),
+            mode: mode(file.mode),
+            region: region(file.region),
+            role: role(file.role),
+            build: build(file.build),
+            patch: non_empty(file.patch).and_then(|v| crate::parse_patch(&v, &origin).ok()),
+            show_left_pane: file.show_left_pane,
+        };
+        let config = Settings {
+            mode: mode(file.config.mode),
+            region: region(file.config.region),
+            role: role(file.config.role),
+            build: build(file.config.build),
+            show_left_pane: file.config.show_left_pane,
+            ..Settings::default()
+        };
+        (session, config)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ConfigError> {
+        self.store(path, &Settings::default())
+    }
+
+    /// Saves the session along with the config file's `config` settings, so
+    /// a later change to the file can win over the session.
+    pub(crate) fn store(&self, path: &Path, config: &Settings) -> Result<(), ConfigError> {
+        let file = SessionFile {
+            champion: self.champion.clone(),
+            mode: self.mode.map(|v| v.to_string()),
+            region: self.region.map(|v| v.to_string()),
+            role: self.role.map(|v| v.to_string()),
+            build: self.build.map(|v| v.to_string()),
+            patch: self.patch.clone(),
+            show_left_pane: self.show_left_pane,
+            config: ConfigFile {
+                mode: config.mode.map(|v| v.to_string()),
+                region: config.region.map(|v| v.to_string()),
+                role: config.role.map(|v| v.to_string()),
+                build: config.build.map(|v| v.to_string()),
+                show_left_pane: config.show_left_pane,
+            },
+        };
+        let text = toml::to_string(&file).map_err(|e| ConfigError::ParseFile {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+        std::fs::write(path, text).map_err(|source| ConfigError::WriteFile {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// The parts of the session that stand in for settings, leaving out any
+    /// the config file changed since the session was saved with `saved`.
+    pub(crate) fn settings(&self, file: &Settings, saved: &Settings) -> Settings {
+        fn unless_changed<T: PartialEq + Copy>(
+            value: Option<T>,
+            now: Option<T>,
+            then: Option<T>,
+        ) -> Option<T> {
+            value.filter(|_| now == then)
+        }
+
+        Settings {
+            mode: unless_changed(self.mode, file.mode, saved.mode),
+            region: unless_changed(self.region, file.region, saved.region),
+            role: unless_changed(self.role, file.role, saved.role),
+            build: unless_changed(self.build, file.build, saved.build),
+            show_left_pane: unless_changed(
+                self.show_left_pane,
+                file.show_left_pane,
+                saved.show_left_pane,
+            ),
+            ..Settings::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips() {
+        let path = std::env::temp_dir().join(format!("uggo-session-{}.toml", std::process::id()));
+        let session = Session {
+            champion: Some("MonkeyKing".to_string()),
+            mode: Some(Mode::ARAM),
+            region: Some(Region::EUN1),
+            role: Some(Role::ADCarry),
+            build: Some(Build::OnHit),
+            patch: Some("14.20".to_string()),
+            show_left_pane: Some(false),
+        };
+        session.save(&path).unwrap();
+        let restored = Session::from_file(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(restored, session);
+    }
+
+    #[test]
+    fn config_changes_win_over_the_session() {
+        let path =
+            std::env::temp_dir().join(format!("uggo-session-config-{}.toml", std::process::id()));
+        let session = Session {
+            region: Some(Region::EUW1),
+            role: Some(Role::Mid),
+            ..Session::default()
+        };
+        let file = Settings {
+            region: Some(Region::NA1),
+            role: Some(Role::Top),
+            ..Settings::default()
+        };
+        session.store(&path, &file).unwrap();
+        let (restored, saved) = Session::load(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        // The region was changed in the file after the session was saved.
+        let file = Settings {
+            region: Some(Region::KR),
+            ..file
+        };
+        let settings = restored.settings(&file, &saved);
+        assert_eq!(settings.region, None);
+        assert_eq!(settings.role, Some(Role::Mid));
+    }
+
+    #[test]
+    fn ignores_unreadable_files() {
+        let path = std::env::temp_dir().join("uggo-session-missing.toml");
+        assert_eq!(Session::from_file(&path), Session::default());
+    }
+}
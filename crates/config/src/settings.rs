@@ -0,0 +1,594 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::Path;
+
+use serde::Deserialize;
+use ugg_types::mappings::{Build, Mode, Rank, Region, Role};
+
+use crate::ConfigError;
+
+/// Preferences that can be set in `config.toml`, environment variables or
+/// command line flags. Unset fields fall back to the next layer down.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Settings {
+    pub mode: Option<Mode>,
+    pub region: Option<Region>,
+    /// Rank bracket to take builds from, when u.gg has data for it.
+    pub rank: Option<Rank>,
+    pub role: Option<Role>,
+    pub build: Option<Build>,
+    /// A patch like `14.20` to use instead of the latest one.
+    pub patch: Option<String>,
+    /// The ddragon locale to use, e.g. `ko_KR` or `pt_BR`.
+    pub locale: Option<String>,
+    pub low_sample_size: Option<i64>,
+    pub prior_games: Option<f64>,
+    /// Whether picking a champion updates the client's current rune page.
+    pub import_runes: Option<bool>,
+    pub show_left_pane: Option<bool>,
+    pub show_champ_win_rates: Option<bool>,
+    /// Whether build options are sorted by popularity instead of win rate.
+    pub sort_by_popularity: Option<bool>,
+    /// Whether the last champion and selections are restored on start.
+    pub restore_session: Option<bool>,
+    /// The key binding preset, e.g. `arrows` or `vim`.
+    pub keymap: Option<String>,
+    /// Bindings that replace the preset's, keyed by action name. They are
+    /// checked when the keymap is built.
+    pub keys: BTreeMap<String, Vec<String>>,
+    /// The colour theme, e.g. `dark`, `light` or `colorblind`.
+    pub theme: Option<String>,
+    /// Colours that replace the theme's, keyed by name. They are checked
+    /// when the theme is built.
+    pub colors: BTreeMap<String, String>,
+    /// Champions pinned to the top of the champion list, as written. They
+    /// are checked once champion data is loaded.
+    pub favorites: Vec<String>,
+    pub pools: Vec<Pool>,
+}
+
+/// A named list of champions to switch between, e.g. the ones played mid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pool {
+    pub name: String,
+    /// The role picked along with a champion from the pool.
+    pub role: Option<Role>,
+    /// Champion names or ids, as written.
+    pub champions: Vec<String>,
+}
+
+impl Settings {
+    /// Layers `overrides` on top, keeping values it leaves unset.
+    #[must_use]
+    pub fn merge(self, overrides: Self) -> Self {
+        Self {
+            mode: overrides.mode.or(self.mode),
+            region: overrides.region.or(self.region),
+            rank: overrides.rank.or(self.rank),
+            role: overrides.role.or(self.role),
+            build: overrides.build.or(self.build),
+            patch: overrides.patch.or(self.patch),
+            locale: overrides.locale.or(self.locale),
+            low_sample_size: overrides.low_sample_size.or(self.low_sample_size),
+            prior_games: overrides.prior_games.or(self.prior_games),
+            import_runes: overrides.import_runes.or(self.import_runes),
+            show_left_pane: overrides.show_left_pane.or(self.show_left_pane),
+            show_champ_win_rates: overrides.show_champ_win_rates.or(self.show_champ_win_rates),
+            sort_by_popularity: overrides.sort_by_popularity.or(self.sort_by_popularity),
+            restore_session: overrides.restore_session.or(self.restore_session),
+            keymap: overrides.keymap.or(self.keymap),
+            keys: self.keys.into_iter().chain(overrides.keys).collect(),
+            theme: overrides.theme.or(self.theme),
+            colors: self.colors.into_iter().chain(overrides.colors).collect(),
+            favorites: if overrides.favorites.is_empty() {
+                self.favorites
+            } else {
+                overrides.favorites
+            },
+            pools: if overrides.pools.is_empty() {
+                self.pools
+            } else {
+                overrides.pools
+            },
+        }
+    }
+
+    /// Reads a `config.toml`. A missing file is only an error if `required`.
+    pub fn from_file(path: &Path, required: bool) -> Result<Self, ConfigError> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound && !required => {
+                return Ok(Self::default());
+            }
+            Err(source) => {
+                return Err(ConfigError::ReadFile {
+                    path: path.to_path_buf(),
+                    source,
+                });
+            }
+        };
+        let file: FileSettings = toml::from_str(&text).map_err(|e| ConfigError::ParseFile {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+        file.validate(&path.display().to_string())
+    }
+
+    /// Reads the `UGGO_*` environment variables.
+    pub fn from_env() -> Result<Self, ConfigError> {
+        fn choice<T: Setting>(name: &str) -> Result<Option<T>, ConfigError> {
+            env_var(name)
+                .map(|v| T::parse_setting(&v, name))
+                .transpose()
+        }
+
+        Ok(Self {
+            mode: choice(MODE_ENV)?,
+            region: choice(REGION_ENV)?,
+            rank: choice(RANK_ENV)?,
+            role: choice(ROLE_ENV)?,
+            build: choice(BUILD_ENV)?,
+            patch: env_var(PATCH_ENV)
+                .map(|v| parse_patch(&v, PATCH_ENV))
+                .transpose()?,
+            locale: env_var(LOCALE_ENV),
+            keymap: env_var(KEYMAP_ENV),
+            theme: env_var(THEME_ENV),
+            low_sample_size: env_var(LOW_SAMPLE_SIZE_ENV)
+                .map(|v| parse_number("low_sample_size", &v, LOW_SAMPLE_SIZE_ENV))
+                .transpose()?,
+            prior_games: env_var(PRIOR_GAMES_ENV)
+                .map(|v| parse_number("prior_games", &v, PRIOR_GAMES_ENV))
+                .transpose()?,
+            ..Self::default()
+        })
+    }
+}
+
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|v| !v.trim().is_empty())
+}
+
+/// Environment variables, named after the setting they override.
+pub const MODE_ENV: &str = "UGGO_MODE";
+pub const REGION_ENV: &str = "UGGO_REGION";
+pub const RANK_ENV: &str = "UGGO_RANK";
+pub const ROLE_ENV: &str = "UGGO_ROLE";
+pub const BUILD_ENV: &str = "UGGO_BUILD";
+pub const PATCH_ENV: &str = "UGGO_PATCH";
+pub const LOCALE_ENV: &str = "UGGO_LOCALE";
+pub const LOW_SAMPLE_SIZE_ENV: &str = "UGGO_LOW_SAMPLE_SIZE";
+pub const PRIOR_GAMES_ENV: &str = "UGGO_PRIOR_GAMES";
+pub const KEYMAP_ENV: &str = "UGGO_KEYMAP";
+pub const THEME_ENV: &str = "UGGO_THEME";
+
+/// The layout of `config.toml`, before any values are checked.
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+struct FileSettings {
+    mode: Option<String>,
+    region: Option<String>,
+    rank: Option<String>,
+    role: Option<String>,
+    build: Option<String>,
+    patch: Option<String>,
+    locale: Option<String>,
+    low_sample_size: Option<i64>,
+    prior_games: Option<f64>,
+    #[serde(default)]
+    favorites: Vec<String>,
+    #[serde(default)]
+    pools: Vec<PoolSettings>,
+    #[serde(default)]
+    client: ClientSettings,
+    #[serde(default)]
+    ui: UiSettings,
+    #[serde(default)]
+    keys: KeySettings,
+    #[serde(default)]
+    theme: ThemeSettings,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+struct ClientSettings {
+    import_runes: Option<bool>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+struct UiSettings {
+    show_left_pane: Option<bool>,
+    show_champ_win_rates: Option<bool>,
+    sort: Option<String>,
+    restore_session: Option<bool>,
+}
+
+#[derive(Deserialize, Default)]
+struct KeySettings {
+    preset: Option<String>,
+    #[serde(flatten)]
+    bindings: BTreeMap<String, KeyList>,
+}
+
+#[derive(Deserialize, Default)]
+struct ThemeSettings {
+    preset: Option<String>,
+    #[serde(flatten)]
+    colors: BTreeMap<String, String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PoolSettings {
+    name: Option<String>,
+    role: Option<String>,
+    champions: Vec<String>,
+}
+
+impl PoolSettings {
+    /// A pool is named after its role unless it has a name of its own.
+    fn validate(self, origin: &str) -> Result<Pool, ConfigError> {
+        let role = self
+            .role
+            .map(|v| Role::parse_setting(&v, origin))
+            .transpose()?;
+        let name = self
+            .name
+            .filter(|n| !n.trim().is_empty())
+            .or_else(|| role.map(|r| r.to_string()))
+            .ok_or_else(|| invalid("pools.name", "", origin, "a name or role for every pool"))?;
+        Ok(Pool {
+            name,
+            role,
+            champions: self.champions,
+        })
+    }
+}
+
+/// A single binding, or a list of them.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum KeyList {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl From<KeyList> for Vec<String> {
+    fn from(keys: KeyList) -> Self {
+        match keys {
+            KeyList::One(key) => vec![key],
+            KeyList::Many(keys) => keys,
+        }
+    }
+}
+
+impl FileSettings {
+    fn validate(self, origin: &str) -> Result<Settings, ConfigError> {
+        let check = |key: &'static str, value: Option<f64>| match value {
+            Some(v) if v < 0.0 => Err(invalid(
+                key,
+                &v.to_string(),
+                origin,
+                "a number of at least 0",
+            )),
+            _ => Ok(()),
+        };
+        #[allow(clippy::cast_precision_loss)]
+        check("low_sample_size", self.low_sample_size.map(|v| v as f64))?;
+        check("prior_games", self.prior_games)?;
+
+        Ok(Settings {
+            mode: self
+                .mode
+                .map(|v| Mode::parse_setting(&v, origin))
+                .transpose()?,
+            region: self
+                .region
+                .map(|v| Region::parse_setting(&v, origin))
+                .transpose()?,
+            rank: self
+                .rank
+                .map(|v| Rank::parse_setting(&v, origin))
+                .transpose()?,
+            role: self
+                .role
+                .map(|v| Role::parse_setting(&v, origin))
+                .transpose()?,
+            build: self
+                .build
+                .map(|v| Build::parse_setting(&v, origin))
+                .transpose()?,
+            patch: self.patch.map(|v| parse_patch(&v, origin)).transpose()?,
+            locale: self.locale.filter(|l| !l.is_empty()),
+            low_sample_size: self.low_sample_size,
+            prior_games: self.prior_games,
+            import_runes: self.client.import_runes,
+            show_left_pane: self.ui.show_left_pane,
+            show_champ_win_rates: self.ui.show_champ_win_rates,
+            restore_session: self.ui.restore_session,
+            sort_by_popularity: self.ui.sort.map(|v| parse_sort(&v, origin)).transpose()?,
+            keymap: self.keys.preset,
+            keys: self
+                .keys
+                .bindings
+                .into_iter()
+                .map(|(action, keys)| (action, keys.into()))
+                .collect(),
+            theme: self.theme.preset,
+            colors: self.theme.colors,
+            favorites: self.favorites,
+            pools: self
+                .pools
+                .into_iter()
+                .map(|p| p.validate(origin))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+fn invalid(key: &'static str, value: &str, origin: &str, expected: &str) -> ConfigError {
+    ConfigError::InvalidValue {
+        key,
+        value: value.to_string(),
+        origin: origin.to_string(),
+        expected: expected.to_string(),
+    }
+}
+
+/// Lowercases and drops punctuation, so `ranked_solo`, `Ranked Solo` and
+/// `rankedsolo` all compare equal. `+` is kept as `plus` for rank brackets.
+fn normalize(value: &str) -> String {
+    value
+        .replace('+', "plus")
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+fn parse_choice<T: Copy + Display>(
+    key: &'static str,
+    value: &str,
+    origin: &str,
+    options: &[T],
+    aliases: &[(&str, T)],
+) -> Result<T, ConfigError> {
+    let wanted = normalize(value);
+    options
+        .iter()
+        .copied()
+        .find(|option| {
+            let name = normalize(&option.to_string());
+            // Regions are shown with a server number, e.g. NA1, but are
+            // usually written without it.
+            name == wanted || name.trim_end_matches(|c: char| c.is_ascii_digit()) == wanted
+        })
+        .or_else(|| {
+            aliases
+                .iter()
+                .find(|(alias, _)| *alias == wanted)
+                .map(|(_, option)| *option)
+        })
+        .ok_or_else(|| {
+            let names = options
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            invalid(key, value, origin, &format!("one of {names}"))
+        })
+}
+
+fn parse_number<T: std::str::FromStr + PartialOrd + Default>(
+    key: &'static str,
+    value: &str,
+    origin: &str,
+) -> Result<T, ConfigError> {
+    value
+        .trim()
+        .parse()
+        .ok()
+        .filter(|v| *v >= T::default())
+        .ok_or_else(|| invalid(key, value, origin, "a number of at least 0"))
+}
+
+/// Checks that `value` looks like a patch, e.g. `14.20`.
+pub fn parse_patch(value: &str, origin: &str) -> Result<String, ConfigError> {
+    let value = value.trim();
+    let parts = value.split('.').collect::<Vec<_>>();
+    if parts.len() == 2
+        && parts
+            .iter()
+            .all(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_digit()))
+    {
+        Ok(value.to_string())
+    } else {
+        Err(invalid("patch", value, origin, "a patch like 14.20"))
+    }
+}
+
+fn parse_sort(value: &str, origin: &str) -> Result<bool, ConfigError> {
+    match normalize(value).as_str() {
+        "winrate" => Ok(false),
+        "popularity" => Ok(true),
+        _ => Err(invalid(
+            "ui.sort",
+            value,
+            origin,
+            "one of win_rate, popularity",
+        )),
+    }
+}
+
+/// A setting written as text, checked against the values u.gg knows about.
+pub trait Setting: Sized {
+    /// Parses `value`, naming `origin` (a file, variable or flag) in errors.
+    fn parse_setting(value: &str, origin: &str) -> Result<Self, ConfigError>;
+}
+
+impl Setting for Mode {
+    fn parse_setting(value: &str, origin: &str) -> Result<Self, ConfigError> {
+        parse_choice(
+            "mode",
+            value,
+            origin,
+            Mode::all(),
+            &[("ranked", Mode::Normal), ("rankedsolo5x5", Mode::Normal)],
+        )
+    }
+}
+
+impl Setting for Region {
+    fn parse_setting(value: &str, origin: &str) -> Result<Self, ConfigError> {
+        parse_choice(
+            "region",
+            value,
+            origin,
+            Region::all(),
+            &[
+                ("eune", Region::EUN1),
+                ("lan", Region::LA1),
+                ("las", Region::LA2),
+                ("oce", Region::OC1),
+                ("all", Region::World),
+            ],
+        )
+    }
+}
+
+impl Setting for Rank {
+    fn parse_setting(value: &str, origin: &str) -> Result<Self, ConfigError> {
+        parse_choice("rank", value, origin, &Rank::preferred_order(), &[])
+    }
+}
+
+impl Setting for Role {
+    fn parse_setting(value: &str, origin: &str) -> Result<Self, ConfigError> {
+        parse_choice(
+            "role",
+            value,
+            origin,
+            Role::all(),
+            &[
+                ("adc", Role::ADCarry),
+                ("bot", Role::ADCarry),
+                ("jg", Role::Jungle),
+                ("middle", Role::Mid),
+                ("supp", Role::Support),
+            ],
+        )
+    }
+}
+
+impl Setting for Build {
+    fn parse_setting(value: &str, origin: &str) -> Result<Self, ConfigError> {
+        parse_choice("build", value, origin, Build::all(), &[])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_loose_names() {
+        assert_eq!(Region::parse_setting("euw", "test").unwrap(), Region::EUW1);
+        assert_eq!(Region::parse_setting("EUNE", "test").unwrap(), Region::EUN1);
+        assert_eq!(
+            Rank::parse_setting("emerald+", "test").unwrap(),
+            Rank::EmeraldPlus
+        );
+        assert_eq!(Role::parse_setting("adc", "test").unwrap(), Role::ADCarry);
+        assert_eq!(
+            Build::parse_setting("on-hit", "test").unwrap(),
+            Build::OnHit
+        );
+        assert_eq!(
+            Mode::parse_setting("one_for_all", "test").unwrap(),
+            Mode::OneForAll
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_values() {
+        let err = Role::parse_setting("toplane", "config.toml").unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("Invalid role \"toplane\" in config.toml")
+        );
+        assert!(parse_patch("14", "test").is_err());
+        assert!(parse_patch("14.x", "test").is_err());
+        assert_eq!(parse_patch(" 14.20 ", "test").unwrap(), "14.20");
+    }
+
+    #[test]
+    fn reads_file_sections() {
+        let file: FileSettings = toml::from_str(
+            r##"
+            region = "kr"
+            rank = "master+"
+            favorites = ["Ahri", "Wukong"]
+
+            [client]
+            import_runes = false
+
+            [ui]
+            sort = "popularity"
+
+            [keys]
+            preset = "vim"
+            search = "/"
+            tier_list = ["T", "alt+t"]
+
+            [theme]
+            preset = "colorblind"
+            negative = "#d55e00"
+
+            [[pools]]
+            role = "mid"
+            champions = ["Ahri", "Orianna"]
+
+            [[pools]]
+            name = "Blind picks"
+            champions = ["Malphite"]
+            "##,
+        )
+        .unwrap();
+        let settings = file.validate("test").unwrap();
+        assert_eq!(settings.region, Some(Region::KR));
+        assert_eq!(settings.rank, Some(Rank::MasterPlus));
+        assert_eq!(settings.import_runes, Some(false));
+        assert_eq!(settings.sort_by_popularity, Some(true));
+        assert_eq!(settings.keymap.as_deref(), Some("vim"));
+        assert_eq!(settings.keys["search"], ["/"]);
+        assert_eq!(settings.keys["tier_list"], ["T", "alt+t"]);
+        assert_eq!(settings.theme.as_deref(), Some("colorblind"));
+        assert_eq!(settings.colors["negative"], "#d55e00");
+        assert_eq!(settings.favorites, ["Ahri", "Wukong"]);
+        assert_eq!(settings.pools[0].name, Role::Mid.to_string());
+        assert_eq!(settings.pools[0].role, Some(Role::Mid));
+        assert_eq!(settings.pools[1].name, "Blind picks");
+        assert_eq!(settings.pools[1].champions, ["Malphite"]);
+
+        assert!(toml::from_str::<FileSettings>("colour = true").is_err());
+        let unnamed: FileSettings = toml::from_str("[[pools]]\nchampions = []").unwrap();
+        assert!(unnamed.validate("test").is_err());
+    }
+
+    #[test]
+    fn later_layers_win() {
+        let file = Settings {
+            region: Some(Region::KR),
+            role: Some(Role::Mid),
+            ..Settings::default()
+        };
+        let flags = Settings {
+            region: Some(Region::NA1),
+            ..Settings::default()
+        };
+        let merged = file.merge(flags);
+        assert_eq!(merged.region, Some(Region::NA1));
+        assert_eq!(merged.role, Some(Role::Mid));
+    }
+}
@@ -1,8 +1,9 @@
 //! # `league_client_connector`
 //!
 //! Rust implementation for [lcu-connector](https://github.com/Pupix/lcu-connector) minus the
-//! file watching mechanism. This crate needs the League Client to be opened, in order to get the
-//! installation path for League of Legends so the `lockfile` can be retrieved correctly.
+//! file watching mechanism, which lives in [`crate::watcher`]. This crate needs the League
+//! Client to be opened, in order to get the installation path for League of Legends so the
+//! `lockfile` can be retrieved correctly.
 //!
 //! Note that every time the League Client is opened, it creates a new `lockfile` so a watcher or
 //! some refresh mechanism needs to be implemented to use correctly in an application.
@@ -39,12 +40,18 @@ use base64::Engine;
 use base64::prelude::BASE64_STANDARD;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
-use snafu::{ResultExt, Snafu};
+use snafu::{OptionExt, ResultExt, Snafu};
 use std::env::consts::OS;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 
+/// Where Linux lists running processes.
+const PROC_ROOT: &str = "/proc";
+
+/// The name of the client process whose command line has the install path.
+const CLIENT_PROCESS: &str = "LeagueClientUx.exe";
+
 /// Make sure the League of Legends Client is opened before running any of the methods.
 pub struct LeagueClientConnector {}
 
@@ -53,15 +60,31 @@ impl LeagueClientConnector {
     /// [Game Client API](https://developer.riotgames.com/docs/lol#game-client-api)
     /// Which uses `RESTful` to interact with League's Client
     pub fn parse_lockfile() -> Result<RiotLockFile> {
-        let mut path = PathBuf::from(Self::get_path()?);
+        Self::read_lockfile(&Self::get_lockfile_path()?)
+    }
+
+    /// Where the running client keeps its lockfile.
+    pub fn get_lockfile_path() -> Result<PathBuf> {
+        let mut path = Self::get_path()?;
         path.push("lockfile");
+        Ok(path)
+    }
+
+    pub fn read_lockfile(path: &Path) -> Result<RiotLockFile> {
         let Some(lockfile) = path.to_str() else {
             return Err(LeagueConnectorError::EmptyPath {});
         };
 
         let contents = fs::read_to_string(lockfile).context(UnableToReadSnafu)?;
+        Self::parse_lockfile_contents(&contents)
+    }
 
-        let pieces: Vec<&str> = contents.split(':').collect();
+    fn parse_lockfile_contents(contents: &str) -> Result<RiotLockFile> {
+        let pieces: Vec<&str> = contents.trim().split(':').collect();
+        // The client may still be writing it.
+        if pieces.len() < 5 {
+            return Err(LeagueConnectorError::IncompleteLockfile {});
+        }
 
         let username = "riot".to_string();
         let address = "127.0.0.1".to_string();
@@ -89,12 +112,13 @@ impl LeagueClientConnector {
     }
 
     /// Gets League of Legends Installation path. Useful to find the "lockfile" for example.
-    /// Works for Windows & Mac OSX
-    pub fn get_path() -> Result<String> {
+    /// Works for Windows, Mac OSX and Linux, where the client runs under Wine
+    pub fn get_path() -> Result<PathBuf> {
         let raw_info: String = match OS {
             "windows" => Self::get_raw_league_info_in_windows()?,
             "macos" => Self::get_raw_league_info_in_macos()?,
-            _ => unimplemented!(),
+            "linux" => return Self::get_path_in_linux(Path::new(PROC_ROOT)),
+            os => return UnsupportedOsSnafu { os }.fail(),
         };
 
         let pattern = Regex::new(r"--install-directory=(?P<dir>[[:alnum:][:space:]:\./\\]+)")
@@ -102,7 +126,7 @@ impl LeagueClientConnector {
         let Some(caps) = pattern.captures(&raw_info) else {
             return Err(LeagueConnectorError::NoInstallationPath {});
         };
-        let path = caps["dir"].to_string().trim().to_string();
+        let path = PathBuf::from(caps["dir"].trim());
 
         Ok(path)
     }
@@ -120,8 +144,6 @@ impl LeagueClientConnector {
 
         let res = String::from_utf8(output_child.stdout).context(Utf8ParseSnafu)?;
 
-        dbg!(&res);
-
         Ok(res)
     }
 
@@ -149,6 +171,93 @@ impl LeagueClientConnector {
 
         Ok(res)
     }
+
+    /// Looks through the command lines in `proc_root` for the client, and maps
+    /// its install directory from Wine to the host filesystem.
+    fn get_path_in_linux(proc_root: &Path) -> Result<PathBuf> {
+        let processes = fs::read_dir(proc_root).context(GetRawPathSnafu)?;
+        for process in processes.flatten() {
+            let is_pid = process
+                .file_name()
+                .to_str()
+                .is_some_and(|name| name.chars().all(|c| c.is_ascii_digit()));
+            if !is_pid {
+                continue;
+            }
+            // Processes can exit while we look, so unreadable ones are skipped.
+            let Ok(cmdline) = fs::read(process.path().join("cmdline")) else {
+                continue;
+            };
+            let args = split_nul(&cmdline);
+            if !args.iter().any(|arg| is_client_process(arg)) {
+                continue;
+            }
+            let Some(install_dir) = args
+                .iter()
+                .find_map(|arg| arg.strip_prefix("--install-directory="))
+            else {
+                continue;
+            };
+
+            let environ = fs::read(process.path().join("environ")).unwrap_or_default();
+            let path = wine_to_host_path(install_dir, &split_nul(&environ))?;
+            let lockfile = path.join("lockfile");
+            if !lockfile.is_file() {
+                return LockfileNotFoundSnafu { path: lockfile }.fail();
+            }
+            return Ok(path);
+        }
+
+        Err(LeagueConnectorError::NoInstallationPath {})
+    }
+}
+
+/// Splits a `/proc` file like `cmdline` or `environ` on its NUL separators.
+fn split_nul(contents: &[u8]) -> Vec<String> {
+    String::from_utf8_lossy(contents)
+        .split('\0')
+        .filter(|part| !part.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn is_client_process(arg: &str) -> bool {
+    arg.rsplit(['/', '\\'])
+        .next()
+        .is_some_and(|name| name.eq_ignore_ascii_case(CLIENT_PROCESS))
+}
+
+/// Maps a Wine path like `C:/Riot Games/League of Legends` to where it lives
+/// on the host, using the Wine prefix from the process' environment.
+fn wine_to_host_path(path: &str, environ: &[String]) -> Result<PathBuf> {
+    let path = path.trim();
+    if path.starts_with('/') {
+        return Ok(PathBuf::from(path));
+    }
+    let (drive, rest) = path
+        .split_once(':')
+        .filter(|(drive, _)| drive.len() == 1)
+        .context(NoInstallationPathSnafu)?;
+
+    let var = |name: &str| {
+        environ
+            .iter()
+            .find_map(|entry| entry.strip_prefix(name)?.strip_prefix('='))
+    };
+    let prefix = var("WINEPREFIX")
+        .map(PathBuf::from)
+        .or_else(|| var("HOME").map(|home| Path::new(home).join(".wine")))
+        .context(NoWinePrefixSnafu)?;
+
+    // Drives are symlinks in `dosdevices`, though older prefixes only have
+    // `drive_c`.
+    let drive = drive.to_ascii_lowercase();
+    let mut host = prefix.join("dosdevices").join(format!("{drive}:"));
+    if !host.exists() && drive == "c" {
+        host = prefix.join("drive_c");
+    }
+    host.extend(rest.split(['/', '\\']).filter(|part| !part.is_empty()));
+    Ok(host)
 }
 
 /// This struct can be used to establish a connection with
@@ -197,6 +306,18 @@ pub enum LeagueConnectorError {
     #[snafu(display("No installation path found for League"))]
     NoInstallationPath {},
 
+    #[snafu(display("No Wine prefix found for the League client"))]
+    NoWinePrefix {},
+
+    #[snafu(display("No lockfile found at {}", path.display()))]
+    LockfileNotFound { path: PathBuf },
+
+    #[snafu(display("League client detection is not supported on {}", os))]
+    UnsupportedOs { os: &'static str },
+
+    #[snafu(display("Lockfile is incomplete"))]
+    IncompleteLockfile {},
+
     #[snafu(display("Path is empty"))]
     EmptyPath {},
 
@@ -246,6 +367,94 @@ mod tests {
         assert_ne!(file1, file2);
     }
 
+    /// Lays out a fake `/proc` with the client running in a Wine prefix, next
+    /// to a process that isn't the client.
+    #[cfg(unix)]
+    fn fake_proc(name: &str, client_cmdline: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("uggo-{name}-{}", std::process::id()));
+        let prefix = root.join("prefix");
+        let install_dir = prefix.join("drive_c/Riot Games/League of Legends");
+        fs::create_dir_all(&install_dir).unwrap();
+        fs::create_dir_all(prefix.join("dosdevices")).unwrap();
+        std::os::unix::fs::symlink("../drive_c", prefix.join("dosdevices/c:")).unwrap();
+        fs::write(
+            install_dir.join("lockfile"),
+            "LeagueClient:1234:54835:password:https",
+        )
+        .unwrap();
+
+        let proc_root = root.join("proc");
+        fs::create_dir_all(proc_root.join("self")).unwrap();
+        fs::create_dir_all(proc_root.join("1")).unwrap();
+        fs::write(proc_root.join("1/cmdline"), "/sbin/init\0").unwrap();
+        fs::create_dir_all(proc_root.join("4242")).unwrap();
+        fs::write(proc_root.join("4242/cmdline"), client_cmdline).unwrap();
+        fs::write(
+            proc_root.join("4242/environ"),
+            format!("HOME=/home/user\0WINEPREFIX={}\0", prefix.display()),
+        )
+        .unwrap();
+        root
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn finds_client_under_wine() {
+        let root = fake_proc(
+            "wine",
+            "C:\\Riot Games\\League of Legends\\LeagueClientUx.exe\0--app-port=54835\0--install-directory=C:/Riot Games/League of Legends/\0",
+        );
+        let path = LeagueClientConnector::get_path_in_linux(&root.join("proc")).unwrap();
+        assert_eq!(
+            fs::read_to_string(path.join("lockfile")).unwrap(),
+            "LeagueClient:1234:54835:password:https"
+        );
+        assert!(path.ends_with("League of Legends"));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn reports_missing_client() {
+        let root = fake_proc("no-client", "/usr/bin/bash\0");
+        assert!(matches!(
+            LeagueClientConnector::get_path_in_linux(&root.join("proc")),
+            Err(LeagueConnectorError::NoInstallationPath {})
+        ));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn parses_lockfile() {
+        let lockfile =
+            LeagueClientConnector::parse_lockfile_contents("LeagueClient:1234:54835:secret:https")
+                .unwrap();
+        assert_eq!(lockfile.pid, 1234);
+        assert_eq!(lockfile.port, 54835);
+        assert_eq!(lockfile.b64_auth, BASE64_STANDARD.encode("riot:secret"));
+        assert!(matches!(
+            LeagueClientConnector::parse_lockfile_contents("LeagueClient:1234:"),
+            Err(LeagueConnectorError::IncompleteLockfile {})
+        ));
+    }
+
+    #[test]
+    fn maps_wine_paths() {
+        let environ = ["HOME=/home/user".to_string()];
+        assert_eq!(
+            wine_to_host_path("D:\\Games\\League of Legends", &environ).unwrap(),
+            Path::new("/home/user/.wine/dosdevices/d:/Games/League of Legends")
+        );
+        assert_eq!(
+            wine_to_host_path("/opt/league", &[]).unwrap(),
+            Path::new("/opt/league")
+        );
+        assert!(matches!(
+            wine_to_host_path("C:/Riot Games", &[]),
+            Err(LeagueConnectorError::NoWinePrefix {})
+        ));
+    }
+
     fn build_lockfile(port: u32, address: &str, b64_auth: &str) -> RiotLockFile {
         RiotLockFile {
             process: "1234".to_string(),
@@ -10,7 +10,9 @@ use ugg_types::client_runepage::{NewRunePage, RunePage, RunePages};
 use ugg_types::client_summoner::ClientSummoner;
 
 mod lcc;
+mod watcher;
 use lcc::{LeagueClientConnector, RiotLockFile};
+pub use watcher::ClientWatcher;
 
 #[derive(Error, Debug)]
 pub enum LOLClientError {
@@ -18,8 +20,8 @@ pub enum LOLClientError {
     TlsConnectorError(#[from] native_tls::Error),
     #[error("Unable to read lockfile")]
     LockfileReadError(#[from] lcc::LeagueConnectorError),
-    #[error("Linux is not supported")]
-    LinuxNotSupported,
+    #[error("Unable to start the lockfile watcher")]
+    WatcherError(#[source] std::io::Error),
 }
 
 pub struct LOLClientAPI {
@@ -29,21 +31,23 @@ pub struct LOLClientAPI {
 
 impl LOLClientAPI {
     pub fn new() -> Result<LOLClientAPI, LOLClientError> {
-        if cfg!(target_os = "linux") {
-            return Err(LOLClientError::LinuxNotSupported);
-        }
         Ok(LOLClientAPI {
-            agent: AgentBuilder::new()
-                .tls_connector(Arc::new(
-                    TlsConnector::builder()
-                        .danger_accept_invalid_certs(true)
-                        .build()?,
-                ))
-                .build(),
+            agent: Self::agent()?,
             lockfile: LeagueClientConnector::parse_lockfile()?,
         })
     }
 
+    /// The client serves a self-signed certificate, so it isn't checked.
+    fn agent() -> Result<Agent, LOLClientError> {
+        Ok(AgentBuilder::new()
+            .tls_connector(Arc::new(
+                TlsConnector::builder()
+                    .danger_accept_invalid_certs(true)
+                    .build()?,
+            ))
+            .build())
+    }
+
     fn get_data<T: DeserializeOwned>(&self, url: &str) -> Option<T> {
         match self
             .agent
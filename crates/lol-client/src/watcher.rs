@@ -0,0 +1,127 @@
+use std::net::{Ipv4Addr, SocketAddr, TcpStream};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+
+use ureq::Agent;
+
+use crate::lcc::{LeagueClientConnector, RiotLockFile};
+use crate::{LOLClientAPI, LOLClientError};
+
+/// How often the lockfile is checked.
+const POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+/// How long the client gets to accept a connection before it counts as gone.
+const CONNECT_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Keeps a connection to the League client as it starts, restarts and closes.
+///
+/// The client writes a new lockfile, with a new port and password, every time
+/// it starts and deletes it when it closes. A background thread checks the
+/// lockfile and sends any change, which [`ClientWatcher::update`] picks up
+/// without blocking. A client that crashes leaves its lockfile behind, so a
+/// lockfile only counts once its port accepts connections. The thread stops
+/// once the watcher is dropped.
+pub struct ClientWatcher {
+    agent: Agent,
+    updates: Receiver<Option<RiotLockFile>>,
+    client: Option<LOLClientAPI>,
+    stop: Arc<AtomicBool>,
+}
+
+impl ClientWatcher {
+    /// Starts watching for the client, which doesn't have to be running yet.
+    pub fn spawn() -> Result<ClientWatcher, LOLClientError> {
+        let agent = LOLClientAPI::agent()?;
+        let (sender, updates) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = Arc::clone(&stop);
+        thread::Builder::new()
+            .name("lockfile-watcher".to_string())
+            .spawn(move || watch(&sender, &thread_stop))
+            .map_err(LOLClientError::WatcherError)?;
+        Ok(ClientWatcher {
+            agent,
+            updates,
+            client: None,
+            stop,
+        })
+    }
+
+    /// Applies the latest change to the lockfile, returning whether the
+    /// connection changed.
+    pub fn update(&mut self) -> bool {
+        let mut latest = None;
+        while let Ok(lockfile) = self.updates.try_recv() {
+            latest = Some(lockfile);
+        }
+        let Some(lockfile) = latest else {
+            return false;
+        };
+        self.client = lockfile.map(|lockfile| LOLClientAPI {
+            agent: self.agent.clone(),
+            lockfile,
+        });
+        true
+    }
+
+    #[must_use]
+    pub fn client(&self) -> Option<&LOLClientAPI> {
+        self.client.as_ref()
+    }
+
+    #[must_use]
+    pub fn is_connected(&self) -> bool {
+        self.client.is_some()
+    }
+}
+
+impl Drop for ClientWatcher {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Sends the lockfile whenever it appears, changes or disappears, until
+/// `stop` is set.
+fn watch(sender: &Sender<Option<RiotLockFile>>, stop: &AtomicBool) {
+    let mut path: Option<PathBuf> = None;
+    let mut current = None;
+    while !stop.load(Ordering::Relaxed) {
+        // Finding the client means looking through every process, so it's
+        // only done again once the lockfile it pointed to is gone or stale.
+        if path.as_ref().is_none_or(|p| !p.is_file()) {
+            path = LeagueClientConnector::get_lockfile_path()
+                .ok()
+                .filter(|p| p.is_file());
+        }
+        let lockfile = path
+            .as_deref()
+            .and_then(|p| LeagueClientConnector::read_lockfile(p).ok())
+            .filter(is_listening);
+        if lockfile.is_none() {
+            path = None;
+        }
+        if lockfile != current {
+            if sender.send(lockfile.clone()).is_err() {
+                return;
+            }
+            current = lockfile;
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Whether the client that wrote `lockfile` is still accepting connections.
+fn is_listening(lockfile: &RiotLockFile) -> bool {
+    u16::try_from(lockfile.port).is_ok_and(|port| {
+        TcpStream::connect_timeout(
+            &SocketAddr::from((Ipv4Addr::LOCALHOST, port)),
+            CONNECT_TIMEOUT,
+        )
+        .is_ok()
+    })
+}
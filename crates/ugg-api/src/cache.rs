@@ -0,0 +1,279 @@
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::UggError;
+
+/// How much of the on-disk cache one patch and kind of data take up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheUsage {
+    /// The patch, e.g. `14.20`, or `None` for files shared by every patch.
+    pub patch: Option<String>,
+    /// What the files hold, e.g. `champion`, `item` or `images`.
+    pub kind: String,
+    pub files: usize,
+    pub bytes: u64,
+}
+
+/// Whether a path segment is a ddragon or cdragon version, e.g. `14.20.1`.
+fn is_version(segment: &str) -> bool {
+    let parts = segment.split('.').collect::<Vec<_>>();
+    parts.len() >= 2
+        && parts
+            .iter()
+            .all(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Whether a path segment is a locale, e.g. `en_US` or `en_us`.
+fn is_locale(segment: &str) -> bool {
+    let segment = segment.strip_suffix(".json").unwrap_or(segment);
+    segment.len() == 5
+        && segment.as_bytes()[2] == b'_'
+        && segment
+            .chars()
+            .enumerate()
+            .all(|(i, c)| i == 2 || c.is_ascii_alphabetic())
+}
+
+/// Works out the patch and kind of data from a cached URL, e.g.
+/// `https://ddragon.leagueoflegends.com/cdn/14.20.1/data/en_US/item.json`
+/// is `item` data for patch `14.20`.
+fn describe(url: &str) -> (Option<String>, String) {
+    let location = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let segments = location.split('/').skip(1).collect::<Vec<_>>();
+    let version = segments.iter().position(|s| is_version(s));
+    let patch = version.map(|i| segments[i].split('.').take(2).collect::<Vec<_>>().join("."));
+
+    let kind = if Path::new(location)
+        .extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case("png"))
+    {
+        "images".to_string()
+    } else {
+        segments[version.map_or(0, |i| i + 1)..]
+            .iter()
+            .filter(|s| !matches!(**s, "cdn" | "data" | "cdragon") && !is_locale(s))
+            .find(|s| !s.is_empty())
+            .map_or("other", |s| s.strip_suffix(".json").unwrap_or(s))
+            .to_string()
+    };
+    (patch, kind)
+}
+
+/// Orders patches newest first.
+fn patch_order(patch: &str) -> Vec<u32> {
+    patch.split('.').map(|p| p.parse().unwrap_or(0)).collect()
+}
+
+/// Where cacache keeps the content for `entry`. Entries written in one go
+/// don't record their size, so it has to come from the file itself.
+fn content_path(dir: &Path, entry: &cacache::Metadata) -> PathBuf {
+    let (algorithm, hex) = entry.integrity.to_hex();
+    dir.join("content-v2")
+        .join(algorithm.to_string())
+        .join(&hex[0..2])
+        .join(&hex[2..4])
+        .join(&hex[4..])
+}
+
+fn entry_size(dir: &Path, entry: &cacache::Metadata) -> u64 {
+    if entry.size > 0 {
+        return entry.size as u64;
+    }
+    std::fs::metadata(content_path(dir, entry)).map_or(0, |m| m.len())
+}
+
+fn entries(dir: &Path) -> Result<Vec<cacache::Metadata>, UggError> {
+    // Nothing has been cached yet, or it was just cleared.
+    if !dir.join("index-v5").exists() {
+        return Ok(Vec::new());
+    }
+    Ok(cacache::list_sync(dir).collect::<Result<Vec<_>, _>>()?)
+}
+
+/// Sums up the cache in `dir` by patch and kind of data, newest patch first
+/// and shared files last.
+pub fn cache_usage(dir: &Path) -> Result<Vec<CacheUsage>, UggError> {
+    let mut usage = BTreeMap::<(Option<String>, String), (usize, u64)>::new();
+    for entry in entries(dir)? {
+        let totals = usage.entry(describe(&entry.key)).or_default();
+        totals.0 += 1;
+        totals.1 += entry_size(dir, &entry);
+    }
+
+    let mut usage = usage
+        .into_iter()
+        .map(|((patch, kind), (files, bytes))| CacheUsage {
+            patch,
+            kind,
+            files,
+            bytes,
+        })
+        .collect::<Vec<_>>();
+    usage.sort_by(|a, b| match (&a.patch, &b.patch) {
+        (Some(x), Some(y)) => patch_order(y)
+            .cmp(&patch_order(x))
+            .then_with(|| a.kind.cmp(&b.kind)),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => a.kind.cmp(&b.kind),
+    });
+    Ok(usage)
+}
+
+/// Deletes everything in the cache, returning how many bytes were freed.
+pub fn clear_cache(dir: &Path) -> Result<u64, UggError> {
+    let mut seen = HashSet::new();
+    let freed = entries(dir)?
+        .iter()
+        .filter(|e| seen.insert(e.integrity.to_string()))
+        .map(|e| entry_size(dir, e))
+        .sum();
+    if dir.exists() {
+        cacache::clear_sync(dir)?;
+    }
+    Ok(freed)
+}
+
+/// Deletes everything but the newest `keep` patches and the patch of
+/// `in_use`, a version like `14.20.1`, returning the patches that were removed
+/// and how many bytes were freed. Files shared by every patch are kept.
+pub fn prune_cache(
+    dir: &Path,
+    keep: usize,
+    in_use: Option<&str>,
+) -> Result<(Vec<String>, u64), UggError> {
+    let entries = entries(dir)?;
+    let mut patches = entries
+        .iter()
+        .filter_map(|e| describe(&e.key).0)
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+    patches.sort_by_key(|p| std::cmp::Reverse(patch_order(p)));
+    let in_use = in_use.map(|v| v.split('.').take(2).collect::<Vec<_>>().join("."));
+    let mut removed = patches.split_off(keep.min(patches.len()));
+    removed.retain(|p| Some(p) != in_use.as_ref());
+
+    let (old, kept): (Vec<_>, Vec<_>) = entries
+        .into_iter()
+        .partition(|e| describe(&e.key).0.is_some_and(|p| removed.contains(&p)));
+    // Identical files are stored once, so only drop content nothing kept uses.
+    let kept_content = kept
+        .iter()
+        .map(|e| e.integrity.to_string())
+        .collect::<HashSet<_>>();
+    let mut removed_content = HashSet::new();
+    let mut freed = 0;
+    for entry in old {
+        cacache::remove_sync(dir, &entry.key)?;
+        let content = entry.integrity.to_string();
+        if !kept_content.contains(&content) && removed_content.insert(content) {
+            freed += entry_size(dir, &entry);
+            cacache::remove_hash_sync(dir, &entry.integrity)?;
+        }
+    }
+    Ok((removed, freed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describes_cached_urls() {
+        let describe = |url| {
+            let (patch, kind) = describe(url);
+            (patch.unwrap_or_default(), kind)
+        };
+        assert_eq!(
+            describe("https://ddragon.leagueoflegends.com/cdn/14.20.1/data/en_US/item.json"),
+            ("14.20".to_string(), "item".to_string())
+        );
+        assert_eq!(
+            describe(
+                "https://ddragon.leagueoflegends.com/cdn/14.20.1/data/ko_KR/champion/Ahri.json"
+            ),
+            ("14.20".to_string(), "champion".to_string())
+        );
+        assert_eq!(
+            describe("https://ddragon.leagueoflegends.com/cdn/14.9.1/img/champion/Ahri.png"),
+            ("14.9".to_string(), "images".to_string())
+        );
+        assert_eq!(
+            describe("https://raw.communitydragon.org/14.20/cdragon/arena/en_us.json"),
+            ("14.20".to_string(), "arena".to_string())
+        );
+        assert_eq!(
+            describe("https://ddragon.leagueoflegends.com/cdn/languages.json"),
+            (String::new(), "languages".to_string())
+        );
+    }
+
+    #[test]
+    fn prunes_old_patches() {
+        let dir = std::env::temp_dir().join(format!("uggo-cache-{}", std::process::id()));
+        let url = |patch: &str, file: &str| {
+            format!("https://ddragon.leagueoflegends.com/cdn/{patch}/data/en_US/{file}.json")
+        };
+        cacache::write_sync(&dir, url("14.9.1", "item"), b"old items").unwrap();
+        cacache::write_sync(&dir, url("14.10.1", "item"), b"new items").unwrap();
+        // The same content as a kept patch, which has to survive.
+        cacache::write_sync(&dir, url("14.9.1", "runesReforged"), b"runes").unwrap();
+        cacache::write_sync(&dir, url("14.10.1", "runesReforged"), b"runes").unwrap();
+        cacache::write_sync(
+            &dir,
+            "https://ddragon.leagueoflegends.com/cdn/languages.json",
+            b"[]",
+        )
+        .unwrap();
+
+        let (removed, freed) = prune_cache(&dir, 1, None).unwrap();
+        assert_eq!(removed, ["14.9"]);
+        assert_eq!(freed, 9);
+        let usage = cache_usage(&dir).unwrap();
+        assert_eq!(
+            usage
+                .iter()
+                .map(|u| (u.patch.as_deref(), u.kind.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                (Some("14.10"), "item"),
+                (Some("14.10"), "runesReforged"),
+                (None, "languages"),
+            ]
+        );
+        assert_eq!(
+            cacache::read_sync(&dir, url("14.10.1", "runesReforged")).unwrap(),
+            b"runes"
+        );
+
+        assert_eq!(clear_cache(&dir).unwrap(), 16);
+        assert!(cache_usage(&dir).unwrap().is_empty());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn keeps_the_patch_in_use() {
+        let dir = std::env::temp_dir().join(format!("uggo-cache-in-use-{}", std::process::id()));
+        let url = |patch: &str| {
+            format!("https://ddragon.leagueoflegends.com/cdn/{patch}/data/en_US/item.json")
+        };
+        for patch in ["14.8.1", "14.9.1", "14.10.1"] {
+            cacache::write_sync(&dir, url(patch), patch.as_bytes()).unwrap();
+        }
+
+        let (removed, _) = prune_cache(&dir, 1, Some("14.8.1")).unwrap();
+        assert_eq!(removed, ["14.9"]);
+        assert_eq!(
+            cache_usage(&dir)
+                .unwrap()
+                .iter()
+                .map(|u| u.patch.as_deref())
+                .collect::<Vec<_>>(),
+            [Some("14.10"), Some("14.8")]
+        );
+
+        clear_cache(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,64 @@
+use ddragon::models::items::Item;
+use std::collections::HashMap;
+
+/// A flattened view of a ddragon item, with ids resolved to numbers.
+#[derive(Debug, Clone)]
+pub struct ItemInfo {
+    pub id: i64,
+    pub name: String,
+    /// Raw ddragon description, which contains HTML-like markup.
+    pub description: String,
+    pub plaintext: String,
+    /// The cost of the item on its own, excluding components.
+    pub cost: i64,
+    pub total_gold: i64,
+    pub sell_gold: i64,
+    pub stats: HashMap<String, f64>,
+    pub tags: Vec<String>,
+    pub builds_from: Vec<i64>,
+    pub builds_into: Vec<i64>,
+}
+
+/// An item along with the full tree of components it builds from.
+#[derive(Debug, Clone)]
+pub struct ItemTree {
+    pub item: ItemInfo,
+    pub components: Vec<ItemTree>,
+}
+
+fn parse_ids(ids: Option<&Vec<String>>) -> Vec<i64> {
+    ids.map(|v| v.iter().filter_map(|id| id.parse().ok()).collect())
+        .unwrap_or_default()
+}
+
+impl ItemInfo {
+    #[must_use]
+    pub fn new(id: i64, item: &Item) -> Self {
+        Self {
+            id,
+            name: item.name.clone(),
+            description: item.description.clone(),
+            plaintext: item.plaintext.clone(),
+            cost: item.gold.base,
+            total_gold: item.gold.total,
+            sell_gold: item.gold.sell,
+            stats: item.stats.clone(),
+            tags: item.tags.clone(),
+            builds_from: parse_ids(item.from.as_ref()),
+            builds_into: parse_ids(item.into.as_ref()),
+        }
+    }
+}
+
+impl ItemTree {
+    #[must_use]
+    pub fn new(id: i64, items: &HashMap<String, Item>) -> Option<Self> {
+        let item = ItemInfo::new(id, items.get(&id.to_string())?);
+        let components = item
+            .builds_from
+            .iter()
+            .filter_map(|c| Self::new(*c, items))
+            .collect();
+        Some(Self { item, components })
+    }
+}
@@ -1,27 +1,62 @@
 use crate::util::sha256;
-use ddragon::models::Augment;
+use ddragon::cache_middleware::CacheMiddleware;
+use ddragon::models::cdragon::augments::AugmentsResponse;
+use ddragon::models::champion::Champion;
 use ddragon::models::champions::ChampionShort;
 use ddragon::models::items::Item;
 use ddragon::models::runes::RuneElement;
+use ddragon::models::{Augment, Champions, Items, Runes, SummonerSpells};
 use ddragon::{Client, ClientBuilder};
 use levenshtein::levenshtein;
 use lru::LruCache;
+use serde::Deserialize;
 use serde::de::DeserializeOwned;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
 use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 use thiserror::Error;
 use ugg_types::mappings::{self, Rank};
 use ugg_types::matchups::{MatchupData, Matchups};
 use ugg_types::overview::{ChampOverview, Overview};
+use ugg_types::rankings::{RankingData, Rankings};
 use ugg_types::rune::RuneExtended;
+use ugg_types::stats::StatSettings;
 use ureq::Agent;
 
+mod cache;
+mod item_info;
+mod report;
+mod tier_list;
 mod util;
 
+pub use cache::{CacheUsage, cache_usage, clear_cache, prune_cache};
+pub use item_info::{ItemInfo, ItemTree};
+pub use report::{
+    AbilitiesReport, ArenaReport, AugmentEntry, BuildReport, ChampionRef, ItemSetReport,
+    MatchupEntry, MatchupsReport, Named, REPORT_SCHEMA_VERSION, Record, RunePathReport,
+    RunesReport, StatEntry, SynergyEntry,
+};
+pub use tier_list::TierListEntry;
+
 type UggAPIVersions = HashMap<String, HashMap<String, String>>;
 
+/// A champion's matchups in one role, and the role they're for.
+type RoleMatchups = (MatchupData, mappings::Role);
+
+/// The shape of a single-champion ddragon file, which ddragon doesn't expose.
+#[derive(Deserialize)]
+struct ChampionFile {
+    data: HashMap<String, Champion>,
+}
+
+/// How many requests are made at once when fetching data for many champions.
+const FETCH_WORKERS: usize = 8;
+
+/// The locale ddragon serves when none is requested.
+const DEFAULT_LOCALE: &str = "en_US";
+
 #[derive(Error, Debug)]
 pub enum UggError {
     #[error("DDragon error")]
@@ -34,15 +69,76 @@ pub enum UggError {
     MissingRegionOrRank,
     #[error("Missing role entry")]
     MissingRole,
+    #[error("Cache error: {0}")]
+    CacheError(#[from] cacache::Error),
+    #[error("Unsupported locale: {0}")]
+    UnsupportedLocale(String),
     #[error("Unknown error occurred")]
     Unknown,
 }
 
+/// How long a single request to u.gg or ddragon may take, including reading
+/// the response, before it fails with [`ErrorKind::TimedOut`].
+pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// A broad category for an [`UggError`], for explaining it to users.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// u.gg has no data for the requested champion, mode, region or role.
+    NoData,
+    TimedOut,
+    /// u.gg or ddragon couldn't be reached at all.
+    Offline,
+    /// A response was received but couldn't be parsed.
+    BadData,
+    Other,
+}
+
+impl UggError {
+    #[must_use]
+    pub fn kind(&self) -> ErrorKind {
+        let request_kind = |e: &ureq::Error| match e {
+            ureq::Error::StatusCode(404) => ErrorKind::NoData,
+            ureq::Error::Timeout(_) => ErrorKind::TimedOut,
+            ureq::Error::HostNotFound | ureq::Error::ConnectionFailed | ureq::Error::Io(_) => {
+                ErrorKind::Offline
+            }
+            _ => ErrorKind::Other,
+        };
+        match self {
+            Self::RequestError(e) | Self::DDragonError(ddragon::ClientError::Request(e)) => {
+                request_kind(e)
+            }
+            Self::MissingRegionOrRank | Self::MissingRole => ErrorKind::NoData,
+            Self::ParseError(_) => ErrorKind::BadData,
+            Self::DDragonError(_)
+            | Self::CacheError(_)
+            | Self::UnsupportedLocale(_)
+            | Self::Unknown => ErrorKind::Other,
+        }
+    }
+}
+
+/// Details about the most recently fetched build.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FetchInfo {
+    /// The rank bracket the build was taken from.
+    pub rank: Rank,
+    /// Whether it came from the in-memory cache rather than u.gg.
+    pub cached: bool,
+}
+
 pub struct DataApi {
     agent: Agent,
     ddragon: Client,
+    ddragon_agent: Agent,
+    locale: Option<String>,
+    /// The rank bracket to try before falling back to [`Rank::preferred_order`].
+    rank: Option<Rank>,
     overview_cache: RefCell<LruCache<String, ChampOverview>>,
-    matchup_cache: RefCell<LruCache<String, Matchups>>,
+    matchup_cache: RefCell<LruCache<String, RoleMatchups>>,
+    last_fetch: Cell<Option<FetchInfo>>,
+    rankings_cache: RefCell<LruCache<String, Rankings>>,
 }
 
 #[derive(Debug, Clone)]
@@ -63,32 +159,127 @@ pub struct UggApi {
     pub runes: HashMap<i64, RuneExtended<RuneElement>>,
     pub summoner_spells: HashMap<i64, String>,
     pub arena_augments: HashMap<i64, Augment>,
+    pub locale: Option<String>,
+    /// English champion names keyed by champion id, only populated when a
+    /// non-English locale is in use so searches can match either name.
+    pub champ_names_en: HashMap<String, String>,
+    /// Thresholds used when smoothing and flagging win rates.
+    pub stat_settings: StatSettings,
+}
+
+/// Lowercases and drops everything but letters and digits, so `Kai'Sa`,
+/// `kaisa` and `Kaisa` all compare equal.
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn fetch_data<T: DeserializeOwned>(agent: &Agent, url: &str) -> Result<T, UggError> {
+    simd_json::serde::from_reader::<ureq::BodyReader<'_>, T>(
+        agent
+            .get(url)
+            .call()
+            .map_err(Box::new)?
+            .into_body()
+            .as_reader(),
+    )
+    .map_err(UggError::ParseError)
+}
+
+/// Fetches the data for each `(key, data path)` pair, a few at a time.
+fn fetch_all<'a, T: DeserializeOwned + Send>(
+    agent: &Agent,
+    paths: &'a [(String, String)],
+    url: impl Fn(&str) -> String + Sync,
+) -> Vec<(&'a String, &'a String, Result<T, UggError>)> {
+    let chunk_size = paths.len().div_ceil(FETCH_WORKERS).max(1);
+    let url = &url;
+    std::thread::scope(|s| {
+        paths
+            .chunks(chunk_size)
+            .map(|chunk| {
+                s.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|(key, data_path)| {
+                            (key, data_path, fetch_data::<T>(agent, &url(data_path)))
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap_or_default())
+            .collect()
+    })
 }
 
 impl DataApi {
-    pub fn new(version: Option<String>, cache_dir: Option<PathBuf>) -> Result<Self, UggError> {
+    pub fn new(
+        version: Option<String>,
+        cache_dir: Option<PathBuf>,
+        locale: Option<String>,
+    ) -> Result<Self, UggError> {
         let mut client_builder = ClientBuilder::new();
         let safe_dir = cache_dir.ok_or(UggError::Unknown)?;
         if let Some(v) = version {
             client_builder = client_builder.version(v.as_str());
         }
-        if let Some(dir) = safe_dir.clone().to_str() {
+        let ddragon_agent: Agent = if let Some(dir) = safe_dir.clone().to_str() {
             client_builder = client_builder.cache(dir);
-        }
+            Agent::config_builder()
+                .timeout_global(Some(REQUEST_TIMEOUT))
+                .middleware(CacheMiddleware::new(dir))
+                .build()
+                .into()
+        } else {
+            Agent::config_builder()
+                .timeout_global(Some(REQUEST_TIMEOUT))
+                .build()
+                .into()
+        };
+        client_builder = client_builder.agent(ddragon_agent.clone());
 
         let cache_size = NonZeroUsize::new(50).unwrap_or(NonZeroUsize::MIN);
         Ok(Self {
-            agent: Agent::new_with_defaults(),
+            agent: Agent::config_builder()
+                .timeout_global(Some(REQUEST_TIMEOUT))
+                .build()
+                .into(),
             ddragon: client_builder.build()?,
+            ddragon_agent,
+            locale: locale.filter(|l| l != DEFAULT_LOCALE),
+            rank: None,
             overview_cache: RefCell::new(LruCache::new(cache_size)),
-            matchup_cache: RefCell::new(LruCache::new(cache_size)),
+            // Counter picks need matchups for every champion at once, so this
+            // holds one role of each rather than whole matchup files.
+            matchup_cache: RefCell::new(LruCache::new(
+                NonZeroUsize::new(256).unwrap_or(NonZeroUsize::MIN),
+            )),
+            last_fetch: Cell::new(None),
+            // Tier lists need rankings for every champion at once.
+            rankings_cache: RefCell::new(LruCache::new(
+                NonZeroUsize::new(512).unwrap_or(NonZeroUsize::MIN),
+            )),
         })
     }
 
-    fn get_data<T: DeserializeOwned>(&self, url: &str) -> Result<T, UggError> {
+    /// Fetches a ddragon data file in the configured locale. The ddragon
+    /// client only ever requests `en_US` data, so localized files are
+    /// requested directly, sharing the same on-disk cache.
+    fn get_localized_data<T: DeserializeOwned>(
+        &self,
+        locale: &str,
+        endpoint: &str,
+    ) -> Result<T, UggError> {
         simd_json::serde::from_reader::<ureq::BodyReader<'_>, T>(
-            self.agent
-                .get(url)
+            self.ddragon_agent
+                .get(&format!(
+                    "https://ddragon.leagueoflegends.com/cdn/{}/data/{locale}/{endpoint}.json",
+                    self.ddragon.version
+                ))
                 .call()
                 .map_err(Box::new)?
                 .into_body()
@@ -97,6 +288,19 @@ impl DataApi {
         .map_err(UggError::ParseError)
     }
 
+    /// Rank brackets in the order they should be tried.
+    fn rank_order(&self) -> impl Iterator<Item = Rank> {
+        self.rank.into_iter().chain(
+            Rank::preferred_order()
+                .into_iter()
+                .filter(|r| Some(*r) != self.rank),
+        )
+    }
+
+    fn get_data<T: DeserializeOwned>(&self, url: &str) -> Result<T, UggError> {
+        fetch_data(&self.agent, url)
+    }
+
     pub fn get_current_version(&mut self) -> String {
         self.ddragon.version.clone()
     }
@@ -105,16 +309,55 @@ impl DataApi {
         self.get_data("https://ddragon.leagueoflegends.com/api/versions.json")
     }
 
+    pub fn get_supported_locales(&self) -> Result<Vec<String>, UggError> {
+        self.get_data("https://ddragon.leagueoflegends.com/cdn/languages.json")
+    }
+
     pub fn get_champ_data(&self) -> Result<HashMap<String, ChampionShort>, UggError> {
-        Ok(self.ddragon.champions()?.data)
+        match self.locale {
+            Some(ref locale) => Ok(self
+                .get_localized_data::<Champions>(locale, "champion")?
+                .data),
+            None => Ok(self.ddragon.champions()?.data),
+        }
+    }
+
+    /// Returns full champion data, including spells and their descriptions.
+    pub fn get_champion(&self, champ: &ChampionShort) -> Result<Champion, UggError> {
+        match self.locale {
+            Some(ref locale) => self
+                .get_localized_data::<ChampionFile>(locale, &format!("champion/{}", champ.id))?
+                .data
+                .remove(&champ.id)
+                .ok_or(UggError::Unknown),
+            None => Ok(self.ddragon.champion(&champ.id)?),
+        }
+    }
+
+    /// Returns English champion names keyed by champion id, regardless of the
+    /// configured locale.
+    pub fn get_champ_names_en(&self) -> Result<HashMap<String, String>, UggError> {
+        Ok(self
+            .ddragon
+            .champions()?
+            .data
+            .into_iter()
+            .map(|(id, champ)| (id, champ.name))
+            .collect())
     }
 
     pub fn get_items(&self) -> Result<HashMap<String, Item>, UggError> {
-        Ok(self.ddragon.items()?.data)
+        match self.locale {
+            Some(ref locale) => Ok(self.get_localized_data::<Items>(locale, "item")?.data),
+            None => Ok(self.ddragon.items()?.data),
+        }
     }
 
     pub fn get_runes(&self) -> Result<HashMap<i64, RuneExtended<RuneElement>>, UggError> {
-        let rune_data = self.ddragon.runes()?;
+        let rune_data = match self.locale {
+            Some(ref locale) => self.get_localized_data::<Runes>(locale, "runesReforged")?,
+            None => self.ddragon.runes()?,
+        };
 
         let mut processed_data = HashMap::new();
         for class in rune_data {
@@ -136,7 +379,10 @@ impl DataApi {
     }
 
     pub fn get_summoner_spells(&self) -> Result<HashMap<i64, String>, UggError> {
-        let summoner_data = self.ddragon.summoner_spells()?;
+        let summoner_data = match self.locale {
+            Some(ref locale) => self.get_localized_data::<SummonerSpells>(locale, "summoner")?,
+            None => self.ddragon.summoner_spells()?,
+        };
 
         let mut reduced_data: HashMap<i64, String> = HashMap::new();
         for (_spell, spell_info) in summoner_data.data {
@@ -149,7 +395,30 @@ impl DataApi {
     }
 
     pub fn get_arena_augments(&self) -> Result<HashMap<i64, Augment>, UggError> {
-        let augment_data = self.ddragon.arena_augments()?;
+        let augment_data = match self.locale {
+            Some(ref locale) => {
+                let cdragon_version = self
+                    .ddragon
+                    .version
+                    .split('.')
+                    .take(2)
+                    .collect::<Vec<_>>()
+                    .join(".");
+                simd_json::serde::from_reader::<ureq::BodyReader<'_>, AugmentsResponse>(
+                    self.ddragon_agent
+                        .get(&format!(
+                            "https://raw.communitydragon.org/{cdragon_version}/cdragon/arena/{}.json",
+                            locale.to_lowercase()
+                        ))
+                        .call()
+                        .map_err(Box::new)?
+                        .into_body()
+                        .as_reader(),
+                )?
+                .augments
+            }
+            None => self.ddragon.arena_augments()?,
+        };
         let mut reduced_data: HashMap<i64, Augment> = HashMap::new();
         for augment in augment_data {
             reduced_data.insert(augment.id, augment);
@@ -188,12 +457,13 @@ impl DataApi {
         );
         let cache_path = format!("{data_path}-{region}-{role}");
 
-        let stats_data = if let Some(data) = self
+        let cached = self
             .overview_cache
             .try_borrow_mut()
             .ok()
-            .and_then(|mut c| c.get(&sha256(&cache_path)).cloned())
-        {
+            .and_then(|mut c| c.get(&sha256(&cache_path)).cloned());
+        let was_cached = cached.is_some();
+        let stats_data = if let Some(data) = cached {
             Ok(data)
         } else {
             self.get_data::<ChampOverview>(&format!("https://stats2.u.gg/lol/1.5/{data_path}.json"))
@@ -203,14 +473,19 @@ impl DataApi {
             c.put(sha256(&cache_path), stats_data.clone());
         }
 
-        let data_by_role = Rank::preferred_order()
-            .iter()
+        let (rank, data_by_role) = self
+            .rank_order()
             .find_map(|rank| {
                 stats_data
                     .get(&region)
-                    .and_then(|region_data| region_data.get(rank))
+                    .and_then(|region_data| region_data.get(&rank))
+                    .map(|data| (rank, data))
             })
             .ok_or(UggError::MissingRegionOrRank)?;
+        self.last_fetch.set(Some(FetchInfo {
+            rank,
+            cached: was_cached,
+        }));
 
         data_by_role
             .get_key_value(&role)
@@ -225,6 +500,45 @@ impl DataApi {
             .ok_or(UggError::MissingRole)
     }
 
+    fn matchups_api_version<'a>(
+        patch: &str,
+        api_versions: &'a HashMap<String, HashMap<String, String>>,
+    ) -> &'a str {
+        if api_versions.contains_key(patch) && api_versions[patch].contains_key("matchups") {
+            api_versions[patch]["matchups"].as_str()
+        } else {
+            "1.5.0"
+        }
+    }
+
+    fn cached_matchups(&self, cache_path: &str) -> Option<(MatchupData, mappings::Role)> {
+        self.matchup_cache
+            .try_borrow_mut()
+            .ok()
+            .and_then(|mut c| c.get(&sha256(cache_path)).cloned())
+    }
+
+    fn cache_matchups(&self, cache_path: &str, data: &(MatchupData, mappings::Role)) {
+        if let Ok(mut c) = self.matchup_cache.try_borrow_mut() {
+            c.put(sha256(cache_path), data.clone());
+        }
+    }
+
+    fn matchups_path(
+        patch: &str,
+        champ: &ChampionShort,
+        mode: mappings::Mode,
+        api_versions: &HashMap<String, HashMap<String, String>>,
+    ) -> String {
+        format!(
+            "{}/{}/{}/{}",
+            patch,
+            mode.to_api_string(),
+            champ.key.as_str(),
+            Self::matchups_api_version(patch, api_versions)
+        )
+    }
+
     pub fn get_matchups(
         &self,
         patch: &str,
@@ -234,60 +548,327 @@ impl DataApi {
         mode: mappings::Mode,
         api_versions: &HashMap<String, HashMap<String, String>>,
     ) -> Result<(MatchupData, mappings::Role), UggError> {
+        let data_path = Self::matchups_path(patch, champ, mode, api_versions);
+        let cache_path = format!("{data_path}-{region}-{role}");
+        if let Some(data) = self.cached_matchups(&cache_path) {
+            return Ok(data);
+        }
+
+        let matchup_data = self.get_data::<Matchups>(&format!(
+            "https://stats2.u.gg/lol/1.5/matchups/{data_path}.json",
+        ))?;
+        let ranks = self.rank_order().collect::<Vec<_>>();
+        let data = matchups_for_role(&matchup_data, &ranks, role, region)?;
+        self.cache_matchups(&cache_path, &data);
+        Ok(data)
+    }
+
+    /// Fetches matchups for each of `champs`, keyed by champion key. Like
+    /// [`DataApi::get_rankings`], uncached champions are requested in
+    /// parallel, champions without data are left out, and an error is only
+    /// returned if nothing could be fetched.
+    pub fn get_all_matchups(
+        &self,
+        patch: &str,
+        champs: &[&ChampionShort],
+        role: mappings::Role,
+        region: mappings::Region,
+        mode: mappings::Mode,
+        api_versions: &HashMap<String, HashMap<String, String>>,
+    ) -> Result<HashMap<String, (MatchupData, mappings::Role)>, UggError> {
+        self.store_matchups(
+            self.matchups_request(patch, champs, role, region, mode, api_versions)
+                .fetch(),
+        )
+    }
+
+    /// Splits `champs` into those with cached matchups and those that still
+    /// have to be fetched.
+    fn matchups_request(
+        &self,
+        patch: &str,
+        champs: &[&ChampionShort],
+        role: mappings::Role,
+        region: mappings::Region,
+        mode: mappings::Mode,
+        api_versions: &HashMap<String, HashMap<String, String>>,
+    ) -> MatchupsRequest {
+        let mut cached = HashMap::new();
+        let mut missing = vec![];
+        for champ in champs {
+            let data_path = Self::matchups_path(patch, champ, mode, api_versions);
+            match self.cached_matchups(&format!("{data_path}-{region}-{role}")) {
+                Some(data) => {
+                    cached.insert(champ.key.clone(), data);
+                }
+                None => missing.push((champ.key.clone(), data_path)),
+            }
+        }
+
+        MatchupsRequest {
+            agent: self.agent.clone(),
+            ranks: self.rank_order().collect(),
+            role,
+            region,
+            cached,
+            missing,
+        }
+    }
+
+    /// Caches what a [`MatchupsRequest`] fetched, returning the matchups of
+    /// every champion it covers.
+    fn store_matchups(
+        &self,
+        fetched: FetchedMatchups,
+    ) -> Result<HashMap<String, (MatchupData, mappings::Role)>, UggError> {
+        let FetchedMatchups {
+            mut matchups,
+            fetched,
+            role,
+            region,
+        } = fetched;
+        let mut first_error = None;
+        for (key, data_path, result) in fetched {
+            match result {
+                Ok(data) => {
+                    self.cache_matchups(&format!("{data_path}-{region}-{role}"), &data);
+                    matchups.insert(key, data);
+                }
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        match first_error {
+            Some(e) if matchups.is_empty() => Err(e),
+            _ => Ok(matchups),
+        }
+    }
+
+    /// Fetches rankings for each of `champs`, keyed by champion key. Uncached
+    /// champions are requested in parallel. Champions without data are left
+    /// out, and an error is only returned if nothing could be fetched.
+    pub fn get_rankings(
+        &self,
+        patch: &str,
+        champs: &[&ChampionShort],
+        mode: mappings::Mode,
+        api_versions: &HashMap<String, HashMap<String, String>>,
+    ) -> Result<HashMap<String, Rankings>, UggError> {
+        self.store_rankings(
+            self.rankings_request(patch, champs, mode, api_versions)
+                .fetch(),
+        )
+    }
+
+    /// Splits `champs` into those with cached rankings and those that still
+    /// have to be fetched.
+    fn rankings_request(
+        &self,
+        patch: &str,
+        champs: &[&ChampionShort],
+        mode: mappings::Mode,
+        api_versions: &HashMap<String, HashMap<String, String>>,
+    ) -> RankingsRequest {
         let api_version =
-            if api_versions.contains_key(patch) && api_versions[patch].contains_key("matchups") {
-                api_versions[patch]["matchups"].as_str()
+            if api_versions.contains_key(patch) && api_versions[patch].contains_key("rankings") {
+                api_versions[patch]["rankings"].as_str()
             } else {
                 "1.5.0"
             };
-        let data_path = &format!(
-            "{}/{}/{}/{}",
-            patch,
-            mode.to_api_string(),
-            champ.key.as_str(),
-            api_version
-        );
-        let cache_path = format!("{data_path}-{region}-{role}");
 
-        let matchup_data = if let Some(data) = self
-            .matchup_cache
-            .try_borrow_mut()
-            .ok()
-            .and_then(|mut c| c.get(&sha256(&cache_path)).cloned())
-        {
-            Ok(data)
-        } else {
-            self.get_data::<Matchups>(&format!(
-                "https://stats2.u.gg/lol/1.5/matchups/{data_path}.json",
-            ))
-        }?;
+        let mut cached = HashMap::new();
+        let mut missing = vec![];
+        for champ in champs {
+            let data_path = format!(
+                "{}/{}/{}/{}",
+                patch,
+                mode.to_api_string(),
+                champ.key.as_str(),
+                api_version
+            );
+            match self
+                .rankings_cache
+                .try_borrow_mut()
+                .ok()
+                .and_then(|mut c| c.get(&sha256(&data_path)).cloned())
+            {
+                Some(data) => {
+                    cached.insert(champ.key.clone(), data);
+                }
+                None => missing.push((champ.key.clone(), data_path)),
+            }
+        }
 
-        let data_by_role = Rank::preferred_order()
-            .iter()
-            .find_map(|rank| {
-                matchup_data
-                    .get(&region)
-                    .and_then(|region_data| region_data.get(rank))
-            })
-            .ok_or(UggError::MissingRegionOrRank)?;
+        RankingsRequest {
+            agent: self.agent.clone(),
+            cached,
+            missing,
+        }
+    }
 
-        data_by_role
-            .get_key_value(&role)
-            .or_else(|| {
-                data_by_role
-                    .iter()
-                    .max_by_key(|(_, data)| data.data.total_matches)
-                    .map(|(role, _)| role)
-                    .and_then(|r| data_by_role.get_key_value(r))
-            })
-            .map(|(role, data)| (data.data.clone(), *role))
-            .ok_or(UggError::MissingRole)
+    /// Caches what a [`RankingsRequest`] fetched, returning every ranking it
+    /// covers.
+    fn store_rankings(
+        &self,
+        fetched: FetchedRankings,
+    ) -> Result<HashMap<String, Rankings>, UggError> {
+        let FetchedRankings {
+            mut rankings,
+            fetched,
+        } = fetched;
+        let mut first_error = None;
+        for (key, data_path, result) in fetched {
+            match result {
+                Ok(data) => {
+                    if let Ok(mut c) = self.rankings_cache.try_borrow_mut() {
+                        c.put(sha256(&data_path), data.clone());
+                    }
+                    rankings.insert(key, data);
+                }
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        match first_error {
+            Some(e) if rankings.is_empty() => Err(e),
+            _ => Ok(rankings),
+        }
+    }
+}
+
+/// Picks the matchups for `role` out of a champion's matchup file, from the
+/// first of `ranks` with data, falling back to its most played role.
+fn matchups_for_role(
+    matchup_data: &Matchups,
+    ranks: &[Rank],
+    role: mappings::Role,
+    region: mappings::Region,
+) -> Result<(MatchupData, mappings::Role), UggError> {
+    let data_by_role = ranks
+        .iter()
+        .find_map(|rank| {
+            matchup_data
+                .get(&region)
+                .and_then(|region_data| region_data.get(rank))
+        })
+        .ok_or(UggError::MissingRegionOrRank)?;
+
+    data_by_role
+        .get_key_value(&role)
+        .or_else(|| {
+            data_by_role
+                .iter()
+                .max_by_key(|(_, data)| data.data.total_matches)
+        })
+        .map(|(role, data)| (data.data.clone(), *role))
+        .ok_or(UggError::MissingRole)
+}
+
+/// Matchups that aren't cached yet, which can be fetched on another thread
+/// and handed back with [`UggApi::store_all_matchups`].
+pub struct MatchupsRequest {
+    agent: Agent,
+    ranks: Vec<Rank>,
+    role: mappings::Role,
+    region: mappings::Region,
+    cached: HashMap<String, RoleMatchups>,
+    missing: Vec<(String, String)>,
+}
+
+/// The result of [`MatchupsRequest::fetch`].
+pub struct FetchedMatchups {
+    matchups: HashMap<String, RoleMatchups>,
+    fetched: Vec<(String, String, Result<RoleMatchups, UggError>)>,
+    role: mappings::Role,
+    region: mappings::Region,
+}
+
+impl MatchupsRequest {
+    /// Whether everything is cached already, so fetching won't block.
+    #[must_use]
+    pub fn is_cached(&self) -> bool {
+        self.missing.is_empty()
+    }
+
+    /// Fetches every missing champion, keeping only the role that was asked
+    /// for out of each file.
+    #[must_use]
+    pub fn fetch(self) -> FetchedMatchups {
+        let fetched = fetch_all::<Matchups>(&self.agent, &self.missing, |data_path| {
+            format!("https://stats2.u.gg/lol/1.5/matchups/{data_path}.json")
+        })
+        .into_iter()
+        .map(|(key, data_path, result)| {
+            (
+                key.clone(),
+                data_path.clone(),
+                result
+                    .and_then(|data| matchups_for_role(&data, &self.ranks, self.role, self.region)),
+            )
+        })
+        .collect();
+        FetchedMatchups {
+            matchups: self.cached,
+            fetched,
+            role: self.role,
+            region: self.region,
+        }
+    }
+}
+
+/// Rankings that aren't cached yet, which can be fetched on another thread
+/// and handed back with [`UggApi::store_all_rankings`].
+pub struct RankingsRequest {
+    agent: Agent,
+    cached: HashMap<String, Rankings>,
+    missing: Vec<(String, String)>,
+}
+
+/// The result of [`RankingsRequest::fetch`].
+pub struct FetchedRankings {
+    rankings: HashMap<String, Rankings>,
+    fetched: Vec<(String, String, Result<Rankings, UggError>)>,
+}
+
+impl RankingsRequest {
+    /// Whether everything is cached already, so fetching won't block.
+    #[must_use]
+    pub fn is_cached(&self) -> bool {
+        self.missing.is_empty()
+    }
+
+    #[must_use]
+    pub fn fetch(self) -> FetchedRankings {
+        let fetched = fetch_all::<Rankings>(&self.agent, &self.missing, |data_path| {
+            format!("https://stats2.u.gg/lol/1.5/rankings/{data_path}.json")
+        })
+        .into_iter()
+        .map(|(key, data_path, result)| (key.clone(), data_path.clone(), result))
+        .collect();
+        FetchedRankings {
+            rankings: self.cached,
+            fetched,
+        }
     }
 }
 
 impl UggApi {
-    pub fn new(version: Option<String>, cache_dir: Option<PathBuf>) -> Result<Self, UggError> {
-        let mut inner_api = DataApi::new(version, cache_dir.clone())?;
+    pub fn new(
+        version: Option<String>,
+        cache_dir: Option<PathBuf>,
+        locale: Option<String>,
+    ) -> Result<Self, UggError> {
+        let mut inner_api = DataApi::new(version, cache_dir.clone(), locale.clone())?;
+
+        if let Some(ref l) = inner_api.locale
+            && !inner_api.get_supported_locales()?.contains(l)
+        {
+            return Err(UggError::UnsupportedLocale(l.clone()));
+        }
 
         let mut current_version = inner_api.get_current_version();
         let allowed_versions = inner_api.get_supported_versions()?;
@@ -306,7 +887,8 @@ impl UggApi {
                 .iter()
                 .any(|v| v.ddragon == current_version)
             {
-                inner_api = DataApi::new(Some(default_if_fails.ddragon.clone()), cache_dir)?;
+                inner_api =
+                    DataApi::new(Some(default_if_fails.ddragon.clone()), cache_dir, locale)?;
                 current_version = inner_api.get_current_version();
             }
         } else {
@@ -320,6 +902,12 @@ impl UggApi {
         let arena_augments = inner_api
             .get_arena_augments()
             .unwrap_or_else(|_| HashMap::new());
+        let champ_names_en = if inner_api.locale.is_some() {
+            inner_api.get_champ_names_en()?
+        } else {
+            HashMap::new()
+        };
+        let locale = inner_api.locale.clone();
 
         let mut patch_version_split = current_version.split('.').collect::<Vec<&str>>();
         patch_version_split.remove(patch_version_split.len() - 1);
@@ -336,9 +924,25 @@ impl UggApi {
             runes,
             summoner_spells,
             arena_augments,
+            locale,
+            champ_names_en,
+            stat_settings: StatSettings::default(),
         })
     }
 
+    /// The newest patch u.gg has data for right now, which may be newer
+    /// than the one this was built for.
+    pub fn latest_version(&self) -> Result<String, UggError> {
+        let ugg_api_versions = self.api.get_ugg_api_versions()?;
+        self.api
+            .get_supported_versions()?
+            .into_iter()
+            .find(|v| {
+                ugg_api_versions.contains_key(&v.split('.').take(2).collect::<Vec<_>>().join("_"))
+            })
+            .ok_or(UggError::Unknown)
+    }
+
     pub fn find_champ(&self, name: &str) -> &ChampionShort {
         if self.champ_data.contains_key(name) {
             &self.champ_data[name]
@@ -349,19 +953,25 @@ impl UggApi {
             let mut substring_lowest_dist = usize::MAX;
             let mut substring_closest_champ: Option<&ChampionShort> = None;
 
+            let query_compare = name.to_lowercase();
             for value in self.champ_data.values() {
-                let query_compare = name.to_ascii_lowercase();
-                let champ_compare = value.name.to_ascii_lowercase();
-                // Prefer matches where search query is an exact starting substring
-                let distance = levenshtein(query_compare.as_str(), champ_compare.as_str());
-                if champ_compare.starts_with(&query_compare) {
-                    if distance <= substring_lowest_dist {
-                        substring_lowest_dist = distance;
-                        substring_closest_champ = Some(value);
+                // Match against both the localized and English names
+                for champ_name in [Some(&value.name), self.champ_names_en.get(&value.id)]
+                    .into_iter()
+                    .flatten()
+                {
+                    let champ_compare = champ_name.to_lowercase();
+                    // Prefer matches where search query is an exact starting substring
+                    let distance = levenshtein(query_compare.as_str(), champ_compare.as_str());
+                    if champ_compare.starts_with(&query_compare) {
+                        if distance <= substring_lowest_dist {
+                            substring_lowest_dist = distance;
+                            substring_closest_champ = Some(value);
+                        }
+                    } else if distance <= lowest_distance {
+                        lowest_distance = distance;
+                        closest_champ = value;
                     }
-                } else if distance <= lowest_distance {
-                    lowest_distance = distance;
-                    closest_champ = value;
                 }
             }
 
@@ -369,6 +979,78 @@ impl UggApi {
         }
     }
 
+    /// Finds a champion by id, name or English name, ignoring case and
+    /// punctuation. Unlike [`Self::find_champ`], this never guesses.
+    #[must_use]
+    pub fn match_champ(&self, name: &str) -> Option<&ChampionShort> {
+        let wanted = normalize_name(name);
+        self.champ_data
+            .values()
+            .find(|c| self.champ_names(c).any(|n| normalize_name(n) == wanted))
+    }
+
+    /// Up to `count` champions whose names start with or are a few edits
+    /// away from `name`, closest first.
+    #[must_use]
+    pub fn suggest_champs(&self, name: &str, count: usize) -> Vec<&ChampionShort> {
+        let wanted = normalize_name(name);
+        let mut close = self
+            .champ_data
+            .values()
+            .filter_map(|c| {
+                let distance = self
+                    .champ_names(c)
+                    .map(|n| {
+                        let n = normalize_name(n);
+                        if n.starts_with(&wanted) {
+                            0
+                        } else {
+                            levenshtein(&wanted, &n)
+                        }
+                    })
+                    .min()?;
+                (distance <= 2.max(wanted.len() / 3)).then_some((distance, c))
+            })
+            .collect::<Vec<_>>();
+        close.sort_by(|(a_dist, a), (b_dist, b)| a_dist.cmp(b_dist).then(a.name.cmp(&b.name)));
+        close.into_iter().take(count).map(|(_, c)| c).collect()
+    }
+
+    fn champ_names<'a>(&'a self, champ: &'a ChampionShort) -> impl Iterator<Item = &'a String> {
+        [
+            Some(&champ.id),
+            Some(&champ.name),
+            self.champ_names_en.get(&champ.id),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    pub fn get_champion(&self, champ: &ChampionShort) -> Result<Champion, UggError> {
+        self.api.get_champion(champ)
+    }
+
+    #[must_use]
+    pub fn item_info(&self, id: i64) -> Option<ItemInfo> {
+        self.items
+            .get(&id.to_string())
+            .map(|item| ItemInfo::new(id, item))
+    }
+
+    #[must_use]
+    pub fn item_tree(&self, id: i64) -> Option<ItemTree> {
+        ItemTree::new(id, &self.items)
+    }
+
+    /// Total gold cost of a set of items, ignoring any unknown ids.
+    #[must_use]
+    pub fn total_gold(&self, ids: &[i64]) -> i64 {
+        ids.iter()
+            .filter_map(|id| self.items.get(&id.to_string()))
+            .map(|item| item.gold.total)
+            .sum()
+    }
+
     pub fn get_stats(
         &self,
         champ: &ChampionShort,
@@ -388,6 +1070,13 @@ impl UggApi {
         )
     }
 
+    /// Which rank and cache the last successful [`UggApi::get_stats`] call
+    /// used.
+    #[must_use]
+    pub fn last_fetch(&self) -> Option<FetchInfo> {
+        self.api.last_fetch.get()
+    }
+
     pub fn get_matchups(
         &self,
         champ: &ChampionShort,
@@ -404,11 +1093,156 @@ impl UggApi {
             &self.api_versions,
         )
     }
+
+    /// Returns the matchups of each of `champs`, keyed by champion key, along
+    /// with the role they were found in.
+    pub fn get_all_matchups(
+        &self,
+        champs: &[&ChampionShort],
+        role: mappings::Role,
+        region: mappings::Region,
+        mode: mappings::Mode,
+    ) -> Result<HashMap<String, (MatchupData, mappings::Role)>, UggError> {
+        self.api.get_all_matchups(
+            &self.patch_version,
+            champs,
+            role,
+            region,
+            mode,
+            &self.api_versions,
+        )
+    }
+
+    /// What [`UggApi::get_all_matchups`] needs to fetch, so it can be done
+    /// without blocking the caller.
+    #[must_use]
+    pub fn all_matchups_request(
+        &self,
+        champs: &[&ChampionShort],
+        role: mappings::Role,
+        region: mappings::Region,
+        mode: mappings::Mode,
+    ) -> MatchupsRequest {
+        self.api.matchups_request(
+            &self.patch_version,
+            champs,
+            role,
+            region,
+            mode,
+            &self.api_versions,
+        )
+    }
+
+    /// Caches matchups fetched by [`UggApi::all_matchups_request`] and
+    /// returns them like [`UggApi::get_all_matchups`].
+    pub fn store_all_matchups(
+        &self,
+        fetched: FetchedMatchups,
+    ) -> Result<HashMap<String, (MatchupData, mappings::Role)>, UggError> {
+        self.api.store_matchups(fetched)
+    }
+
+    /// Returns a champion's rankings for every role it has data in.
+    pub fn get_rankings(
+        &self,
+        champ: &ChampionShort,
+        region: mappings::Region,
+        rank: Option<Rank>,
+        mode: mappings::Mode,
+    ) -> Result<HashMap<mappings::Role, RankingData>, UggError> {
+        let rankings =
+            self.api
+                .get_rankings(&self.patch_version, &[champ], mode, &self.api_versions)?;
+        rankings
+            .get(&champ.key)
+            .and_then(|r| tier_list::rankings_by_role(r, region, rank))
+            .cloned()
+            .ok_or(UggError::MissingRegionOrRank)
+    }
+
+    /// Returns the per-role rankings of every champion, keyed by champion id.
+    pub fn get_all_rankings(
+        &self,
+        region: mappings::Region,
+        rank: Option<Rank>,
+        mode: mappings::Mode,
+    ) -> Result<HashMap<String, HashMap<mappings::Role, RankingData>>, UggError> {
+        self.store_all_rankings(self.all_rankings_request(mode).fetch(), region, rank)
+    }
+
+    /// What [`UggApi::get_all_rankings`] needs to fetch, so it can be done
+    /// without blocking the caller.
+    #[must_use]
+    pub fn all_rankings_request(&self, mode: mappings::Mode) -> RankingsRequest {
+        let champs = self.champ_data.values().collect::<Vec<_>>();
+        self.api
+            .rankings_request(&self.patch_version, &champs, mode, &self.api_versions)
+    }
+
+    /// Caches rankings fetched by [`UggApi::all_rankings_request`] and
+    /// returns them like [`UggApi::get_all_rankings`].
+    pub fn store_all_rankings(
+        &self,
+        fetched: FetchedRankings,
+        region: mappings::Region,
+        rank: Option<Rank>,
+    ) -> Result<HashMap<String, HashMap<mappings::Role, RankingData>>, UggError> {
+        let rankings = self.api.store_rankings(fetched)?;
+        Ok(self
+            .champ_data
+            .values()
+            .filter_map(|champ| {
+                let by_role = tier_list::rankings_by_role(rankings.get(&champ.key)?, region, rank)?;
+                Some((champ.id.clone(), by_role.clone()))
+            })
+            .collect())
+    }
+
+    /// Builds a tier list for `role`. With [`mappings::Role::Automatic`],
+    /// every champion is listed once under its most played role.
+    pub fn get_tier_list(
+        &self,
+        role: mappings::Role,
+        region: mappings::Region,
+        rank: Option<Rank>,
+        mode: mappings::Mode,
+    ) -> Result<Vec<TierListEntry>, UggError> {
+        Ok(self.build_tier_list(&self.get_all_rankings(region, rank, mode)?, role))
+    }
+
+    /// Builds a tier list for `role` out of rankings from
+    /// [`UggApi::get_all_rankings`] or [`UggApi::store_all_rankings`].
+    #[must_use]
+    pub fn build_tier_list(
+        &self,
+        rankings: &HashMap<String, HashMap<mappings::Role, RankingData>>,
+        role: mappings::Role,
+    ) -> Vec<TierListEntry> {
+        rankings
+            .iter()
+            .filter_map(|(id, by_role)| {
+                let (role, data) = if role == mappings::Role::Automatic {
+                    by_role.iter().max_by_key(|(_, data)| data.matches)?
+                } else {
+                    by_role.get_key_value(&role)?
+                };
+                let champion = self.champ_data.get(id)?;
+                (data.matches > 0).then(|| TierListEntry {
+                    champion: champion.clone(),
+                    role: *role,
+                    data: *data,
+                })
+            })
+            .collect()
+    }
 }
 
 pub struct UggApiBuilder {
     version: Option<String>,
     cache_dir: Option<PathBuf>,
+    locale: Option<String>,
+    rank: Option<Rank>,
+    stat_settings: StatSettings,
 }
 
 impl UggApiBuilder {
@@ -417,6 +1251,9 @@ impl UggApiBuilder {
         Self {
             version: None,
             cache_dir: None,
+            locale: None,
+            rank: None,
+            stat_settings: StatSettings::DEFAULT,
         }
     }
 
@@ -432,8 +1269,34 @@ impl UggApiBuilder {
         self
     }
 
+    /// Sets the ddragon locale (e.g. `ko_KR`) used for champion, item, rune
+    /// and spell names.
+    #[must_use]
+    pub fn locale(mut self, locale: &str) -> Self {
+        self.locale = Some(locale.to_owned());
+        self
+    }
+
+    /// Sets the rank bracket builds and matchups are taken from, when u.gg
+    /// has data for it.
+    #[must_use]
+    pub fn rank(mut self, rank: Rank) -> Self {
+        self.rank = Some(rank);
+        self
+    }
+
+    /// Sets the thresholds used when smoothing and flagging win rates.
+    #[must_use]
+    pub fn stat_settings(mut self, settings: StatSettings) -> Self {
+        self.stat_settings = settings;
+        self
+    }
+
     pub fn build(self) -> Result<UggApi, UggError> {
-        UggApi::new(self.version, self.cache_dir)
+        let mut api = UggApi::new(self.version, self.cache_dir, self.locale)?;
+        api.api.rank = self.rank;
+        api.stat_settings = self.stat_settings;
+        Ok(api)
     }
 }
 
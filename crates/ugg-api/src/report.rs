@@ -0,0 +1,645 @@
+use ddragon::models::cdragon::AugmentRarity;
+use ddragon::models::champions::ChampionShort;
+use serde::Serialize;
+use std::collections::HashMap;
+use ugg_types::arena_overview::{ArenaOverviewData, ChampionSynergy};
+use ugg_types::default_overview::{Abilities, Items, LateItem, OverviewData};
+use ugg_types::mappings::{self, Shard};
+use ugg_types::matchups::{Matchup, MatchupData};
+use ugg_types::overview::Overview;
+use ugg_types::stats::{self, Stat, StatSettings};
+
+use crate::{UggApi, UggError};
+
+/// Bumped whenever a field is renamed, removed or changes meaning. Adding
+/// fields doesn't change the version.
+pub const REPORT_SCHEMA_VERSION: u32 = 1;
+
+/// A champion's build with every id resolved to a name, meant for
+/// serializing to JSON or YAML.
+#[derive(Debug, Clone, Serialize)]
+pub struct BuildReport {
+    pub schema_version: u32,
+    pub champion: ChampionRef,
+    pub role: String,
+    pub mode: String,
+    pub region: String,
+    pub build: String,
+    /// The ddragon version the data was fetched for, e.g. `14.20.1`.
+    pub patch: String,
+    pub record: Record,
+    pub low_sample_size: bool,
+    /// Only present outside of Arena.
+    pub runes: Option<RunesReport>,
+    /// Offense, flex and defense shards, in that order.
+    pub shards: Vec<Named>,
+    pub summoner_spells: Option<ItemSetReport>,
+    pub starting_items: ItemSetReport,
+    pub core_items: ItemSetReport,
+    pub fourth_items: Vec<StatEntry>,
+    pub fifth_items: Vec<StatEntry>,
+    pub sixth_items: Vec<StatEntry>,
+    pub abilities: AbilitiesReport,
+    /// Only present in Arena.
+    pub arena: Option<ArenaReport>,
+    /// Not available in ARAM or Arena.
+    pub matchups: Option<MatchupsReport>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChampionRef {
+    /// The ddragon id, e.g. `MonkeyKing`.
+    pub id: String,
+    /// The numeric key u.gg uses, e.g. `62`.
+    pub key: String,
+    pub name: String,
+}
+
+impl From<&ChampionShort> for ChampionRef {
+    fn from(champ: &ChampionShort) -> Self {
+        Self {
+            id: champ.id.clone(),
+            key: champ.key.clone(),
+            name: champ.name.clone(),
+        }
+    }
+}
+
+/// Wins and games played, with the raw and smoothed win rates precomputed.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Record {
+    pub wins: i64,
+    pub matches: i64,
+    pub win_rate: f64,
+    pub smoothed_win_rate: f64,
+}
+
+impl Record {
+    fn new(wins: i64, matches: i64, settings: &StatSettings) -> Self {
+        Self {
+            wins,
+            matches,
+            win_rate: stats::win_rate(wins, matches),
+            smoothed_win_rate: settings.smoothed_win_rate(wins, matches),
+        }
+    }
+}
+
+/// An id with its name, which is `null` if the id is unknown to ddragon.
+#[derive(Debug, Clone, Serialize)]
+pub struct Named {
+    pub id: i64,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RunePathReport {
+    pub id: i64,
+    pub name: String,
+    pub runes: Vec<Named>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RunesReport {
+    pub record: Record,
+    pub primary: RunePathReport,
+    pub secondary: RunePathReport,
+}
+
+/// A fixed set of picks (items or spells) and how it performed as a whole.
+#[derive(Debug, Clone, Serialize)]
+pub struct ItemSetReport {
+    pub record: Record,
+    pub items: Vec<Named>,
+}
+
+/// A single option (late item, augment, ...) and how it performed.
+#[derive(Debug, Clone, Serialize)]
+pub struct StatEntry {
+    pub id: i64,
+    pub name: Option<String>,
+    pub record: Record,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AbilitiesReport {
+    pub record: Record,
+    /// The ability leveled at each level, e.g. `["Q", "W", "E", ...]`.
+    pub order: Vec<String>,
+    /// The order abilities are maxed in, e.g. `["Q", "E", "W"]`.
+    pub max_order: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AugmentEntry {
+    pub id: i64,
+    pub name: Option<String>,
+    /// One of `silver`, `gold`, `prismatic` or `other`.
+    pub rarity: Option<&'static str>,
+    pub record: Record,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SynergyEntry {
+    pub champion: ChampionRef,
+    /// Arena has no wins, so `wins` here counts top four finishes.
+    pub record: Record,
+    pub first: i64,
+    pub average_placement: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ArenaReport {
+    pub augments: Vec<AugmentEntry>,
+    pub prismatic_items: Vec<StatEntry>,
+    pub consumables: Vec<StatEntry>,
+    pub champion_synergies: Vec<SynergyEntry>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MatchupEntry {
+    pub champion: ChampionRef,
+    pub record: Record,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MatchupsReport {
+    pub total_matches: i64,
+    pub best: Vec<MatchupEntry>,
+    pub worst: Vec<MatchupEntry>,
+}
+
+const fn rarity_name(rarity: &AugmentRarity) -> &'static str {
+    match rarity {
+        AugmentRarity::Silver => "silver",
+        AugmentRarity::Gold => "gold",
+        AugmentRarity::Prismatic => "prismatic",
+        _ => "other",
+    }
+}
+
+/// Resolves ids against the data an [`UggApi`] has already loaded.
+struct Resolver<'a> {
+    api: &'a UggApi,
+    champs_by_key: HashMap<&'a str, &'a ChampionShort>,
+}
+
+impl<'a> Resolver<'a> {
+    fn new(api: &'a UggApi) -> Self {
+        Self {
+            api,
+            champs_by_key: api
+                .champ_data
+                .values()
+                .map(|c| (c.key.as_str(), c))
+                .collect(),
+        }
+    }
+
+    fn record(&self, stat: &impl Stat) -> Record {
+        Record::new(stat.wins(), stat.matches(), &self.api.stat_settings)
+    }
+
+    fn item(&self, id: i64) -> Named {
+        Named {
+            id,
+            name: self.api.items.get(&id.to_string()).map(|i| i.name.clone()),
+        }
+    }
+
+    fn item_set(&self, items: &Items) -> ItemSetReport {
+        ItemSetReport {
+            record: self.record(items),
+            items: items.item_ids.iter().map(|id| self.item(*id)).collect(),
+        }
+    }
+
+    fn stat_entries<'s, T: Stat + 's>(
+        &self,
+        entries: impl IntoIterator<Item = &'s T>,
+        id: impl Fn(&T) -> i64,
+    ) -> Vec<StatEntry> {
+        entries
+            .into_iter()
+            .map(|entry| {
+                let Named { id, name } = self.item(id(entry));
+                StatEntry {
+                    id,
+                    name,
+                    record: self.record(entry),
+                }
+            })
+            .collect()
+    }
+
+    fn late_items(&self, items: &[LateItem]) -> Vec<StatEntry> {
+        self.stat_entries(items, |i| i.id)
+    }
+
+    fn champion(&self, key: i64) -> Option<ChampionRef> {
+        self.champs_by_key
+            .get(key.to_string().as_str())
+            .map(|c| ChampionRef::from(*c))
+    }
+
+    fn abilities(&self, abilities: &Abilities) -> AbilitiesReport {
+        AbilitiesReport {
+            record: self.record(abilities),
+            order: abilities
+                .ability_order
+                .iter()
+                .map(char::to_string)
+                .collect(),
+            max_order: abilities
+                .ability_max_order
+                .chars()
+                .map(|c| c.to_string())
+                .collect(),
+        }
+    }
+
+    fn runes(&self, overview: &OverviewData) -> RunesReport {
+        let path = |style_id: i64| RunePathReport {
+            id: style_id,
+            name: self
+                .api
+                .runes
+                .values()
+                .find(|r| r.parent_id == style_id)
+                .map(|r| r.parent.clone())
+                .unwrap_or_default(),
+            runes: overview
+                .runes
+                .rune_ids
+                .iter()
+                .filter_map(|id| self.api.runes.get(id))
+                .filter(|r| r.parent_id == style_id)
+                .map(|r| Named {
+                    id: r.rune.id,
+                    name: Some(r.rune.name.clone()),
+                })
+                .collect(),
+        };
+        RunesReport {
+            record: self.record(&overview.runes),
+            primary: path(overview.runes.primary_style_id),
+            secondary: path(overview.runes.secondary_style_id),
+        }
+    }
+
+    fn shards(overview: &OverviewData) -> Vec<Named> {
+        overview
+            .shards
+            .shard_ids
+            .iter()
+            .map(|id| Named {
+                id: *id,
+                // Shard names aren't in ddragon, so these are always English.
+                name: Shard::try_from(*id).ok().map(|s| s.to_string()),
+            })
+            .collect()
+    }
+
+    fn summoner_spells(&self, overview: &OverviewData) -> ItemSetReport {
+        ItemSetReport {
+            record: self.record(&overview.summoner_spells),
+            items: overview
+                .summoner_spells
+                .spell_ids
+                .iter()
+                .map(|id| Named {
+                    id: *id,
+                    name: self.api.summoner_spells.get(id).cloned(),
+                })
+                .collect(),
+        }
+    }
+
+    fn arena(&self, overview: &ArenaOverviewData) -> ArenaReport {
+        // The data is ranked with the default settings, so rank it again with
+        // the configured ones.
+        let settings = &self.api.stat_settings;
+        let mut augments = overview.augments.clone();
+        augments.sort_by(|a, b| {
+            b.smoothed_win_rate(settings)
+                .total_cmp(&a.smoothed_win_rate(settings))
+        });
+        let mut prismatic_items = overview.prismatic_items.clone();
+        prismatic_items.sort_by(|a, b| {
+            b.smoothed_win_rate(settings)
+                .total_cmp(&a.smoothed_win_rate(settings))
+        });
+        ArenaReport {
+            augments: augments
+                .iter()
+                .map(|a| {
+                    let game = self.api.arena_augments.get(&a.id);
+                    AugmentEntry {
+                        id: a.id,
+                        name: game.map(|g| g.name.clone()),
+                        rarity: game.map(|g| rarity_name(&g.rarity)),
+                        record: self.record(a),
+                    }
+                })
+                .collect(),
+            prismatic_items: self.stat_entries(&prismatic_items, |p| p.id),
+            consumables: self.late_items(&overview.consumables),
+            champion_synergies: overview
+                .champion_synergies
+                .iter()
+                .filter_map(|s| self.synergy(s))
+                .collect(),
+        }
+    }
+
+    fn synergy(&self, synergy: &ChampionSynergy) -> Option<SynergyEntry> {
+        Some(SynergyEntry {
+            champion: self.champion(synergy.id)?,
+            record: self.record(synergy),
+            first: synergy.first,
+            #[allow(clippy::cast_precision_loss)]
+            average_placement: if synergy.picked > 0 {
+                synergy.sum_of_placements as f64 / synergy.picked as f64
+            } else {
+                0.0
+            },
+        })
+    }
+
+    fn matchups(&self, matchups: &MatchupData) -> MatchupsReport {
+        let entries = |list: &[Matchup]| {
+            list.iter()
+                .filter_map(|m| {
+                    Some(MatchupEntry {
+                        champion: self.champion(m.champion_id)?,
+                        record: self.record(m),
+                    })
+                })
+                .collect()
+        };
+        MatchupsReport {
+            total_matches: i64::from(matchups.total_matches),
+            best: entries(&matchups.best_matchups(&self.api.stat_settings)),
+            worst: entries(&matchups.worst_matchups(&self.api.stat_settings)),
+        }
+    }
+}
+
+impl UggApi {
+    /// Fetches a champion's build and matchups and resolves every id in them
+    /// to a name.
+    pub fn get_build_report(
+        &self,
+        champ: &ChampionShort,
+        role: mappings::Role,
+        region: mappings::Region,
+        mode: mappings::Mode,
+        build: mappings::Build,
+    ) -> Result<BuildReport, UggError> {
+        let (overview, role) = self.get_stats(champ, role, region, mode, build)?;
+        let matchups = if mode == mappings::Mode::ARAM || mode == mappings::Mode::Arena {
+            None
+        } else {
+            self.get_matchups_report(champ, role, region, mode).ok()
+        };
+
+        let resolver = Resolver::new(self);
+        let (wins, matches, starting_items, core_items, late_items, abilities) = match &overview {
+            Overview::Default(d) => (
+                d.wins,
+                d.matches,
+                &d.starting_items,
+                &d.core_items,
+                [&d.item_4_options, &d.item_5_options, &d.item_6_options],
+                &d.abilities,
+            ),
+            Overview::Arena(a) => (
+                a.wins,
+                a.matches,
+                &a.starting_items,
+                &a.core_items,
+                [&a.item_4_options, &a.item_5_options, &a.item_6_options],
+                &a.abilities,
+            ),
+        };
+        let default = match &overview {
+            Overview::Default(d) => Some(d),
+            Overview::Arena(_) => None,
+        };
+        let [fourth_items, fifth_items, sixth_items] =
+            late_items.map(|items| resolver.late_items(items));
+
+        Ok(BuildReport {
+            schema_version: REPORT_SCHEMA_VERSION,
+            champion: ChampionRef::from(champ),
+            role: role.to_string(),
+            mode: mode.to_string(),
+            region: region.to_string(),
+            build: build.to_string(),
+            patch: self.current_version.clone(),
+            record: Record::new(wins, matches, &self.stat_settings),
+            low_sample_size: overview.is_low_sample_size(&self.stat_settings),
+            runes: default.map(|d| resolver.runes(d)),
+            shards: default.map(Resolver::shards).unwrap_or_default(),
+            summoner_spells: default.map(|d| resolver.summoner_spells(d)),
+            starting_items: resolver.item_set(starting_items),
+            core_items: resolver.item_set(core_items),
+            fourth_items,
+            fifth_items,
+            sixth_items,
+            abilities: resolver.abilities(abilities),
+            arena: match &overview {
+                Overview::Arena(a) => Some(resolver.arena(a)),
+                Overview::Default(_) => None,
+            },
+            matchups,
+        })
+    }
+
+    /// Fetches a champion's best and worst matchups with champion names
+    /// resolved.
+    pub fn get_matchups_report(
+        &self,
+        champ: &ChampionShort,
+        role: mappings::Role,
+        region: mappings::Region,
+        mode: mappings::Mode,
+    ) -> Result<MatchupsReport, UggError> {
+        let (matchups, _) = self.get_matchups(champ, role, region, mode)?;
+        Ok(Resolver::new(self).matchups(&matchups))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    const README: &str = include_str!("../../../README.md");
+
+    /// The field names in the first column of the schema table in the README.
+    fn documented_fields() -> Vec<String> {
+        let table = README
+            .split_once("The current schema is version")
+            .expect("README documents the schema")
+            .1;
+        table
+            .lines()
+            .skip_while(|line| !line.starts_with('|'))
+            .take_while(|line| line.starts_with('|'))
+            .filter_map(|line| line.split('|').nth(1))
+            .flat_map(|cell| cell.split(','))
+            .filter_map(|name| name.trim().strip_prefix('`')?.strip_suffix('`'))
+            .map(str::to_owned)
+            .collect()
+    }
+
+    fn keys(value: &Value) -> Vec<&str> {
+        let mut keys = value
+            .as_object()
+            .expect("value is an object")
+            .keys()
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        keys.sort_unstable();
+        keys
+    }
+
+    fn fixture() -> BuildReport {
+        let settings = StatSettings::default();
+        let record = Record::new(520, 1000, &settings);
+        let champion = ChampionRef {
+            id: "MonkeyKing".to_string(),
+            key: "62".to_string(),
+            name: "Wukong".to_string(),
+        };
+        let named = |id, name: &str| Named {
+            id,
+            name: Some(name.to_string()),
+        };
+        let entry = |id, name: &str| StatEntry {
+            id,
+            name: Some(name.to_string()),
+            record,
+        };
+        let path = |id, name: &str| RunePathReport {
+            id,
+            name: name.to_string(),
+            runes: vec![named(8010, "Conqueror")],
+        };
+        BuildReport {
+            schema_version: REPORT_SCHEMA_VERSION,
+            champion: champion.clone(),
+            role: "Top".to_string(),
+            mode: "Normal".to_string(),
+            region: "World".to_string(),
+            build: "Recommended".to_string(),
+            patch: "14.20.1".to_string(),
+            record,
+            low_sample_size: false,
+            runes: Some(RunesReport {
+                record,
+                primary: path(8000, "Precision"),
+                secondary: path(8400, "Resolve"),
+            }),
+            shards: vec![Named {
+                id: 5008,
+                name: Shard::try_from(5008).ok().map(|s| s.to_string()),
+            }],
+            summoner_spells: Some(ItemSetReport {
+                record,
+                items: vec![named(4, "Flash")],
+            }),
+            starting_items: ItemSetReport {
+                record,
+                items: vec![named(1055, "Doran's Blade")],
+            },
+            core_items: ItemSetReport {
+                record,
+                items: vec![named(6631, "Stridebreaker")],
+            },
+            fourth_items: vec![entry(3053, "Sterak's Gage")],
+            fifth_items: vec![],
+            sixth_items: vec![],
+            abilities: AbilitiesReport {
+                record,
+                order: vec!["Q".to_string(), "E".to_string(), "W".to_string()],
+                max_order: vec!["Q".to_string(), "E".to_string(), "W".to_string()],
+            },
+            arena: Some(ArenaReport {
+                augments: vec![AugmentEntry {
+                    id: 1,
+                    name: None,
+                    rarity: Some("gold"),
+                    record,
+                }],
+                prismatic_items: vec![entry(4403, "The Golden Spatula")],
+                consumables: vec![],
+                champion_synergies: vec![SynergyEntry {
+                    champion: champion.clone(),
+                    record,
+                    first: 12,
+                    average_placement: 3.5,
+                }],
+            }),
+            matchups: Some(MatchupsReport {
+                total_matches: 1000,
+                best: vec![MatchupEntry { champion, record }],
+                worst: vec![],
+            }),
+        }
+    }
+
+    #[test]
+    fn report_matches_documented_schema() {
+        let report = serde_json::to_value(fixture()).unwrap();
+
+        let mut documented = documented_fields();
+        documented.sort_unstable();
+        assert_eq!(keys(&report), documented);
+        assert!(README.contains(&format!(
+            "The current schema is version `{REPORT_SCHEMA_VERSION}`"
+        )));
+        assert_eq!(report["schema_version"], REPORT_SCHEMA_VERSION);
+
+        let record = ["matches", "smoothed_win_rate", "win_rate", "wins"];
+        assert_eq!(keys(&report["record"]), record);
+        assert_eq!(keys(&report["champion"]), ["id", "key", "name"]);
+        assert_eq!(keys(&report["shards"][0]), ["id", "name"]);
+        assert_eq!(report["shards"][0]["name"], "Adaptive Force");
+
+        let runes = &report["runes"];
+        assert_eq!(keys(runes), ["primary", "record", "secondary"]);
+        assert_eq!(keys(&runes["primary"]), ["id", "name", "runes"]);
+        assert_eq!(keys(&runes["primary"]["runes"][0]), ["id", "name"]);
+
+        assert_eq!(keys(&report["summoner_spells"]), ["items", "record"]);
+        assert_eq!(keys(&report["starting_items"]), ["items", "record"]);
+        assert_eq!(keys(&report["fourth_items"][0]), ["id", "name", "record"]);
+        assert_eq!(keys(&report["abilities"]), ["max_order", "order", "record"]);
+
+        let arena = &report["arena"];
+        assert_eq!(
+            keys(arena),
+            [
+                "augments",
+                "champion_synergies",
+                "consumables",
+                "prismatic_items"
+            ]
+        );
+        assert_eq!(
+            keys(&arena["augments"][0]),
+            ["id", "name", "rarity", "record"]
+        );
+        assert_eq!(arena["augments"][0]["name"], Value::Null);
+        assert_eq!(
+            keys(&arena["champion_synergies"][0]),
+            ["average_placement", "champion", "first", "record"]
+        );
+
+        let matchups = &report["matchups"];
+        assert_eq!(keys(matchups), ["best", "total_matches", "worst"]);
+        assert_eq!(keys(&matchups["best"][0]), ["champion", "record"]);
+    }
+}
@@ -0,0 +1,25 @@
+use ddragon::models::champions::ChampionShort;
+use std::collections::HashMap;
+use ugg_types::mappings::{Rank, Region, Role};
+use ugg_types::rankings::{RankingData, Rankings};
+
+/// A single champion's row in the tier list.
+#[derive(Debug, Clone)]
+pub struct TierListEntry {
+    pub champion: ChampionShort,
+    pub role: Role,
+    pub data: RankingData,
+}
+
+/// Picks the per-role rankings for a region from `rank` if it has data, or
+/// else the first bracket with data from [`Rank::preferred_order`].
+pub fn rankings_by_role(
+    rankings: &Rankings,
+    region: Region,
+    rank: Option<Rank>,
+) -> Option<&HashMap<Role, RankingData>> {
+    let region_data = rankings.get(&region)?;
+    rank.into_iter()
+        .chain(Rank::preferred_order())
+        .find_map(|rank| region_data.get(&rank))
+}
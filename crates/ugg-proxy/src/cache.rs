@@ -0,0 +1,35 @@
+use lru::LruCache;
+use std::num::NonZeroUsize;
+use std::time::{Duration, Instant};
+
+/// Serialized responses, kept for a fixed amount of time so that repeated
+/// requests from any client don't go back out to u.gg.
+pub struct ResponseCache {
+    entries: LruCache<String, (Instant, String)>,
+    ttl: Duration,
+}
+
+impl ResponseCache {
+    pub fn new(capacity: NonZeroUsize, ttl: Duration) -> Self {
+        Self {
+            entries: LruCache::new(capacity),
+            ttl,
+        }
+    }
+
+    pub fn get(&mut self, key: &str) -> Option<&str> {
+        let expired = self
+            .entries
+            .peek(key)
+            .is_some_and(|(stored, _)| stored.elapsed() > self.ttl);
+        if expired {
+            self.entries.pop(key);
+            return None;
+        }
+        self.entries.get(key).map(|(_, body)| body.as_str())
+    }
+
+    pub fn insert(&mut self, key: String, body: String) {
+        self.entries.put(key, (Instant::now(), body));
+    }
+}
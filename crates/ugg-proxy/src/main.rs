@@ -0,0 +1,278 @@
+#![deny(clippy::pedantic)]
+
+use std::num::NonZeroUsize;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::anyhow;
+use clap::Parser;
+use ddragon::models::champions::ChampionShort;
+use serde::Serialize;
+use tiny_http::{Header, Method, Request, Response, Server};
+use uggo_config::Config;
+use uggo_ugg_api::{UggApi, UggApiBuilder, UggError};
+
+mod cache;
+mod routes;
+
+use cache::ResponseCache;
+use routes::{Query, Route, RouteError};
+
+/// HTTP server exposing normalized u.gg builds, with a shared server-side
+/// cache.
+#[derive(Parser)]
+#[command(version, about)]
+struct Args {
+    /// Address to listen on. Use 0.0.0.0 to accept requests from other
+    /// machines.
+    #[arg(long, default_value = "127.0.0.1:8080")]
+    bind: String,
+
+    /// How long responses are cached for, in seconds.
+    #[arg(long, default_value_t = 1800)]
+    ttl: u64,
+
+    /// How many responses are kept in the cache at once.
+    #[arg(long, default_value_t = NonZeroUsize::new(1024).unwrap())]
+    cache_size: NonZeroUsize,
+
+    /// How many requests are handled at once.
+    #[arg(long, default_value_t = NonZeroUsize::new(4).unwrap())]
+    workers: NonZeroUsize,
+
+    /// How often to check for a new patch, in seconds.
+    #[arg(long, default_value_t = 3600)]
+    patch_check: u64,
+}
+
+/// The newest patch, shared between workers so only one of them asks u.gg
+/// each time it's due.
+struct LatestPatch {
+    version: String,
+    checked: Instant,
+    interval: Duration,
+}
+
+impl LatestPatch {
+    /// The newest patch, checked again with `api` once the interval has
+    /// passed. A failed check keeps the last known patch until the next one.
+    fn get(latest: &Mutex<Self>, api: &UggApi) -> String {
+        let mut latest = latest.lock().unwrap_or_else(PoisonError::into_inner);
+        if latest.checked.elapsed() >= latest.interval {
+            latest.checked = Instant::now();
+            match api.latest_version() {
+                Ok(version) => latest.version = version,
+                Err(e) => eprintln!("Failed to check for a new patch: {e}"),
+            }
+        }
+        latest.version.clone()
+    }
+}
+
+#[derive(Serialize)]
+struct ErrorBody<'a> {
+    error: &'a str,
+    /// The query parameter that was rejected, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parameter: Option<&'a str>,
+}
+
+/// Why a request couldn't be answered.
+enum Failure {
+    UnknownChampion(String),
+    Fetch(UggError),
+    Serialize(serde_json::Error),
+}
+
+impl Failure {
+    const fn status(&self) -> u16 {
+        match self {
+            Self::UnknownChampion(_) => 404,
+            Self::Fetch(_) => 502,
+            Self::Serialize(_) => 500,
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            Self::UnknownChampion(name) => format!("unknown champion \"{name}\""),
+            Self::Fetch(e) => e.to_string(),
+            Self::Serialize(e) => format!("could not serialize the response: {e}"),
+        }
+    }
+}
+
+fn json_header() -> Header {
+    Header::from_bytes("Content-Type", "application/json").expect("header is valid")
+}
+
+fn respond(request: Request, status: u16, body: String, cache_status: Option<&str>) {
+    let mut response = Response::from_string(body)
+        .with_status_code(status)
+        .with_header(json_header());
+    if let Some(cache_status) = cache_status {
+        response.add_header(Header::from_bytes("X-Cache", cache_status).expect("header is valid"));
+    }
+    if let Err(e) = request.respond(response) {
+        eprintln!("Failed to send response: {e}");
+    }
+}
+
+fn respond_error(request: Request, status: u16, error: &str, parameter: Option<&str>) {
+    match serde_json::to_string(&ErrorBody { error, parameter }) {
+        Ok(body) => respond(request, status, body, None),
+        Err(e) => {
+            eprintln!("Failed to serialize error: {e}");
+            let body = r#"{"error":"internal server error"}"#.to_string();
+            respond(request, 500, body, None);
+        }
+    }
+}
+
+/// Looks up the champion a route asks for, by exact name or id.
+fn find_champ<'a>(api: &'a UggApi, route: &Route) -> Result<&'a ChampionShort, Failure> {
+    let (Route::Build { champion, .. } | Route::Matchups { champion, .. }) = route;
+    api.match_champ(champion)
+        .ok_or_else(|| Failure::UnknownChampion(champion.clone()))
+}
+
+/// Fetches the route's data from u.gg as a JSON string.
+fn fetch(api: &UggApi, champ: &ChampionShort, route: &Route) -> Result<String, Failure> {
+    match route {
+        Route::Build { query, .. } => serde_json::to_string(
+            &api.get_build_report(champ, query.role, query.region, query.mode, query.build)
+                .map_err(Failure::Fetch)?,
+        ),
+        Route::Matchups { query, .. } => serde_json::to_string(
+            &api.get_matchups_report(champ, query.role, query.region, query.mode)
+                .map_err(Failure::Fetch)?,
+        ),
+    }
+    .map_err(Failure::Serialize)
+}
+
+fn cache_key(api: &UggApi, champ: &ChampionShort, route: &Route) -> String {
+    let version = &api.current_version;
+    match route {
+        Route::Build { query, .. } => format!("{version}/build/{}/{query:?}", champ.id),
+        Route::Matchups { query, .. } => format!("{version}/matchups/{}/{query:?}", champ.id),
+    }
+}
+
+/// An API builder using the configured cache, rank, locale and win rate
+/// thresholds, like uggo's.
+fn api_builder(config: &Config) -> UggApiBuilder {
+    let mut builder = UggApiBuilder::new()
+        .cache_dir(config.cache())
+        .stat_settings(config.stat_settings());
+    if let Some(rank) = config.settings().rank {
+        builder = builder.rank(rank);
+    }
+    if let Some(locale) = config.locale() {
+        builder = builder.locale(locale);
+    }
+    builder
+}
+
+/// Rebuilds `api` for the newest patch once one comes out, keeping the old
+/// one if that fails.
+fn refresh(api: &mut UggApi, config: &Config, latest: &Mutex<LatestPatch>) {
+    let version = LatestPatch::get(latest, api);
+    if version == api.current_version {
+        return;
+    }
+    match api_builder(config).version(&version).build() {
+        Ok(new_api) => *api = new_api,
+        Err(e) => eprintln!("Failed to load patch {version}: {e}"),
+    }
+}
+
+fn handle(api: &UggApi, cache: &Mutex<ResponseCache>, defaults: Query, request: Request) {
+    if *request.method() != Method::Get {
+        respond_error(request, 405, "only GET requests are supported", None);
+        return;
+    }
+    let route = match routes::parse(request.url(), defaults) {
+        Ok(route) => route,
+        Err(RouteError::UnknownEndpoint) => {
+            respond_error(request, 404, "unknown endpoint", None);
+            return;
+        }
+        Err(RouteError::InvalidParameter { name, message }) => {
+            respond_error(request, 400, &message, Some(name));
+            return;
+        }
+    };
+
+    let result = find_champ(api, &route).and_then(|champ| {
+        let key = cache_key(api, champ, &route);
+        let cached = lock(cache).get(&key).map(str::to_owned);
+        if let Some(body) = cached {
+            return Ok((body, "HIT"));
+        }
+        let body = fetch(api, champ, &route)?;
+        lock(cache).insert(key, body.clone());
+        Ok((body, "MISS"))
+    });
+    match result {
+        Ok((body, cache_status)) => respond(request, 200, body, Some(cache_status)),
+        Err(failure) => {
+            let message = failure.message();
+            if let Failure::Fetch(_) | Failure::Serialize(_) = failure {
+                eprintln!("Failed to fetch {}: {message}", request.url());
+            }
+            respond_error(request, failure.status(), &message, None);
+        }
+    }
+}
+
+/// A worker that panicked mid-request can't leave the cache half-written, so
+/// keep using it.
+fn lock(cache: &Mutex<ResponseCache>) -> MutexGuard<'_, ResponseCache> {
+    cache.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let config = Config::new()?;
+    let defaults = Query::from_settings(config.settings());
+
+    // `UggApi` keeps its caches in `RefCell`s, so every worker gets its own,
+    // all on the same patch until a new one comes out.
+    let api = api_builder(&config).build()?;
+    let version = api.current_version.clone();
+    let mut apis = vec![api];
+    for _ in 1..args.workers.get() {
+        apis.push(api_builder(&config).version(&version).build()?);
+    }
+
+    let latest = Mutex::new(LatestPatch {
+        version: version.clone(),
+        checked: Instant::now(),
+        interval: Duration::from_secs(args.patch_check),
+    });
+    let cache = Mutex::new(ResponseCache::new(
+        args.cache_size,
+        Duration::from_secs(args.ttl),
+    ));
+    let server = Server::http(&args.bind).map_err(|e| anyhow!(e))?;
+    println!(
+        "Serving builds for patch {version} on http://{} with {} workers",
+        args.bind, args.workers
+    );
+
+    thread::scope(|scope| {
+        for mut api in apis {
+            let (server, cache, config, latest) = (&server, &cache, &config, &latest);
+            scope.spawn(move || {
+                for request in server.incoming_requests() {
+                    refresh(&mut api, config, latest);
+                    handle(&api, cache, defaults, request);
+                }
+            });
+        }
+    });
+
+    Ok(())
+}
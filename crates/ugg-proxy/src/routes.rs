@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+use ugg_types::mappings::{Build, Mode, Region, Role};
+use uggo_config::{Setting, Settings};
+
+/// The filters shared by every endpoint. Anything missing falls back to the
+/// configured defaults, like `uggo build`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Query {
+    pub role: Role,
+    pub region: Region,
+    pub mode: Mode,
+    pub build: Build,
+}
+
+impl Query {
+    pub fn from_settings(settings: &Settings) -> Self {
+        Self {
+            role: settings.role.unwrap_or_default(),
+            region: settings.region.unwrap_or_default(),
+            mode: settings.mode.unwrap_or_default(),
+            build: settings.build.unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Route {
+    Build { champion: String, query: Query },
+    Matchups { champion: String, query: Query },
+}
+
+/// Why a url couldn't be matched to a route.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RouteError {
+    UnknownEndpoint,
+    /// A query parameter has a value the matching `uggo build` flag would
+    /// reject.
+    InvalidParameter {
+        name: &'static str,
+        message: String,
+    },
+}
+
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        // `from_str_radix` would also take a sign, as in `%+f`.
+        let escaped = (bytes[i] == b'%')
+            .then(|| text.get(i + 1..i + 3))
+            .flatten()
+            .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (escaped, bytes[i]) {
+            (Some(byte), _) => {
+                decoded.push(byte);
+                i += 2;
+            }
+            (None, b'+') => decoded.push(b' '),
+            (None, byte) => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn param<T: Setting>(
+    params: &HashMap<String, String>,
+    name: &'static str,
+    default: T,
+) -> Result<T, RouteError> {
+    params.get(name).map_or(Ok(default), |value| {
+        T::parse_setting(value, "the query").map_err(|e| RouteError::InvalidParameter {
+            name,
+            message: e.to_string(),
+        })
+    })
+}
+
+fn parse_query(query: &str, defaults: Query) -> Result<Query, RouteError> {
+    let params = query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| (key.to_lowercase(), percent_decode(value)))
+        // An empty value means the same as leaving the parameter out.
+        .filter(|(_, value)| !value.is_empty())
+        .collect::<HashMap<_, _>>();
+
+    Ok(Query {
+        role: param(&params, "role", defaults.role)?,
+        region: param(&params, "region", defaults.region)?,
+        mode: param(&params, "mode", defaults.mode)?,
+        build: param(&params, "build", defaults.build)?,
+    })
+}
+
+/// Matches a request url such as `/champion/ahri/build?role=mid` to a route,
+/// filling in anything the query leaves out from `defaults`.
+pub fn parse(url: &str, defaults: Query) -> Result<Route, RouteError> {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let segments = path
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>();
+
+    match segments.as_slice() {
+        ["champion", champion, "build"] => Ok(Route::Build {
+            champion: percent_decode(champion),
+            query: parse_query(query, defaults)?,
+        }),
+        ["champion", champion, "matchups"] => Ok(Route::Matchups {
+            champion: percent_decode(champion),
+            query: parse_query(query, defaults)?,
+        }),
+        _ => Err(RouteError::UnknownEndpoint),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let defaults = Query::default();
+        assert_eq!(
+            parse(
+                "/champion/Kai%27Sa/build?role=adc&region=euw&mode=&build=crit",
+                defaults
+            ),
+            Ok(Route::Build {
+                champion: "Kai'Sa".to_string(),
+                query: Query {
+                    role: Role::ADCarry,
+                    region: Region::EUW1,
+                    mode: Mode::Normal,
+                    build: Build::Crit,
+                },
+            })
+        );
+        assert_eq!(
+            parse("/champion/lee+sin/matchups", defaults),
+            Ok(Route::Matchups {
+                champion: "lee sin".to_string(),
+                query: Query::default(),
+            })
+        );
+        assert_eq!(
+            parse("/champion/a%+fb%4/build", defaults),
+            Ok(Route::Build {
+                champion: "a% fb%4".to_string(),
+                query: Query::default(),
+            })
+        );
+        assert_eq!(
+            parse("/champion/ahri", defaults),
+            Err(RouteError::UnknownEndpoint)
+        );
+        assert_eq!(
+            parse("/champion/ahri/build/extra", defaults),
+            Err(RouteError::UnknownEndpoint)
+        );
+    }
+
+    #[test]
+    fn test_parse_defaults_and_errors() {
+        let defaults = Query {
+            region: Region::KR,
+            mode: Mode::ARAM,
+            ..Query::default()
+        };
+        assert_eq!(
+            parse("/champion/ahri/build?role=mid", defaults),
+            Ok(Route::Build {
+                champion: "ahri".to_string(),
+                query: Query {
+                    role: Role::Mid,
+                    ..defaults
+                },
+            })
+        );
+        assert!(matches!(
+            parse("/champion/ahri/build?role=middle&region=nowhere", defaults),
+            Err(RouteError::InvalidParameter { name: "region", .. })
+        ));
+        assert!(matches!(
+            parse("/champion/ahri/matchups?mode=nope", defaults),
+            Err(RouteError::InvalidParameter { name: "mode", .. })
+        ));
+    }
+}
@@ -5,6 +5,7 @@ use serde::de::{Deserialize, Deserializer, IgnoredAny, SeqAccess, Visitor};
 
 use crate::default_overview::{Abilities, Items, LateItem};
 use crate::overview::handle_unknown;
+use crate::stats::{Stat, StatSettings};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct ArenaOverviewData {
@@ -18,6 +19,7 @@ pub struct ArenaOverviewData {
     pub prismatic_items: Vec<PrismaticItem>,
     pub wins: i64,
     pub matches: i64,
+    #[deprecated(note = "uses the default threshold, use `Overview::is_low_sample_size` instead")]
     pub low_sample_size: bool,
     pub champion_synergies: Vec<ChampionSynergy>,
     pub augments: Vec<Augment>,
@@ -90,10 +92,10 @@ impl<'de> Deserialize<'de> for PrismaticItem {
 #[derive(Debug, Clone, Serialize, Eq, PartialEq)]
 pub struct ChampionSynergy {
     pub id: i64,
-    top_four: i64,
-    picked: i64,
-    first: i64,
-    sum_of_placements: i64,
+    pub top_four: i64,
+    pub picked: i64,
+    pub first: i64,
+    pub sum_of_placements: i64,
 }
 
 impl ChampionSynergy {
@@ -160,8 +162,8 @@ impl<'de> Deserialize<'de> for ChampionSynergy {
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
 pub struct Augment {
     pub id: i64,
-    wins: i64,
-    matches: i64,
+    pub wins: i64,
+    pub matches: i64,
 }
 
 impl Augment {
@@ -267,11 +269,14 @@ impl<'de> Deserialize<'de> for ArenaOverviewData {
 
                 // Prismatic items are ordered by pickrate, not winrate
                 // reorder them
+                let settings = StatSettings::DEFAULT;
                 let mut prismatic_items = late_items.4;
-                prismatic_items.sort_by(|a, b| b.cmp(a));
+                prismatic_items.sort_by(|a, b| {
+                    b.smoothed_win_rate(&settings)
+                        .total_cmp(&a.smoothed_win_rate(&settings))
+                });
 
                 let match_info = visitor.next_element::<(i64, i64)>()?.unwrap_or_default();
-                let low_sample_size = match_info.1 < 1000;
 
                 // this is the original low sample size value, it's always false though, so ignore.
                 let _ = visitor.next_element::<IgnoredAny>().is_ok();
@@ -291,14 +296,18 @@ impl<'de> Deserialize<'de> for ArenaOverviewData {
 
                 // Augments are ordered by pickrate in the UI, but winrate
                 // in the data. Pick rate is presumably influenced by the tier
-                // of the augment. Sort by winrate, but consider bucketing these
-                // by tier if the information is available.
+                // of the augment. Sort by smoothed winrate, but consider bucketing
+                // these by tier if the information is available.
                 let mut augments = visitor.next_element::<Vec<Augment>>()?.unwrap_or_default();
-                augments.sort_by(|a, b| b.cmp(a));
+                augments.sort_by(|a, b| {
+                    b.smoothed_win_rate(&settings)
+                        .total_cmp(&a.smoothed_win_rate(&settings))
+                });
 
                 // Don't know what this is yet
                 while let Some(IgnoredAny) = visitor.next_element()? {}
 
+                #[allow(deprecated)]
                 let arena_overview_data = ArenaOverviewData {
                     starting_items,
                     core_items,
@@ -310,7 +319,7 @@ impl<'de> Deserialize<'de> for ArenaOverviewData {
                     prismatic_items,
                     wins: match_info.0,
                     matches: match_info.1,
-                    low_sample_size,
+                    low_sample_size: StatSettings::DEFAULT.is_low_sample_size(match_info.1),
                     champion_synergies,
                     augments,
                 };
@@ -6,6 +6,7 @@ use serde::de::{Deserialize, Deserializer, IgnoredAny, SeqAccess, Visitor};
 use std::fmt;
 
 use crate::overview::handle_unknown;
+use crate::stats::StatSettings;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct OverviewData {
@@ -19,6 +20,7 @@ pub struct OverviewData {
     pub item_6_options: Vec<LateItem>,
     pub wins: i64,
     pub matches: i64,
+    #[deprecated(note = "uses the default threshold, use `Overview::is_low_sample_size` instead")]
     pub low_sample_size: bool,
     pub shards: Shards,
 }
@@ -294,7 +296,6 @@ impl<'de> Deserialize<'de> for OverviewData {
                     .next_element::<Vec<i64>>()
                     .unwrap_or_default()
                     .unwrap_or_default();
-                let low_sample_size = match_info.get(1).is_some_and(|games| *games < 1000);
 
                 // this is the original low sample size value, it's always false though, so ignore.
                 let _ = visitor.next_element::<IgnoredAny>().is_ok();
@@ -307,6 +308,7 @@ impl<'de> Deserialize<'de> for OverviewData {
                 // Don't know what this is yet
                 while let Some(IgnoredAny) = visitor.next_element()? {}
 
+                #[allow(deprecated)]
                 let overview_data = OverviewData {
                     runes,
                     summoner_spells,
@@ -318,7 +320,7 @@ impl<'de> Deserialize<'de> for OverviewData {
                     item_6_options: late_items[2].clone(),
                     wins: match_info[0],
                     matches: match_info[1],
-                    low_sample_size,
+                    low_sample_size: StatSettings::DEFAULT.is_low_sample_size(match_info[1]),
                     shards,
                 };
                 Ok(overview_data)
@@ -5,4 +5,6 @@ pub mod default_overview;
 pub mod mappings;
 pub mod matchups;
 pub mod overview;
+pub mod rankings;
 pub mod rune;
+pub mod stats;
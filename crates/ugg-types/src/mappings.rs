@@ -540,6 +540,55 @@ impl Build {
     }
 }
 
+/// Stat shards, which ddragon doesn't list.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Shard {
+    HealthScaling = 5001,
+    Armor = 5002,
+    MagicResist = 5003,
+    AttackSpeed = 5005,
+    AbilityHaste = 5007,
+    AdaptiveForce = 5008,
+    MoveSpeed = 5010,
+    Health = 5011,
+    Tenacity = 5013,
+}
+
+impl Display for Shard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let shard_str = match self {
+            Shard::HealthScaling => "Health Scaling",
+            Shard::Armor => "Armor",
+            Shard::MagicResist => "Magic Resist",
+            Shard::AttackSpeed => "Attack Speed",
+            Shard::AbilityHaste => "Ability Haste",
+            Shard::AdaptiveForce => "Adaptive Force",
+            Shard::MoveSpeed => "Move Speed",
+            Shard::Health => "Health",
+            Shard::Tenacity => "Tenacity",
+        };
+        write!(f, "{shard_str}")
+    }
+}
+
+impl TryFrom<i64> for Shard {
+    type Error = ();
+    fn try_from(v: i64) -> Result<Self, Self::Error> {
+        match v {
+            5001 => Ok(Self::HealthScaling),
+            5002 => Ok(Self::Armor),
+            5003 => Ok(Self::MagicResist),
+            5005 => Ok(Self::AttackSpeed),
+            5007 => Ok(Self::AbilityHaste),
+            5008 => Ok(Self::AdaptiveForce),
+            5010 => Ok(Self::MoveSpeed),
+            5011 => Ok(Self::Health),
+            5013 => Ok(Self::Tenacity),
+            _ => Err(()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -2,6 +2,7 @@
 // structure of the champ overview stats data.
 
 use crate::mappings;
+use crate::stats::{Stat, StatSettings};
 use serde::Serialize;
 use serde::de::{Deserialize, Deserializer, IgnoredAny, SeqAccess, Visitor};
 use std::collections::HashMap;
@@ -49,11 +50,82 @@ impl<'de> Deserialize<'de> for WrappedMatchupData {
 
 #[derive(Debug, Clone, Serialize)]
 pub struct MatchupData {
+    #[deprecated(note = "ranked by raw win rate, use `MatchupData::best_matchups` instead")]
     pub best_matchups: Vec<Matchup>,
+    #[deprecated(note = "ranked by raw win rate, use `MatchupData::worst_matchups` instead")]
     pub worst_matchups: Vec<Matchup>,
+    /// Every opponent, including rare ones left out of the best and worst
+    /// lists, by raw win rate, best first.
+    pub all_matchups: Vec<Matchup>,
     pub total_matches: i32,
 }
 
+impl MatchupData {
+    /// Sorts `all_matchups` by raw win rate, best first, and fills in the
+    /// deprecated best and worst lists from it.
+    #[must_use]
+    #[allow(deprecated)]
+    pub fn new(mut all_matchups: Vec<Matchup>, total_matches: i32) -> Self {
+        all_matchups.sort_by(|a, b| b.winrate.total_cmp(&a.winrate));
+        let common = all_matchups
+            .iter()
+            .filter(|a| f64::from(a.matches) >= (f64::from(total_matches) / 200.0))
+            .cloned()
+            .collect::<Vec<Matchup>>();
+        let (best_matchups, worst_matchups) = if common.len() >= 5 {
+            (
+                common[..5].to_vec(),
+                common[common.len() - 5..].iter().rev().cloned().collect(),
+            )
+        } else {
+            (vec![], vec![])
+        };
+        Self {
+            best_matchups,
+            worst_matchups,
+            all_matchups,
+            total_matches,
+        }
+    }
+
+    /// Matchups that represent at least a 0.5% possibility of showing up,
+    /// best first by smoothed win rate.
+    fn common_matchups(&self, settings: &StatSettings) -> Vec<Matchup> {
+        let mut common = self
+            .all_matchups
+            .iter()
+            .filter(|a| f64::from(a.matches) >= (f64::from(self.total_matches) / 200.0))
+            .cloned()
+            .collect::<Vec<Matchup>>();
+        common.sort_by(|a, b| {
+            b.smoothed_win_rate(settings)
+                .total_cmp(&a.smoothed_win_rate(settings))
+        });
+        common
+    }
+
+    /// The five best common matchups, or none if there aren't enough.
+    #[must_use]
+    pub fn best_matchups(&self, settings: &StatSettings) -> Vec<Matchup> {
+        let common = self.common_matchups(settings);
+        if common.len() < 5 {
+            return vec![];
+        }
+        common[..5].to_vec()
+    }
+
+    /// The five worst common matchups, worst first, or none if there
+    /// aren't enough.
+    #[must_use]
+    pub fn worst_matchups(&self, settings: &StatSettings) -> Vec<Matchup> {
+        let common = self.common_matchups(settings);
+        if common.len() < 5 {
+            return vec![];
+        }
+        common[common.len() - 5..].iter().rev().cloned().collect()
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct Matchup {
     pub champion_id: i64,
@@ -102,32 +174,7 @@ impl<'de> Deserialize<'de> for MatchupData {
                     }
                 }
 
-                // Only consider matchups that represent at least a 0.5% possibility of showing up
-                all_matchups = all_matchups
-                    .into_iter()
-                    .filter(|a| f64::from(a.matches) >= (f64::from(total_matches) / 200.0))
-                    .collect::<Vec<Matchup>>();
-                all_matchups.sort_by(|a, b| b.winrate.partial_cmp(&a.winrate).unwrap());
-
-                if all_matchups.len() >= 5 {
-                    let best_matchups: Vec<Matchup> = all_matchups.clone()[..5].to_vec();
-                    let mut worst_matchups: Vec<Matchup> =
-                        all_matchups.clone()[all_matchups.len() - 5..].to_vec();
-                    worst_matchups.reverse();
-
-                    let matchup_data = MatchupData {
-                        best_matchups,
-                        worst_matchups,
-                        total_matches,
-                    };
-                    Ok(matchup_data)
-                } else {
-                    Ok(MatchupData {
-                        best_matchups: vec![],
-                        worst_matchups: vec![],
-                        total_matches: 0,
-                    })
-                }
+                Ok(MatchupData::new(all_matchups, total_matches))
             }
         }
 
@@ -4,6 +4,7 @@
 use crate::arena_overview::ArenaOverviewData;
 use crate::default_overview::{Abilities, OverviewData};
 use crate::mappings;
+use crate::stats::StatSettings;
 use serde::de::{Deserialize, Deserializer, IgnoredAny, SeqAccess, Visitor};
 use serde::{Deserialize as DeserializeDerive, Serialize};
 use std::collections::HashMap;
@@ -43,11 +44,14 @@ impl Overview {
     }
 
     #[must_use]
+    #[deprecated(note = "use `Overview::is_low_sample_size` to pick the threshold")]
     pub fn low_sample_size(&self) -> bool {
-        match self {
-            Overview::Arena(a) => a.low_sample_size,
-            Overview::Default(d) => d.low_sample_size,
-        }
+        self.is_low_sample_size(&StatSettings::DEFAULT)
+    }
+
+    #[must_use]
+    pub fn is_low_sample_size(&self, settings: &StatSettings) -> bool {
+        settings.is_low_sample_size(self.matches())
     }
 }
 
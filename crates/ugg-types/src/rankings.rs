@@ -0,0 +1,173 @@
+// Credit to https://github.com/pradishb/ugg-parser for figuring out the
+// structure of the champion rankings data.
+
+use crate::mappings;
+use crate::overview::handle_unknown;
+use serde::Serialize;
+use serde::de::{Deserialize, Deserializer, IgnoredAny, SeqAccess, Visitor};
+use std::collections::HashMap;
+use std::fmt;
+
+pub type Rankings =
+    HashMap<mappings::Region, HashMap<mappings::Rank, HashMap<mappings::Role, RankingData>>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub enum Tier {
+    SPlus,
+    S,
+    A,
+    B,
+    C,
+    D,
+}
+
+impl Tier {
+    /// Buckets a champion by where its rank falls among every champion
+    /// ranked in the same role, e.g. the top 5% are S+.
+    #[must_use]
+    pub fn from_rank(rank: i64, total_rank: i64) -> Self {
+        if rank <= 0 || total_rank <= 0 {
+            return Self::D;
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        let percentile = rank as f64 / total_rank as f64;
+        match percentile {
+            p if p <= 0.05 => Self::SPlus,
+            p if p <= 0.15 => Self::S,
+            p if p <= 0.35 => Self::A,
+            p if p <= 0.6 => Self::B,
+            p if p <= 0.85 => Self::C,
+            _ => Self::D,
+        }
+    }
+}
+
+impl fmt::Display for Tier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tier_str = match self {
+            Tier::SPlus => "S+",
+            Tier::S => "S",
+            Tier::A => "A",
+            Tier::B => "B",
+            Tier::C => "C",
+            Tier::D => "D",
+        };
+        write!(f, "{tier_str}")
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct RankingData {
+    pub wins: i64,
+    pub matches: i64,
+    /// This champion's position among all champions in the same role.
+    pub rank: i64,
+    /// The number of champions ranked in the same role.
+    pub total_rank: i64,
+    pub bans: i64,
+    /// The number of games played in this region and rank.
+    pub total_matches: i64,
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn ratio(numerator: i64, denominator: i64) -> f64 {
+    if denominator == 0 {
+        0.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
+
+impl RankingData {
+    #[must_use]
+    pub fn win_rate(&self) -> f64 {
+        ratio(self.wins, self.matches)
+    }
+
+    #[must_use]
+    pub fn pick_rate(&self) -> f64 {
+        ratio(self.matches, self.total_matches)
+    }
+
+    #[must_use]
+    pub fn ban_rate(&self) -> f64 {
+        ratio(self.bans, self.total_matches)
+    }
+
+    #[must_use]
+    pub fn tier(&self) -> Tier {
+        Tier::from_rank(self.rank, self.total_rank)
+    }
+}
+
+const WINS_INDEX: usize = 0;
+const MATCHES_INDEX: usize = 1;
+const RANK_INDEX: usize = 2;
+const TOTAL_RANK_INDEX: usize = 3;
+const BANS_INDEX: usize = 10;
+const TOTAL_MATCHES_INDEX: usize = 11;
+
+impl<'de> Deserialize<'de> for RankingData {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct RankingDataVisitor;
+
+        impl<'de> Visitor<'de> for RankingDataVisitor {
+            type Value = RankingData;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("ranking data")
+            }
+
+            fn visit_seq<V>(self, mut visitor: V) -> Result<RankingData, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let mut data = RankingData::default();
+                let mut index = 0;
+                loop {
+                    let field = match index {
+                        WINS_INDEX => &mut data.wins,
+                        MATCHES_INDEX => &mut data.matches,
+                        RANK_INDEX => &mut data.rank,
+                        TOTAL_RANK_INDEX => &mut data.total_rank,
+                        BANS_INDEX => &mut data.bans,
+                        TOTAL_MATCHES_INDEX => &mut data.total_matches,
+                        _ => match visitor.next_element::<IgnoredAny>()? {
+                            Some(_) => {
+                                index += 1;
+                                continue;
+                            }
+                            None => break,
+                        },
+                    };
+                    match visitor.next_element::<i64>() {
+                        Ok(None) => break,
+                        value => *field = handle_unknown(value),
+                    }
+                    index += 1;
+                }
+                Ok(data)
+            }
+        }
+
+        deserializer.deserialize_seq(RankingDataVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tier_from_rank() {
+        assert_eq!(Tier::from_rank(1, 60), Tier::SPlus);
+        assert_eq!(Tier::from_rank(9, 60), Tier::S);
+        assert_eq!(Tier::from_rank(30, 60), Tier::B);
+        assert_eq!(Tier::from_rank(60, 60), Tier::D);
+        assert_eq!(Tier::from_rank(0, 0), Tier::D);
+    }
+}
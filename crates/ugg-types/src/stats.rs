@@ -0,0 +1,163 @@
+use crate::arena_overview::{Augment, ChampionSynergy, PrismaticItem};
+use crate::default_overview::{Abilities, Items, LateItem, Runes, Shards, SummonerSpells};
+use crate::matchups::Matchup;
+use crate::rankings::RankingData;
+
+/// Thresholds used when judging how trustworthy a win rate is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StatSettings {
+    /// Overviews with fewer games than this are flagged as low sample size.
+    pub low_sample_size: i64,
+    /// How many games of "average" results are blended into every win rate
+    /// when smoothing. Larger values pull small samples harder towards
+    /// `prior_win_rate`.
+    pub prior_matches: f64,
+    /// The win rate assumed before seeing any games.
+    pub prior_win_rate: f64,
+    /// The z-score used for Wilson intervals, e.g. 1.96 for 95% confidence.
+    pub confidence_z: f64,
+}
+
+impl StatSettings {
+    pub const DEFAULT: Self = Self {
+        low_sample_size: 1000,
+        prior_matches: 50.0,
+        prior_win_rate: 0.5,
+        confidence_z: 1.96,
+    };
+
+    /// The win rate after blending in `prior_matches` games at
+    /// `prior_win_rate`, so that a 3-0 record doesn't outrank a 600-400 one.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn smoothed_win_rate(&self, wins: i64, matches: i64) -> f64 {
+        let prior_wins = self.prior_matches * self.prior_win_rate;
+        let total = matches.max(0) as f64 + self.prior_matches;
+        if total <= 0.0 {
+            return self.prior_win_rate;
+        }
+        (wins.max(0) as f64 + prior_wins) / total
+    }
+
+    /// The Wilson score interval for the true win rate, as `(lower, upper)`.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn wilson_interval(&self, wins: i64, matches: i64) -> (f64, f64) {
+        if matches <= 0 {
+            return (0.0, 1.0);
+        }
+        let z = self.confidence_z;
+        let n = matches as f64;
+        let p = win_rate(wins, matches);
+        let z2 = z * z;
+        let center = (p + z2 / (2.0 * n)) / (1.0 + z2 / n);
+        let margin = (z / (1.0 + z2 / n)) * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt();
+        ((center - margin).max(0.0), (center + margin).min(1.0))
+    }
+
+    #[must_use]
+    pub fn is_low_sample_size(&self, matches: i64) -> bool {
+        matches < self.low_sample_size
+    }
+}
+
+impl Default for StatSettings {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn win_rate(wins: i64, matches: i64) -> f64 {
+    if matches <= 0 {
+        return 0f64;
+    }
+    (wins as f64) / (matches as f64)
+}
+
+/// Anything with a win/loss record.
+pub trait Stat {
+    fn wins(&self) -> i64;
+    fn matches(&self) -> i64;
+
+    fn win_rate(&self) -> f64 {
+        win_rate(self.wins(), self.matches())
+    }
+
+    fn smoothed_win_rate(&self, settings: &StatSettings) -> f64 {
+        settings.smoothed_win_rate(self.wins(), self.matches())
+    }
+
+    fn wilson_interval(&self, settings: &StatSettings) -> (f64, f64) {
+        settings.wilson_interval(self.wins(), self.matches())
+    }
+}
+
+macro_rules! impl_stat {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Stat for $ty {
+                fn wins(&self) -> i64 {
+                    i64::from(self.wins)
+                }
+
+                fn matches(&self) -> i64 {
+                    i64::from(self.matches)
+                }
+            }
+        )*
+    };
+}
+
+impl_stat!(
+    Augment,
+    PrismaticItem,
+    LateItem,
+    Items,
+    Runes,
+    SummonerSpells,
+    Shards,
+    Abilities,
+    Matchup,
+    RankingData,
+);
+
+/// Arena synergies have no wins, so a top four finish counts as one.
+impl Stat for ChampionSynergy {
+    fn wins(&self) -> i64 {
+        self.top_four
+    }
+
+    fn matches(&self) -> i64 {
+        self.picked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smoothing_and_intervals() {
+        // A perfect record over a handful of games shouldn't beat a solid
+        // win rate over many.
+        let settings = StatSettings::default();
+        assert!(settings.smoothed_win_rate(3, 3) < settings.smoothed_win_rate(600, 1000));
+        assert!((settings.smoothed_win_rate(0, 0) - 0.5).abs() < f64::EPSILON);
+
+        let (lower, upper) = settings.wilson_interval(50, 100);
+        assert!(lower < 0.5 && upper > 0.5);
+        assert!((lower - 0.4038).abs() < 0.001);
+        assert_eq!(settings.wilson_interval(0, 0), (0.0, 1.0));
+
+        // A weaker prior lets small samples move further from it.
+        let loose = StatSettings {
+            prior_matches: 5.0,
+            ..settings
+        };
+        assert!(loose.smoothed_win_rate(3, 3) > settings.smoothed_win_rate(3, 3));
+        assert!(settings.is_low_sample_size(999));
+        assert!(!settings.is_low_sample_size(1000));
+    }
+}
@@ -0,0 +1,449 @@
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::bail;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use ddragon::models::champions::ChampionShort;
+use ugg_types::mappings::{Build, Mode, Rank, Region, Role};
+use uggo_config::{Config, Setting, Settings};
+use uggo_ugg_api::{
+    AbilitiesReport, ArenaReport, BuildReport, MatchupEntry, MatchupsReport, Named, Record,
+    StatEntry, UggApi,
+};
+
+use crate::components::shards;
+use crate::context::AppContext;
+use crate::i18n::{Text, tr};
+use crate::util;
+
+/// CLI tool to query builds from u.gg, for League of Legends.
+///
+/// Starts the interactive UI when no command is given.
+#[derive(Parser)]
+#[command(version, about)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    #[command(flatten)]
+    settings: SettingArgs,
+}
+
+/// Flags that override `config.toml` and the `UGGO_*` environment variables.
+#[derive(Args)]
+struct SettingArgs {
+    /// Config file to use instead of config.toml in the config directory.
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+
+    /// Game mode, e.g. aram, arena or urf.
+    #[arg(long, global = true, value_parser = setting::<Mode>)]
+    mode: Option<Mode>,
+
+    /// Region, e.g. na, euw or kr. Defaults to all regions.
+    #[arg(long, global = true, value_parser = setting::<Region>)]
+    region: Option<Region>,
+
+    /// Rank bracket, e.g. emerald+ or master. Defaults to the highest
+    /// bracket with data.
+    #[arg(long, global = true, value_parser = setting::<Rank>)]
+    rank: Option<Rank>,
+
+    /// Role, e.g. top, jungle, mid, adc or support. Defaults to the
+    /// champion's most played role.
+    #[arg(long, global = true, value_parser = setting::<Role>)]
+    role: Option<Role>,
+
+    /// Build variant, e.g. ap, ad, crit, onhit, lethality or tank.
+    #[arg(long, global = true, value_parser = setting::<Build>)]
+    build: Option<Build>,
+
+    /// Patch, e.g. 14.20. Defaults to the latest patch with data.
+    #[arg(long, global = true, value_parser = patch)]
+    patch: Option<String>,
+}
+
+const FLAG_ORIGIN: &str = "command line flags";
+
+fn setting<T: Setting>(value: &str) -> Result<T, String> {
+    T::parse_setting(value, FLAG_ORIGIN).map_err(|e| e.to_string())
+}
+
+fn patch(value: &str) -> Result<String, String> {
+    uggo_config::parse_patch(value, FLAG_ORIGIN).map_err(|e| e.to_string())
+}
+
+impl Cli {
+    /// Loads the config, with any flags given taking priority.
+    pub fn load_config(&self) -> anyhow::Result<Config> {
+        let args = &self.settings;
+        Ok(Config::load(
+            args.config.as_deref(),
+            Settings {
+                mode: args.mode,
+                region: args.region,
+                rank: args.rank,
+                role: args.role,
+                build: args.build,
+                patch: args.patch.clone(),
+                ..Settings::default()
+            },
+        )?)
+    }
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Print a champion's build as plain text and exit.
+    Build(BuildArgs),
+    /// Show, clear or prune the on-disk data cache.
+    #[command(subcommand)]
+    Cache(CacheCommand),
+}
+
+#[derive(Subcommand)]
+pub enum CacheCommand {
+    /// Show how much space each patch and kind of data takes up.
+    Info,
+    /// Delete everything in the cache.
+    Clear,
+    /// Delete everything but the newest patches.
+    Prune {
+        /// How many patches to keep.
+        #[arg(long, default_value_t = 2)]
+        keep: usize,
+    },
+}
+
+#[derive(Clone, Copy, Default, ValueEnum)]
+pub enum Format {
+    /// Human readable text.
+    #[default]
+    Table,
+    /// JSON, following the schema documented in the README.
+    Json,
+    /// YAML, with the same fields as JSON.
+    Yaml,
+}
+
+#[derive(Args)]
+pub struct BuildArgs {
+    /// Champion name or id, ignoring case and punctuation (e.g. `kaisa`).
+    champion: String,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+}
+
+impl Command {
+    pub fn run(self, config: &Config) -> anyhow::Result<()> {
+        match self {
+            Self::Build(args) => print_build(&args, config),
+            Self::Cache(command) => run_cache(&command, config),
+        }
+    }
+}
+
+fn print_build(args: &BuildArgs, config: &Config) -> anyhow::Result<()> {
+    let api = AppContext::load_api(config)?;
+    let champ = match_champ(&api, &args.champion)?.clone();
+    let settings = config.settings();
+    let role = settings.role.unwrap_or_default();
+    let mode = settings.mode.unwrap_or_default();
+    let region = settings.region.unwrap_or_default();
+    let build = settings.build.unwrap_or_default();
+
+    let report = api.get_build_report(&champ, role, region, mode, build)?;
+    let mut out = io::stdout().lock();
+    match args.format {
+        Format::Table => print_table(&mut out, &report)?,
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut out, &report)?;
+            writeln!(out)?;
+        }
+        Format::Yaml => serde_yaml_ng::to_writer(&mut out, &report)?,
+    }
+    Ok(())
+}
+
+/// Finds the champion asked for, or fails with the closest names instead of
+/// quietly printing a build for some other champion.
+fn match_champ<'a>(api: &'a UggApi, name: &str) -> anyhow::Result<&'a ChampionShort> {
+    if let Some(champ) = api.match_champ(name) {
+        return Ok(champ);
+    }
+    let suggestions = api
+        .suggest_champs(name, 5)
+        .into_iter()
+        .map(|c| c.name.as_str())
+        .collect::<Vec<_>>();
+    if suggestions.is_empty() {
+        bail!("Unknown champion \"{name}\"");
+    }
+    bail!(
+        "Unknown champion \"{name}\", did you mean {}?",
+        suggestions.join(", ")
+    )
+}
+
+fn run_cache(command: &CacheCommand, config: &Config) -> anyhow::Result<()> {
+    let dir = config.cache();
+    let mut out = io::stdout().lock();
+    match command {
+        CacheCommand::Info => print_cache_usage(&mut out, dir)?,
+        CacheCommand::Clear => {
+            let freed = uggo_ugg_api::clear_cache(dir)?;
+            writeln!(out, "{}: {}", tr(Text::Freed), util::format_size(freed))?;
+        }
+        CacheCommand::Prune { keep } => {
+            let (removed, freed) = uggo_ugg_api::prune_cache(dir, *keep, None)?;
+            writeln!(
+                out,
+                "{}: {}",
+                tr(Text::RemovedPatches),
+                if removed.is_empty() {
+                    "-".to_string()
+                } else {
+                    removed.join(", ")
+                }
+            )?;
+            writeln!(out, "{}: {}", tr(Text::Freed), util::format_size(freed))?;
+        }
+    }
+    Ok(())
+}
+
+fn print_cache_usage(out: &mut impl Write, dir: &Path) -> anyhow::Result<()> {
+    let usage = uggo_ugg_api::cache_usage(dir)?;
+    writeln!(out, "{}", dir.display())?;
+
+    let mut patch = None;
+    for entry in &usage {
+        if patch != Some(&entry.patch) {
+            patch = Some(&entry.patch);
+            let bytes = util::cache_size(usage.iter().filter(|u| u.patch == entry.patch));
+            writeln!(
+                out,
+                "\n{}: {}",
+                entry.patch.as_ref().map_or_else(
+                    || tr(Text::SharedFiles).to_string(),
+                    |p| format!("{} {p}", tr(Text::Patch))
+                ),
+                util::format_size(bytes)
+            )?;
+        }
+        writeln!(
+            out,
+            "  {:<16}{:>7}{:>11}",
+            entry.kind,
+            entry.files,
+            util::format_size(entry.bytes)
+        )?;
+    }
+
+    let bytes = util::cache_size(usage.iter());
+    writeln!(out, "\n{}: {}", tr(Text::Total), util::format_size(bytes))?;
+    Ok(())
+}
+
+fn print_table(out: &mut impl Write, report: &BuildReport) -> io::Result<()> {
+    writeln!(
+        out,
+        "{}, {} ({}, {}, {} {}, {} {})",
+        report.champion.name,
+        report.role,
+        report.mode,
+        report.region,
+        tr(Text::Patch),
+        report.patch,
+        tr(Text::Build),
+        report.build,
+    )?;
+    writeln!(
+        out,
+        "{}: {:.2}%, {}: {}{}",
+        tr(Text::WinRate),
+        report.record.win_rate * 100.0,
+        tr(Text::Matches),
+        report.record.matches,
+        if report.low_sample_size {
+            format!(" ({})", tr(Text::LowSampleSize))
+        } else {
+            String::new()
+        },
+    )?;
+
+    match &report.arena {
+        None => print_default(out, report)?,
+        Some(arena) => print_arena(out, report, arena)?,
+    }
+
+    if let Some(matchups) = &report.matchups {
+        print_matchups(out, matchups)?;
+    }
+
+    Ok(())
+}
+
+fn join<'a>(names: impl Iterator<Item = &'a str>) -> String {
+    names.collect::<Vec<_>>().join(", ")
+}
+
+fn names(entries: &[Named]) -> String {
+    join(entries.iter().filter_map(|e| e.name.as_deref()))
+}
+
+fn with_win_rate(name: &str, record: &Record) -> String {
+    format!("{name} ({:.1}%)", record.win_rate * 100.0)
+}
+
+/// Entries best first by smoothed win rate, as the interactive UI sorts them
+/// by default.
+fn best_first<T>(entries: &[T], record: impl Fn(&T) -> &Record) -> Vec<&T> {
+    let mut sorted = entries.iter().collect::<Vec<_>>();
+    sorted.sort_by(|a, b| {
+        record(b)
+            .smoothed_win_rate
+            .total_cmp(&record(a).smoothed_win_rate)
+    });
+    sorted
+}
+
+fn stat_entries<'a>(entries: impl IntoIterator<Item = &'a StatEntry>) -> String {
+    entries
+        .into_iter()
+        .filter_map(|e| e.name.as_deref().map(|n| with_win_rate(n, &e.record)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn print_default(out: &mut impl Write, report: &BuildReport) -> io::Result<()> {
+    writeln!(out, "\n{}", tr(Text::RunePaths))?;
+    if let Some(runes) = &report.runes {
+        for path in [&runes.primary, &runes.secondary] {
+            writeln!(out, "  {}: {}", path.name, names(&path.runes))?;
+        }
+    }
+
+    let shard_labels = [Text::ShardOffense, Text::ShardFlex, Text::ShardDefense];
+    writeln!(
+        out,
+        "  {}",
+        report
+            .shards
+            .iter()
+            .zip(shard_labels)
+            .map(|(shard, label)| format!("{}: {}", tr(label), shards::shard_text(shard.id)))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )?;
+
+    if let Some(spells) = &report.summoner_spells {
+        writeln!(out, "\n{}: {}", tr(Text::Spells), names(&spells.items))?;
+    }
+
+    print_items(out, report)?;
+    print_abilities(out, &report.abilities)
+}
+
+fn print_arena(out: &mut impl Write, report: &BuildReport, arena: &ArenaReport) -> io::Result<()> {
+    let augments = best_first(&arena.augments, |a| &a.record);
+    writeln!(out, "\n{}", tr(Text::Augments))?;
+    for (label, rarity) in [
+        (Text::SilverAugments, "silver"),
+        (Text::GoldAugments, "gold"),
+        (Text::PrismaticAugments, "prismatic"),
+    ] {
+        writeln!(
+            out,
+            "  {}: {}",
+            tr(label),
+            augments
+                .iter()
+                .filter(|a| a.rarity == Some(rarity))
+                .filter_map(|a| a.name.as_deref().map(|n| with_win_rate(n, &a.record)))
+                .take(6)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )?;
+    }
+
+    print_items(out, report)?;
+    writeln!(
+        out,
+        "  {}: {}",
+        tr(Text::PrismaticItems),
+        stat_entries(best_first(&arena.prismatic_items, |p| &p.record))
+    )?;
+
+    print_abilities(out, &report.abilities)?;
+
+    writeln!(
+        out,
+        "\n{}: {}",
+        tr(Text::ChampSynergies),
+        best_first(&arena.champion_synergies, |s| &s.record)
+            .into_iter()
+            .map(|s| with_win_rate(&s.champion.name, &s.record))
+            .take(10)
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+fn print_items(out: &mut impl Write, report: &BuildReport) -> io::Result<()> {
+    writeln!(out, "\n{}", tr(Text::Items))?;
+    for (label, items) in [
+        (Text::StartingItems, &report.starting_items),
+        (Text::CoreItems, &report.core_items),
+    ] {
+        writeln!(out, "  {}: {}", tr(label), names(&items.items))?;
+    }
+    for (label, items) in [
+        (Text::FourthItems, &report.fourth_items),
+        (Text::FifthItems, &report.fifth_items),
+        (Text::SixthItems, &report.sixth_items),
+    ] {
+        writeln!(
+            out,
+            "  {}: {}",
+            tr(label),
+            stat_entries(best_first(items, |i| &i.record))
+        )?;
+    }
+    Ok(())
+}
+
+fn print_abilities(out: &mut impl Write, abilities: &AbilitiesReport) -> io::Result<()> {
+    writeln!(
+        out,
+        "\n{}: {}",
+        tr(Text::AbilityOrder),
+        abilities.order.join(" ")
+    )?;
+    writeln!(
+        out,
+        "  {}: {}",
+        tr(Text::MaxOrder),
+        abilities.max_order.join(" > ")
+    )
+}
+
+fn print_matchups(out: &mut impl Write, matchups: &MatchupsReport) -> io::Result<()> {
+    let names = |list: &[MatchupEntry]| {
+        list.iter()
+            .map(|m| with_win_rate(&m.champion.name, &m.record))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    writeln!(out)?;
+    writeln!(out, "{}: {}", tr(Text::BestMatchups), names(&matchups.best))?;
+    writeln!(
+        out,
+        "{}: {}",
+        tr(Text::WorstMatchups),
+        names(&matchups.worst)
+    )
+}
@@ -0,0 +1,96 @@
+use ratatui::{
+    layout::Rect,
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Widget, Wrap},
+};
+
+use crate::components::ability_order::ability_colors;
+use crate::context::{AppContext, State};
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
+use crate::util;
+
+fn push_description(lines: &mut Vec<Line<'_>>, description: &str) {
+    lines.extend(
+        util::strip_html(description)
+            .lines()
+            .map(|l| Line::from(l.to_owned())),
+    );
+    lines.push(Line::from(""));
+}
+
+pub fn make<'a>(ctx: &AppContext) -> Option<(impl Widget + 'a, Rect)> {
+    let champion = ctx.selected_champ_details.as_ref()?;
+    let mut lines = vec![];
+
+    if let Some(overview) = &ctx.selected_champ_overview {
+        let max_order = overview
+            .abilities()
+            .ability_max_order
+            .chars()
+            .map(String::from)
+            .collect::<Vec<_>>();
+        if !max_order.is_empty() {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{}: ", tr(Text::MaxOrder)),
+                    Style::default().fg(theme().muted),
+                ),
+                Span::styled(
+                    max_order.join(" > "),
+                    Style::default().fg(theme().highlight).bold(),
+                ),
+            ]));
+            lines.push(Line::from(""));
+        }
+    }
+
+    lines.push(Line::from(format!("{} - {}", tr(Text::Passive), champion.passive.name)).bold());
+    push_description(&mut lines, &champion.passive.description);
+
+    for ((letter, color), spell) in ability_colors().iter().zip(&champion.spells) {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{letter} - {}", spell.name),
+                Style::default().fg(*color).bold(),
+            ),
+            Span::styled(
+                format!("  {}: {}s", tr(Text::Cooldown), spell.cooldown_burn),
+                Style::default().fg(theme().muted),
+            ),
+        ]));
+        push_description(&mut lines, &spell.description);
+    }
+
+    Some((
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .scroll((ctx.ability_detail_scroll, 0))
+            .style(Style::default().fg(theme().text))
+            .block(
+                Block::default()
+                    .title(format!(" {} ", champion.name))
+                    .title_style(Style::default().bold())
+                    .borders(Borders::ALL),
+            ),
+        Rect::new(0, 0, 64, 22),
+    ))
+}
+
+impl AppContext<'_> {
+    pub fn show_ability_detail(&mut self) {
+        if self.selected_champ_details.is_some() && self.selected_champ_overview.is_some() {
+            self.ability_detail_scroll = 0;
+            self.state = State::AbilityDetail;
+        }
+    }
+
+    pub fn scroll_ability_detail_down(&mut self) {
+        self.ability_detail_scroll = self.ability_detail_scroll.saturating_add(1);
+    }
+
+    pub fn scroll_ability_detail_up(&mut self) {
+        self.ability_detail_scroll = self.ability_detail_scroll.saturating_sub(1);
+    }
+}
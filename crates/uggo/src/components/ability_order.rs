@@ -1,3 +1,4 @@
+use ddragon::models::champion::Champion;
 use ratatui::{
     layout::Rect,
     style::{Color, Style, Stylize},
@@ -5,7 +6,20 @@ use ratatui::{
 };
 use ugg_types::overview::Overview;
 
-fn format_ability_level_order(ability_order: &[char], ability: char) -> String {
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
+
+pub fn ability_colors() -> [(char, Color); 4] {
+    let theme = theme();
+    [
+        ('Q', theme.cyan),
+        ('W', theme.yellow),
+        ('E', theme.green),
+        ('R', theme.red),
+    ]
+}
+
+fn format_ability_level_order(ability_order: &[char], ability: char, separator: &str) -> String {
     ability_order
         .iter()
         .copied()
@@ -17,66 +31,73 @@ fn format_ability_level_order(ability_order: &[char], ability: char) -> String {
             }
         })
         .collect::<Vec<String>>()
-        .join(" ")
+        .join(separator)
 }
 
-const ABILITY_LEFT_OFFSET: u16 = 4;
 const ABILITY_WIDTH: u16 = (5 /* Q */ + 5 /* W */ + 5 /* E */ + 3 /* R */) * 2 /* spaces */;
+const COMPACT_ABILITY_WIDTH: u16 = ABILITY_WIDTH / 2;
+const MIN_LABEL_WIDTH: u16 = 8;
 
 pub fn make_placeholder() -> impl Widget {
     Block::default()
-        .white()
-        .title(" Ability Order ")
+        .fg(theme().text)
+        .title(format!(" {} ", tr(Text::AbilityOrder)))
         .title_style(Style::default().bold())
         .borders(Borders::ALL)
 }
 
-pub fn make(bounds: Rect, overview: &Overview) -> Vec<(impl Widget, Rect)> {
+pub fn make(
+    bounds: Rect,
+    overview: &Overview,
+    champion: Option<&Champion>,
+) -> Vec<(impl Widget, Rect)> {
     let abilities = overview.abilities();
-    vec![
-        // Draw Q |
-        (
-            Paragraph::new("Q│ "),
-            Rect::new(bounds.left(), bounds.top(), ABILITY_LEFT_OFFSET, 1),
-        ),
-        // Draw Q abilities
-        (
-            Paragraph::new(format_ability_level_order(&abilities.ability_order, 'Q'))
-                .style(Style::default().fg(Color::Cyan).bold()),
-            Rect::new(bounds.left() + 3, bounds.top(), ABILITY_WIDTH, 1),
-        ),
-        // Draw W |
-        (
-            Paragraph::new("W│ "),
-            Rect::new(bounds.left(), bounds.top() + 1, ABILITY_LEFT_OFFSET, 1),
-        ),
-        // Draw W abilities
-        (
-            Paragraph::new(format_ability_level_order(&abilities.ability_order, 'W'))
-                .style(Style::default().fg(Color::Yellow).bold()),
-            Rect::new(bounds.left() + 3, bounds.top() + 1, ABILITY_WIDTH, 1),
-        ),
-        // Draw E |
-        (
-            Paragraph::new("E│ "),
-            Rect::new(bounds.left(), bounds.top() + 2, ABILITY_LEFT_OFFSET, 1),
-        ),
-        // Draw E abilities
-        (
-            Paragraph::new(format_ability_level_order(&abilities.ability_order, 'E'))
-                .style(Style::default().fg(Color::Green).bold()),
-            Rect::new(bounds.left() + 3, bounds.top() + 2, ABILITY_WIDTH, 1),
-        ),
-        // Draw R |
-        (
-            Paragraph::new("R│ "),
-            Rect::new(bounds.left(), bounds.top() + 3, ABILITY_LEFT_OFFSET, 1),
-        ),
-        // Draw R abilities
-        (
-            Paragraph::new(format_ability_level_order(&abilities.ability_order, 'R'))
-                .style(Style::default().fg(Color::Red).bold()),
-            Rect::new(bounds.left() + 3, bounds.top() + 3, ABILITY_WIDTH, 1),
+
+    // Ability names go between the letter and the dots. If there isn't enough
+    // room for them alongside the spaced-out dots, drop the spacing instead.
+    let (label_width, dots_width, separator) = match champion {
+        None => (1, ABILITY_WIDTH, " "),
+        Some(_) if bounds.width >= 3 + ABILITY_WIDTH + MIN_LABEL_WIDTH => {
+            (bounds.width - 2 - ABILITY_WIDTH, ABILITY_WIDTH, " ")
+        }
+        Some(_) => (
+            bounds.width.saturating_sub(2 + COMPACT_ABILITY_WIDTH),
+            COMPACT_ABILITY_WIDTH,
+            "",
         ),
-    ]
+    };
+
+    ability_colors()
+        .iter()
+        .zip(0u16..)
+        .flat_map(|((letter, color), row)| {
+            let label = match champion.and_then(|c| c.spells.get(usize::from(row))) {
+                Some(spell) => format!("{letter} {}", spell.name),
+                None => letter.to_string(),
+            };
+            let y = bounds.top() + row;
+            [
+                // Draw the letter and ability name
+                (
+                    Paragraph::new(label),
+                    Rect::new(bounds.left(), y, label_width, 1),
+                ),
+                // Draw |
+                (
+                    Paragraph::new("│ "),
+                    Rect::new(bounds.left() + label_width, y, 2, 1),
+                ),
+                // Draw the levels the ability is taken at
+                (
+                    Paragraph::new(format_ability_level_order(
+                        &abilities.ability_order,
+                        *letter,
+                        separator,
+                    ))
+                    .style(Style::default().fg(*color).bold()),
+                    Rect::new(bounds.left() + label_width + 2, y, dots_width, 1),
+                ),
+            ]
+        })
+        .collect()
 }
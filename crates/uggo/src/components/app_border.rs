@@ -4,38 +4,67 @@ use ratatui::{
     widgets::{Block, Borders, Widget},
 };
 
-use crate::context::AppContext;
+use crate::context::{AppContext, State};
+use crate::i18n::{Text, tr};
+use crate::keymap::Action;
+use crate::theme::theme;
 
+/// The bottom right title, split so that each setting can be clicked.
 #[allow(clippy::cast_precision_loss)]
-#[cfg(debug_assertions)]
-fn make_bottom_right_title<'a>(ctx: &'a AppContext) -> Line<'a> {
-    Line::from(format!(
-        " [Mode: {}] [Patch: {}] [Region: {}] [Render: {:.2}ms] ",
-        ctx.mode,
-        ctx.version,
-        ctx.region,
-        ctx.last_render_duration
-            .map_or(0.0, |d| d.as_micros() as f64 / 1000.0)
-    ))
-    .right_aligned()
+pub fn title_segments(ctx: &AppContext) -> Vec<(String, Option<State>)> {
+    let mut segments = vec![
+        (" ".to_string(), None),
+        (
+            format!("[{}: {}]", tr(Text::Mode), ctx.mode),
+            Some(State::ModeSelect),
+        ),
+        (" ".to_string(), None),
+        (
+            format!("[{}: {}]", tr(Text::Patch), ctx.version),
+            Some(State::VersionSelect),
+        ),
+        (" ".to_string(), None),
+        (
+            format!("[{}: {}]", tr(Text::Region), ctx.region),
+            Some(State::RegionSelect),
+        ),
+    ];
+    #[cfg(debug_assertions)]
+    segments.push((
+        format!(
+            " [Render: {:.2}ms]",
+            ctx.last_render_duration
+                .map_or(0.0, |d| d.as_micros() as f64 / 1000.0)
+        ),
+        None,
+    ));
+    segments.push((" ".to_string(), None));
+    segments
 }
 
-#[allow(clippy::cast_precision_loss)]
-#[cfg(not(debug_assertions))]
-fn make_bottom_right_title<'a>(ctx: &'a AppContext) -> Line<'a> {
-    Line::from(format!(
-        " [Mode: {}] [Patch: {}] [Region: {}] ",
-        ctx.mode, ctx.version, ctx.region
-    ))
+fn make_bottom_right_title<'a>(ctx: &AppContext) -> Line<'a> {
+    Line::from(
+        title_segments(ctx)
+            .into_iter()
+            .map(|(text, _)| text)
+            .collect::<String>(),
+    )
     .right_aligned()
 }
 
 pub fn make<'a>(ctx: &'a AppContext) -> impl Widget + 'a {
     Block::default()
         .title_top(Line::from(format!(" uggo v{} ", env!("CARGO_PKG_VERSION"))).centered())
-        .title_bottom(Line::from(" [Help: ?] ").left_aligned())
+        .title_bottom(
+            Line::from(format!(
+                " [{}: {}] ",
+                tr(Text::Help),
+                ctx.keymap.describe(Action::Help)
+            ))
+            .left_aligned(),
+        )
         .title_bottom(make_bottom_right_title(ctx))
         .title_style(Style::default().bold())
         .borders(Borders::ALL)
-        .magenta()
+        .fg(theme().magenta)
 }
@@ -2,21 +2,29 @@ use std::collections::HashMap;
 
 use ddragon::models::cdragon::AugmentRarity;
 use ratatui::{
+    layout::Constraint,
     style::{Style, Stylize},
-    widgets::{Block, Borders, List, ListItem, Widget},
+    text::Line,
+    widgets::{Block, Borders, Cell, Row, Table, Widget},
 };
 use ugg_types::arena_overview::{ArenaOverviewData, Augment};
+use ugg_types::stats::StatSettings;
+
+use crate::context::BuildSort;
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
+use crate::util;
 
 fn make_augment_block<'a>(title: &str) -> Block<'a> {
     Block::default()
-        .white()
+        .fg(theme().text)
         .title(format!(" {title} "))
         .title_style(Style::default().bold())
         .borders(Borders::ALL)
 }
 
 pub fn make_placeholder() -> impl Widget {
-    make_augment_block("Augments")
+    make_augment_block(tr(Text::Augments))
 }
 
 fn make_list_from_augments<'a>(
@@ -24,44 +32,62 @@ fn make_list_from_augments<'a>(
     augments: &[Augment],
     game_augments: &HashMap<i64, ddragon::models::Augment>,
     rarity: &AugmentRarity,
-) -> List<'a> {
-    List::new(
+    total_matches: i64,
+) -> Table<'a> {
+    Table::new(
         augments
             .iter()
             .filter_map(|i| {
                 game_augments
                     .get(&i.id)
                     .filter(|a| a.rarity == *rarity)
-                    .map(|it| ListItem::new(it.name.clone()))
+                    .map(|it| {
+                        Row::new(vec![
+                            Cell::from(it.name.clone()),
+                            Cell::from(
+                                Line::from(util::stat_spans(i.wins, i.matches, total_matches))
+                                    .right_aligned(),
+                            ),
+                        ])
+                    })
             })
             .take(6)
             .collect::<Vec<_>>(),
+        [Constraint::Min(0), Constraint::Length(13)],
     )
+    .column_spacing(1)
     .block(make_augment_block(name))
 }
 
 pub fn make(
     overview: &ArenaOverviewData,
     game_augments: &HashMap<i64, ddragon::models::Augment>,
+    sort: BuildSort,
+    settings: &StatSettings,
 ) -> [impl Widget; 3] {
+    let mut augments = overview.augments.clone();
+    util::sort_by_stat(&mut augments, sort, settings, |a| (a.wins, a.matches));
     [
         make_list_from_augments(
-            "Silver Augments",
-            &overview.augments,
+            tr(Text::SilverAugments),
+            &augments,
             game_augments,
             &AugmentRarity::Silver,
+            overview.matches,
         ),
         make_list_from_augments(
-            "Gold Augments",
-            &overview.augments,
+            tr(Text::GoldAugments),
+            &augments,
             game_augments,
             &AugmentRarity::Gold,
+            overview.matches,
         ),
         make_list_from_augments(
-            "Prismatic Augments",
-            &overview.augments,
+            tr(Text::PrismaticAugments),
+            &augments,
             game_augments,
             &AugmentRarity::Prismatic,
+            overview.matches,
         ),
     ]
 }
@@ -1,30 +1,32 @@
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     widgets::{Block, Borders, List, ListItem, ListState},
 };
 use ugg_types::mappings::Build;
 
 use crate::context::{AppContext, State};
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
 
 #[allow(clippy::cast_possible_truncation)]
 pub fn make<'a>(ctx: &AppContext) -> (List<'a>, ListState, Rect) {
     let overview_kind_list = List::new(
         Build::all()
             .iter()
-            .map(|m| ListItem::new(m.to_string()).style(Style::default().fg(Color::White)))
+            .map(|m| ListItem::new(m.to_string()).style(Style::default().fg(theme().text)))
             .collect::<Vec<_>>(),
     )
-    .style(Style::default().fg(Color::White).not_bold())
+    .style(Style::default().fg(theme().text).not_bold())
     .highlight_style(
         Style::default()
-            .fg(Color::Green)
+            .fg(theme().accent)
             .add_modifier(Modifier::ITALIC),
     )
     .highlight_symbol("> ")
     .block(
         Block::default()
-            .title(" Overview Kind ")
+            .title(format!(" {} ", tr(Text::OverviewKind)))
             .title_style(Style::default().bold())
             .borders(Borders::ALL),
     );
@@ -0,0 +1,157 @@
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, List, ListItem, ListState},
+};
+
+use crate::context::AppContext;
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
+use crate::util;
+
+/// The rows that do something, shown above the usage.
+const ACTIONS: usize = 2;
+
+/// Each patch, newest first, then the shared files, with their size.
+fn patch_rows(ctx: &AppContext) -> Vec<String> {
+    let mut patches = Vec::new();
+    for entry in &ctx.cache_usage {
+        if !patches.contains(&&entry.patch) {
+            patches.push(&entry.patch);
+        }
+    }
+    patches
+        .into_iter()
+        .map(|patch| {
+            let bytes = util::cache_size(ctx.cache_usage.iter().filter(|u| u.patch == *patch));
+            format!(
+                "{}: {}",
+                patch.as_ref().map_or_else(
+                    || tr(Text::SharedFiles).to_string(),
+                    |p| format!("{} {p}", tr(Text::Patch))
+                ),
+                util::format_size(bytes),
+            )
+        })
+        .collect()
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub fn make<'a>(ctx: &AppContext) -> (List<'a>, ListState, Rect) {
+    let total = util::cache_size(ctx.cache_usage.iter());
+    let actions = [
+        format!("{}: ‹{}›", tr(Text::PruneCache), ctx.cache_keep),
+        tr(Text::ClearCache).to_string(),
+    ];
+    let usage = patch_rows(ctx);
+
+    let title = format!(" {}: {} ", tr(Text::ManageCache), util::format_size(total));
+    let hint = format!(" {} ", ctx.keymap.hint(tr(Text::CacheHint), false));
+    let widest = actions
+        .iter()
+        .chain(&usage)
+        .map(|row| Line::from(row.as_str()).width() + 3)
+        .chain([
+            Line::from(title.as_str()).width(),
+            Line::from(hint.as_str()).width(),
+        ])
+        .max()
+        .unwrap_or_default();
+    let height = ACTIONS + usage.len();
+
+    let items = actions
+        .into_iter()
+        .map(|row| ListItem::new(row).style(Style::default().fg(theme().text)))
+        .chain(
+            usage
+                .into_iter()
+                .map(|row| ListItem::new(row).style(Style::default().fg(theme().muted))),
+        )
+        .collect::<Vec<_>>();
+
+    let cache_list = List::new(items)
+        .style(Style::default().fg(theme().text).not_bold())
+        .highlight_style(
+            Style::default()
+                .fg(theme().accent)
+                .add_modifier(Modifier::ITALIC),
+        )
+        .highlight_symbol("> ")
+        .block(
+            Block::default()
+                .title(title)
+                .title_style(Style::default().bold())
+                .title_bottom(Line::from(hint).right_aligned())
+                .borders(Borders::ALL),
+        );
+
+    (
+        cache_list,
+        ListState::default().with_selected(ctx.cache_scroll_pos),
+        Rect::new(0, 0, widest as u16 + 2, height as u16 + 2),
+    )
+}
+
+impl AppContext<'_> {
+    pub fn load_cache_usage(&mut self) {
+        self.cache_usage = uggo_ugg_api::cache_usage(self.config.cache()).unwrap_or_else(|e| {
+            log::error!("Failed to read the cache: {e:?}");
+            Vec::new()
+        });
+    }
+
+    pub fn next_cache_action(&mut self) {
+        if let Some(pos) = self.cache_scroll_pos
+            && pos + 1 < ACTIONS
+        {
+            self.cache_scroll_pos = Some(pos + 1);
+        }
+    }
+
+    pub fn prev_cache_action(&mut self) {
+        if let Some(pos) = self.cache_scroll_pos
+            && pos > 0
+        {
+            self.cache_scroll_pos = Some(pos - 1);
+        }
+    }
+
+    pub fn keep_more_patches(&mut self) {
+        self.cache_keep += 1;
+    }
+
+    /// At least the newest patch is kept. The patch being looked at is kept
+    /// on top of these, however old it is.
+    pub fn keep_fewer_patches(&mut self) {
+        self.cache_keep = self.cache_keep.saturating_sub(1).max(1);
+    }
+
+    /// Moves to the `index`th action, returning whether it exists.
+    pub fn set_cache_pos(&mut self, index: usize) -> bool {
+        if index < ACTIONS {
+            self.cache_scroll_pos = Some(index);
+        }
+        index < ACTIONS
+    }
+
+    /// Prunes or clears the cache, leaving the popup open with the new usage.
+    pub fn run_cache_action(&mut self) {
+        let dir = self.config.cache();
+        let result = match self.cache_scroll_pos {
+            Some(0) => uggo_ugg_api::prune_cache(dir, self.cache_keep, Some(&self.version)).map(
+                |(removed, freed)| {
+                    log::info!("Removed patches {removed:?} from the cache");
+                    freed
+                },
+            ),
+            Some(1) => uggo_ugg_api::clear_cache(dir),
+            _ => return,
+        };
+        match result {
+            Ok(freed) => log::info!("Freed {} from the cache", util::format_size(freed)),
+            Err(e) => log::error!("Failed to clean up the cache: {e:?}"),
+        }
+        self.load_cache_usage();
+    }
+}
@@ -1,23 +1,96 @@
+use ddragon::models::{champion::Tag, champions::ChampionShort};
 use ratatui::{
-    style::{Color, Modifier, Style},
-    widgets::{Block, Borders, List, ListState},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, List, ListItem, ListState},
 };
+use std::sync::mpsc::{self, TryRecvError};
+use std::thread;
+use ugg_types::mappings::{Mode, Region, Role};
+use uggo_ugg_api::FetchedRankings;
 
+use crate::components::status_bar;
 use crate::context::{AppContext, State};
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
+
+/// A role counts as viable for a champion if at least this share of the
+/// champion's games are played in it.
+const MIN_ROLE_SHARE: f64 = 0.1;
+
+const ROLE_FILTERS: [Option<Role>; 6] = [
+    None,
+    Some(Role::Top),
+    Some(Role::Jungle),
+    Some(Role::Mid),
+    Some(Role::ADCarry),
+    Some(Role::Support),
+];
+
+const CLASS_FILTERS: [Option<Tag>; 7] = [
+    None,
+    Some(Tag::Assassin),
+    Some(Tag::Fighter),
+    Some(Tag::Mage),
+    Some(Tag::Marksman),
+    Some(Tag::Support),
+    Some(Tag::Tank),
+];
+
+/// The width left for a list entry once the borders and highlight symbol
+/// are accounted for.
+const ENTRY_WIDTH: usize = 15;
+const WIN_RATE_WIDTH: usize = 5;
+
+pub const fn tag_text(tag: Tag) -> Text {
+    match tag {
+        Tag::Assassin => Text::Assassin,
+        Tag::Fighter => Text::Fighter,
+        Tag::Mage => Text::Mage,
+        Tag::Marksman => Text::Marksman,
+        Tag::Support => Text::SupportClass,
+        Tag::Tank => Text::Tank,
+    }
+}
+
+fn next_filter<T: Copy + PartialEq>(filters: &[Option<T>], current: Option<T>) -> Option<T> {
+    let index = filters
+        .iter()
+        .position(|f| *f == current)
+        .unwrap_or_default();
+    filters[(index + 1) % filters.len()]
+}
+
+fn filter_chips<'a>(ctx: &AppContext) -> Option<Line<'a>> {
+    let chips = [
+        ctx.champ_role_filter.map(|r| r.to_string()),
+        ctx.champ_class_filter.map(|t| tr(tag_text(t)).to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+    (!chips.is_empty()).then(|| {
+        Line::from(format!(" {} ", chips.join(" · ")))
+            .style(Style::default().fg(theme().cyan).not_bold())
+    })
+}
 
 pub fn make<'a>(ctx: &'a AppContext) -> (List<'a>, ListState) {
+    let mut block = Block::default()
+        .title(format!(" {} ", tr(Text::Champions)))
+        .style(Style::default().fg(theme().text).bold())
+        .borders(Borders::ALL);
+    if let Some(chips) = filter_chips(ctx) {
+        block = block.title_bottom(chips);
+    }
+
     (
         List::new(ctx.champ_list.clone())
-            .block(
-                Block::default()
-                    .title(" Champions ")
-                    .style(Style::default().fg(Color::White).bold())
-                    .borders(Borders::ALL),
-            )
-            .style(Style::default().fg(Color::White).not_bold())
+            .block(block)
+            .style(Style::default().fg(theme().text).not_bold())
             .highlight_style(
                 Style::default()
-                    .fg(Color::Green)
+                    .fg(theme().accent)
                     .add_modifier(Modifier::ITALIC),
             )
             .highlight_symbol("> "),
@@ -54,6 +127,153 @@ impl AppContext<'_> {
         }
     }
 
+    /// Whether `champ` passes the active role and class filters.
+    pub fn matches_champ_filters(&self, champ: &ChampionShort) -> bool {
+        let class_matches = self
+            .champ_class_filter
+            .is_none_or(|tag| champ.tags.contains(&tag));
+        let role_matches = self.champ_role_filter.is_none_or(|role| {
+            self.champ_rankings.get(&champ.id).is_some_and(|by_role| {
+                let total = by_role.values().map(|d| d.matches).sum::<i64>();
+                by_role.get(&role).is_some_and(|d| {
+                    #[allow(clippy::cast_precision_loss)]
+                    let share = d.matches as f64 / total as f64;
+                    share >= MIN_ROLE_SHARE
+                })
+            })
+        });
+        class_matches && role_matches
+    }
+
+    /// The champion's win rate in the filtered role, or its most played role.
+    fn champ_win_rate(&self, champ: &ChampionShort) -> Option<f64> {
+        let by_role = self.champ_rankings.get(&champ.id)?;
+        let data = match self.champ_role_filter {
+            Some(role) => by_role.get(&role)?,
+            None => by_role.values().max_by_key(|d| d.matches)?,
+        };
+        Some(data.win_rate())
+    }
+
+    pub fn champ_list_item<'a>(&self, champ: &ChampionShort) -> ListItem<'a> {
+        let name = if self.is_favorite(champ) {
+            format!("★ {}", champ.name)
+        } else {
+            champ.name.clone()
+        };
+        if !self.show_champ_win_rates {
+            return ListItem::new(name);
+        }
+
+        let name_width = ENTRY_WIDTH - WIN_RATE_WIDTH - 1;
+        let win_rate = self
+            .champ_win_rate(champ)
+            .map(|wr| format!("{:.1}", wr * 100.0))
+            .unwrap_or_default();
+        ListItem::new(format!(
+            "{name:<name_width$.name_width$} {win_rate:>WIN_RATE_WIDTH$}"
+        ))
+    }
+
+    /// Starts fetching per-role rankings for every champion if a filter needs
+    /// them.
+    pub fn load_champ_rankings(&mut self) {
+        if self.champ_role_filter.is_some() || self.show_champ_win_rates {
+            self.fetch_champ_rankings();
+        }
+    }
+
+    /// Starts fetching per-role rankings for every champion unless they're
+    /// already loaded for the current region and mode. That's a request per
+    /// champion, so it's done in the background unless they're all cached.
+    pub fn fetch_champ_rankings(&mut self) {
+        let key = (self.region, self.mode);
+        if self.champ_rankings_key == Some(key)
+            || self.champ_rankings_failed == Some(key)
+            || self
+                .champ_rankings_loading
+                .as_ref()
+                .is_some_and(|(loading, _)| *loading == key)
+        {
+            return;
+        }
+
+        let request = self.api.all_rankings_request(self.mode);
+        if request.is_cached() {
+            self.store_champ_rankings(key, request.fetch());
+            return;
+        }
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            // The receiver is gone if something else was asked for meanwhile.
+            let _ = sender.send(request.fetch());
+        });
+        self.champ_rankings_loading = Some((key, receiver));
+    }
+
+    /// Picks up rankings that finished loading in the background.
+    pub fn poll_champ_rankings(&mut self) {
+        let Some((key, receiver)) = &self.champ_rankings_loading else {
+            return;
+        };
+        let key = *key;
+        match receiver.try_recv() {
+            Ok(fetched) => {
+                self.champ_rankings_loading = None;
+                self.store_champ_rankings(key, fetched);
+                self.update_champ_list();
+            }
+            Err(TryRecvError::Disconnected) => self.champ_rankings_loading = None,
+            Err(TryRecvError::Empty) => {}
+        }
+    }
+
+    fn store_champ_rankings(&mut self, key: (Region, Mode), fetched: FetchedRankings) {
+        let (region, mode) = key;
+        match self
+            .api
+            .store_all_rankings(fetched, region, self.config.settings().rank)
+        {
+            Ok(rankings) => {
+                self.champ_rankings = rankings;
+                self.champ_rankings_key = Some(key);
+                if self.state == State::TierList {
+                    self.update_tier_list();
+                }
+            }
+            Err(e) => {
+                log::error!("Failed to load champion rankings: {e:?}");
+                self.last_error = Some(status_bar::describe_error(
+                    &e,
+                    tr(Text::Champions),
+                    mode,
+                    &self.version,
+                ));
+                self.champ_rankings_failed = Some(key);
+            }
+        }
+    }
+
+    fn refresh_champ_filters(&mut self) {
+        self.champ_scroll_pos = None;
+        self.update_champ_list();
+    }
+
+    pub fn cycle_champ_role_filter(&mut self) {
+        self.champ_role_filter = next_filter(&ROLE_FILTERS, self.champ_role_filter);
+        self.refresh_champ_filters();
+    }
+
+    pub fn cycle_champ_class_filter(&mut self) {
+        self.champ_class_filter = next_filter(&CLASS_FILTERS, self.champ_class_filter);
+        self.refresh_champ_filters();
+    }
+
+    pub fn toggle_champ_win_rates(&mut self) {
+        self.show_champ_win_rates = !self.show_champ_win_rates;
+        self.refresh_champ_filters();
+    }
+
     pub fn go_to_search(&mut self) {
         self.state = State::TextInput;
         self.update_champ_list();
@@ -1,36 +1,58 @@
 use ddragon::models::champions::ChampionShort;
 use ratatui::{
-    style::{Color, Style},
+    style::Style,
     widgets::{Paragraph, Widget},
 };
 use ugg_types::overview::Overview;
 
-use crate::context::AppContext;
+use crate::context::{AppContext, BuildSort, State};
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
+
+/// The first line, split so that the role and build can be clicked.
+pub fn segments(ctx: &AppContext, selected: &ChampionShort) -> Vec<(String, Option<State>)> {
+    let sort = match ctx.build_sort {
+        BuildSort::WinRate => tr(Text::WinRate),
+        BuildSort::Popularity => tr(Text::Popularity),
+    };
+    vec![
+        (
+            format!(" {}: {}, ", tr(Text::Selected), selected.name),
+            None,
+        ),
+        (
+            format!(
+                "{}: {}",
+                tr(Text::Role),
+                ctx.selected_champ_role.unwrap_or(ctx.role)
+            ),
+            Some(State::RoleSelect),
+        ),
+        (", ".to_string(), None),
+        (
+            format!("{}: {}", tr(Text::Build), ctx.build),
+            Some(State::BuildSelect),
+        ),
+        (format!(", {}: {sort}", tr(Text::SortBy)), None),
+    ]
+}
 
 pub fn make<'a>(
     ctx: &'a AppContext,
     overview: &'a Overview,
     selected: &'a ChampionShort,
 ) -> impl Widget + 'a {
-    let champ_name = selected.name.clone();
-    let (selected_text, color) = if overview.low_sample_size() {
+    let selected_text = segments(ctx, selected)
+        .into_iter()
+        .map(|(text, _)| text)
+        .collect::<String>();
+    let (selected_text, color) = if overview.is_low_sample_size(&ctx.api.stat_settings) {
         (
-            format!(
-                " Selected: {champ_name}, Role: {}, Build: {}\n ⚠️ Warning: Low Sample Size",
-                ctx.selected_champ_role.unwrap_or(ctx.role),
-                ctx.build
-            ),
-            Color::Yellow,
+            format!("{selected_text}\n ⚠️ {}", tr(Text::LowSampleSize)),
+            theme().highlight,
         )
     } else {
-        (
-            format!(
-                " Selected: {champ_name}, Role: {}, Build: {}",
-                ctx.selected_champ_role.unwrap_or(ctx.role),
-                ctx.build
-            ),
-            Color::Green,
-        )
+        (selected_text, theme().accent)
     };
 
     Paragraph::new(selected_text).style(Style::default().fg(color).bold())
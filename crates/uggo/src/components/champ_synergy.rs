@@ -2,42 +2,72 @@ use std::collections::HashMap;
 
 use ddragon::models::champions::ChampionShort;
 use ratatui::{
+    layout::Constraint,
     style::{Style, Stylize},
-    widgets::{Block, Borders, List, ListItem, Widget},
+    text::Line,
+    widgets::{Block, Borders, Cell, Row, Table, Widget},
 };
 use ugg_types::arena_overview::{ArenaOverviewData, ChampionSynergy};
+use ugg_types::stats::StatSettings;
+
+use crate::context::BuildSort;
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
+use crate::util;
 
 fn make_synergy_block<'a>(title: &str) -> Block<'a> {
     Block::default()
-        .white()
+        .fg(theme().text)
         .title(format!(" {title} "))
         .title_style(Style::default().bold())
         .borders(Borders::ALL)
 }
 
 pub fn make_placeholder() -> impl Widget {
-    make_synergy_block("Champ Synergies")
+    make_synergy_block(tr(Text::ChampSynergies))
 }
 
 fn make_list_from_champ_synergies<'a>(
     name: &str,
     synergies: &[ChampionSynergy],
     champs: &HashMap<String, ChampionShort>,
-) -> List<'a> {
-    List::new(
+    total_matches: i64,
+) -> Table<'a> {
+    Table::new(
         synergies
             .iter()
             .filter_map(|i| {
-                champs
-                    .get(&i.id.to_string())
-                    .map(|it| ListItem::new(it.name.clone()))
+                champs.get(&i.id.to_string()).map(|it| {
+                    Row::new(vec![
+                        Cell::from(it.name.clone()),
+                        // Arena has no wins, so the top four rate stands in.
+                        Cell::from(
+                            Line::from(util::stat_spans(i.top_four, i.picked, total_matches))
+                                .right_aligned(),
+                        ),
+                    ])
+                })
             })
             .take(10)
             .collect::<Vec<_>>(),
+        [Constraint::Min(0), Constraint::Length(13)],
     )
+    .column_spacing(1)
     .block(make_synergy_block(name))
 }
 
-pub fn make(overview: &ArenaOverviewData, champs: &HashMap<String, ChampionShort>) -> impl Widget {
-    make_list_from_champ_synergies("Champ Synergies", &overview.champion_synergies, champs)
+pub fn make(
+    overview: &ArenaOverviewData,
+    champs: &HashMap<String, ChampionShort>,
+    sort: BuildSort,
+    settings: &StatSettings,
+) -> impl Widget {
+    let mut synergies = overview.champion_synergies.clone();
+    util::sort_by_stat(&mut synergies, sort, settings, |s| (s.top_four, s.picked));
+    make_list_from_champ_synergies(
+        tr(Text::ChampSynergies),
+        &synergies,
+        champs,
+        overview.matches,
+    )
 }
@@ -0,0 +1,230 @@
+use ddragon::models::cdragon::AugmentRarity;
+use ratatui::{
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Paragraph, Tabs, Wrap},
+};
+use ugg_types::{mappings::Mode, overview::Overview};
+
+use crate::components::{items, rune_path, shards};
+use crate::context::{AppContext, State};
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
+use crate::util;
+
+/// Below this size even the compact layout doesn't fit.
+pub const MIN_WIDTH: u16 = 40;
+pub const MIN_HEIGHT: u16 = 16;
+
+/// Item names longer than this are cut short in the compact layout.
+const ITEM_NAME_WIDTH: usize = 16;
+const AUGMENTS_PER_RARITY: usize = 4;
+
+/// The sections of the compact layout, one shown at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompactPage {
+    #[default]
+    Champions,
+    Runes,
+    Items,
+    Abilities,
+    Matchups,
+}
+
+impl CompactPage {
+    const ALL: [Self; 5] = [
+        Self::Champions,
+        Self::Runes,
+        Self::Items,
+        Self::Abilities,
+        Self::Matchups,
+    ];
+
+    const fn title(self, mode: Mode) -> Text {
+        match (self, mode) {
+            (Self::Champions, _) => Text::Champions,
+            (Self::Runes, Mode::Arena) => Text::Augments,
+            (Self::Runes, _) => Text::RunePaths,
+            (Self::Items, _) => Text::Items,
+            (Self::Abilities, _) => Text::AbilityOrder,
+            (Self::Matchups, Mode::Arena) => Text::ChampSynergies,
+            (Self::Matchups, _) => Text::Matchups,
+        }
+    }
+}
+
+fn abbreviate(name: &str) -> String {
+    if name.chars().count() <= ITEM_NAME_WIDTH {
+        name.to_string()
+    } else {
+        let short = name.chars().take(ITEM_NAME_WIDTH - 1).collect::<String>();
+        format!("{}…", short.trim_end())
+    }
+}
+
+fn labelled<'a>(label: Text, values: impl Iterator<Item = String>) -> Line<'a> {
+    Line::from(vec![
+        Span::styled(
+            format!("{}: ", tr(label)),
+            Style::default().fg(theme().muted).bold(),
+        ),
+        Span::raw(values.collect::<Vec<_>>().join(", ")),
+    ])
+}
+
+pub fn make_tabs<'a>(ctx: &AppContext) -> Tabs<'a> {
+    let page = ctx.compact_page_shown();
+    Tabs::new(
+        CompactPage::ALL
+            .iter()
+            .map(|p| tr(p.title(ctx.mode)))
+            .collect::<Vec<_>>(),
+    )
+    .select(CompactPage::ALL.iter().position(|p| *p == page))
+    .style(Style::default().fg(theme().muted))
+    .highlight_style(Style::default().fg(theme().accent).bold())
+}
+
+/// Keystones and tree names, or augments in arena.
+pub fn make_runes<'a>(ctx: &AppContext, overview: &Overview) -> Paragraph<'a> {
+    let lines = match overview {
+        Overview::Default(d) => {
+            let [primary, secondary] = util::group_runes(&d.runes.rune_ids, &ctx.api.runes);
+            let keystone = primary
+                .1
+                .first()
+                .map(|r| format!(": {}", r.rune.name))
+                .unwrap_or_default();
+            let shard_labels = [Text::ShardOffense, Text::ShardFlex, Text::ShardDefense];
+            vec![
+                Line::from(format!("● {}{keystone}", primary.0))
+                    .fg(rune_path::rune_color(&primary.0))
+                    .bold(),
+                Line::from(format!("● {}", secondary.0))
+                    .fg(rune_path::rune_color(&secondary.0))
+                    .bold(),
+                Line::default(),
+                Line::from(
+                    d.shards
+                        .shard_ids
+                        .iter()
+                        .zip(shard_labels)
+                        .map(|(id, label)| format!("{}: {}", tr(label), shards::shard_text(*id)))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ),
+                labelled(
+                    Text::Spells,
+                    d.summoner_spells
+                        .spell_ids
+                        .iter()
+                        .filter_map(|id| ctx.api.summoner_spells.get(id).cloned()),
+                ),
+            ]
+        }
+        Overview::Arena(a) => {
+            let mut augments = a.augments.clone();
+            util::sort_by_stat(&mut augments, ctx.build_sort, &ctx.api.stat_settings, |a| {
+                (a.wins, a.matches)
+            });
+            [
+                (Text::SilverAugments, AugmentRarity::Silver),
+                (Text::GoldAugments, AugmentRarity::Gold),
+                (Text::PrismaticAugments, AugmentRarity::Prismatic),
+            ]
+            .into_iter()
+            .map(|(label, rarity)| {
+                labelled(
+                    label,
+                    augments
+                        .iter()
+                        .filter_map(|a| {
+                            ctx.api
+                                .arena_augments
+                                .get(&a.id)
+                                .filter(|game| game.rarity == rarity)
+                                .map(|game| game.name.clone())
+                        })
+                        .take(AUGMENTS_PER_RARITY),
+                )
+            })
+            .collect()
+        }
+    };
+    Paragraph::new(lines).wrap(Wrap { trim: false })
+}
+
+/// Every item column on its own line, with long names cut short.
+pub fn make_items<'a>(ctx: &AppContext, overview: &Overview) -> Paragraph<'a> {
+    let labels = match overview {
+        Overview::Default(_) => [
+            Text::StartingItems,
+            Text::CoreItems,
+            Text::FourthItems,
+            Text::FifthItems,
+            Text::SixthItems,
+        ],
+        Overview::Arena(_) => [
+            Text::SecondThirdItems,
+            Text::FourthItems,
+            Text::FifthItems,
+            Text::SixthItems,
+            Text::PrismaticItems,
+        ],
+    };
+    let lines = items::columns(
+        overview,
+        &ctx.api.items,
+        ctx.build_sort,
+        &ctx.api.stat_settings,
+    )
+    .iter()
+    .zip(labels)
+    .map(|(column, label)| {
+        labelled(
+            label,
+            column
+                .iter()
+                .filter_map(|id| ctx.api.items.get(&id.to_string()))
+                .map(|item| abbreviate(&item.name)),
+        )
+    })
+    .collect::<Vec<_>>();
+    Paragraph::new(lines).wrap(Wrap { trim: false })
+}
+
+impl AppContext<'_> {
+    /// The page to show, which is always the champion list while searching
+    /// or before a champion is picked.
+    pub const fn compact_page_shown(&self) -> CompactPage {
+        if self.selected_champ.is_none()
+            || matches!(self.state, State::TextInput | State::ChampScroll)
+        {
+            CompactPage::Champions
+        } else {
+            self.compact_page
+        }
+    }
+
+    fn move_compact_page(&mut self, forward: bool) {
+        let pages = CompactPage::ALL;
+        let index = pages
+            .iter()
+            .position(|p| *p == self.compact_page_shown())
+            .unwrap_or_default();
+        let next = if forward {
+            (index + 1) % pages.len()
+        } else {
+            (index + pages.len() - 1) % pages.len()
+        };
+        self.compact_page = pages[next];
+    }
+
+    pub fn next_compact_page(&mut self) {
+        self.move_compact_page(true);
+    }
+
+    pub fn prev_compact_page(&mut self) {
+        self.move_compact_page(false);
+    }
+}
@@ -0,0 +1,224 @@
+use ratatui::{
+    layout::Alignment,
+    style::{Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+use ugg_types::{default_overview::Abilities, overview::Overview};
+
+use crate::components::{items, shards};
+use crate::context::{AppContext, State};
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
+use crate::util;
+
+/// How many late item or augment options are compared per slot.
+const OPTIONS_SHOWN: usize = 3;
+
+/// One side of a comparison, with every id already turned into a name so
+/// that a pinned build stays readable after switching patches.
+#[derive(Debug, Clone)]
+pub struct CompareSide {
+    title: String,
+    record: String,
+    sections: Vec<(Text, Vec<String>)>,
+}
+
+impl CompareSide {
+    /// Captures the build currently on screen.
+    fn capture(ctx: &AppContext) -> Option<Self> {
+        let champ = ctx.selected_champ.as_ref()?;
+        let overview = ctx.selected_champ_overview.as_ref()?;
+        let (wins, matches) = match overview {
+            Overview::Default(d) => (d.wins, d.matches),
+            Overview::Arena(a) => (a.wins, a.matches),
+        };
+
+        Some(Self {
+            title: format!(
+                "{}, {}, {} {}, {}, {}, {} {}",
+                champ.name,
+                ctx.selected_champ_role.unwrap_or(ctx.role),
+                tr(Text::Build),
+                ctx.build,
+                ctx.region,
+                ctx.mode,
+                tr(Text::Patch),
+                ctx.version,
+            ),
+            record: format!(
+                "{}: {:.2}%, {}: {matches}",
+                tr(Text::WinRate),
+                util::ratio(wins, matches) * 100.0,
+                tr(Text::Matches),
+            ),
+            sections: sections(ctx, overview),
+        })
+    }
+}
+
+fn sections(ctx: &AppContext, overview: &Overview) -> Vec<(Text, Vec<String>)> {
+    let item_name = |id: &i64| {
+        ctx.api
+            .items
+            .get(&id.to_string())
+            .map_or_else(|| id.to_string(), |i| i.name.clone())
+    };
+    let [first, second, third, fourth, fifth] = items::columns(
+        overview,
+        &ctx.api.items,
+        ctx.build_sort,
+        &ctx.api.stat_settings,
+    )
+    .map(|column| {
+        column
+            .iter()
+            .take(OPTIONS_SHOWN)
+            .map(item_name)
+            .collect::<Vec<_>>()
+    });
+    let abilities = |a: &Abilities| {
+        [
+            a.ability_max_order
+                .chars()
+                .map(|c| c.to_string())
+                .collect::<Vec<_>>()
+                .join(" > "),
+            a.ability_order.iter().collect::<String>(),
+        ]
+    };
+
+    match overview {
+        Overview::Default(d) => {
+            let runes = util::group_runes(&d.runes.rune_ids, &ctx.api.runes)
+                .into_iter()
+                .flat_map(|(path, runes)| {
+                    std::iter::once(path).chain(runes.into_iter().map(|r| r.rune.name.clone()))
+                })
+                .collect();
+            let shards_and_spells = d
+                .shards
+                .shard_ids
+                .iter()
+                .map(|id| shards::shard_text(*id).to_string())
+                .chain(
+                    d.summoner_spells
+                        .spell_ids
+                        .iter()
+                        .filter_map(|id| ctx.api.summoner_spells.get(id).cloned()),
+                )
+                .collect();
+            vec![
+                (Text::RunePaths, runes),
+                (Text::ShardsAndSpells, shards_and_spells),
+                (Text::StartingItems, first),
+                (Text::CoreItems, second),
+                (Text::FourthItems, third),
+                (Text::FifthItems, fourth),
+                (Text::SixthItems, fifth),
+                (Text::AbilityOrder, abilities(&d.abilities).into()),
+            ]
+        }
+        Overview::Arena(a) => {
+            let mut augments = a.augments.clone();
+            util::sort_by_stat(&mut augments, ctx.build_sort, &ctx.api.stat_settings, |a| {
+                (a.wins, a.matches)
+            });
+            let augments = augments
+                .iter()
+                .filter_map(|a| ctx.api.arena_augments.get(&a.id).map(|g| g.name.clone()))
+                .take(OPTIONS_SHOWN * 2)
+                .collect();
+            vec![
+                (Text::Augments, augments),
+                (Text::CoreItems, first),
+                (Text::FourthItems, second),
+                (Text::FifthItems, third),
+                (Text::SixthItems, fourth),
+                (Text::PrismaticItems, fifth),
+                (Text::AbilityOrder, abilities(&a.abilities).into()),
+            ]
+        }
+    }
+}
+
+/// Renders one side, highlighting anything the other side doesn't have.
+fn make_side<'a>(
+    label: Text,
+    side: &CompareSide,
+    other: &CompareSide,
+    hint: Option<String>,
+) -> Paragraph<'a> {
+    let mut lines = vec![
+        Line::from(side.title.clone()).fg(theme().accent).bold(),
+        Line::from(side.record.clone()).fg(theme().muted),
+    ];
+    for (title, values) in &side.sections {
+        let other_values = other
+            .sections
+            .iter()
+            .find(|(t, _)| t == title)
+            .map(|(_, v)| v.as_slice())
+            .unwrap_or_default();
+        let mut spans = Vec::new();
+        for (i, value) in values.iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::raw(", "));
+            }
+            let style = if other_values.contains(value) {
+                Style::default().fg(theme().text)
+            } else {
+                Style::default()
+                    .fg(theme().highlight)
+                    .add_modifier(Modifier::BOLD)
+            };
+            spans.push(Span::styled(value.clone(), style));
+        }
+        lines.push(Line::default());
+        lines.push(Line::from(tr(*title)).fg(theme().muted).bold());
+        lines.push(Line::from(spans));
+    }
+
+    let mut block = Block::default()
+        .title(format!(" {} ", tr(label)))
+        .title_style(Style::default().bold())
+        .borders(Borders::ALL);
+    if let Some(hint) = hint {
+        block = block.title_bottom(Line::from(format!(" {hint} ")).alignment(Alignment::Right));
+    }
+
+    Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .block(block)
+}
+
+/// The pinned build and the current one, side by side.
+pub fn make<'a>(ctx: &AppContext) -> Option<[Paragraph<'a>; 2]> {
+    let pinned = ctx.compare_pin.as_ref()?;
+    let current = CompareSide::capture(ctx)?;
+    Some([
+        make_side(Text::Pinned, pinned, &current, None),
+        make_side(
+            Text::Current,
+            &current,
+            pinned,
+            Some(ctx.keymap.hint(tr(Text::CompareHint), false)),
+        ),
+    ])
+}
+
+impl AppContext<'_> {
+    /// Remembers the build on screen so it can be compared with another
+    /// champion, build, region or patch.
+    pub fn pin_comparison(&mut self) {
+        if let Some(side) = CompareSide::capture(self) {
+            self.compare_pin = Some(side);
+        }
+    }
+
+    pub fn open_comparison(&mut self) {
+        if self.compare_pin.is_some() && self.selected_champ_overview.is_some() {
+            self.state = State::Compare;
+        }
+    }
+}
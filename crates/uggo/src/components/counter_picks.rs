@@ -0,0 +1,381 @@
+use std::collections::HashMap;
+use std::sync::mpsc::{self, TryRecvError};
+use std::thread;
+
+use ddragon::models::champions::ChampionShort;
+use ratatui::{
+    layout::{Alignment, Constraint},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Cell, Row, Table, TableState},
+};
+use ugg_types::{
+    mappings::{Mode, Role},
+    matchups::{Matchup, MatchupData},
+    stats::{Stat, StatSettings},
+};
+
+use uggo_ugg_api::FetchedMatchups;
+
+use crate::components::status_bar;
+use crate::context::{AppContext, State};
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
+use crate::util;
+
+/// A champion that could be picked into the enemy, with its record against
+/// them.
+#[derive(Debug, Clone)]
+pub struct CounterPick {
+    pub champion: ChampionShort,
+    pub matchup: Matchup,
+}
+
+/// Every candidate with data in `role` and a record against `enemy_key`, best
+/// smoothed win rate first.
+fn rank(
+    candidates: Vec<&ChampionShort>,
+    matchups: &HashMap<String, (MatchupData, Role)>,
+    enemy_key: i64,
+    role: Role,
+    settings: &StatSettings,
+) -> Vec<CounterPick> {
+    let mut picks = candidates
+        .into_iter()
+        .filter_map(|champion| {
+            // A champion is only a counter pick if it has data in the role.
+            let (data, _) = matchups
+                .get(&champion.key)
+                .filter(|(_, found)| *found == role)?;
+            let matchup = data
+                .all_matchups
+                .iter()
+                .find(|m| m.champion_id == enemy_key)?;
+            Some(CounterPick {
+                champion: champion.clone(),
+                matchup: matchup.clone(),
+            })
+        })
+        .collect::<Vec<_>>();
+    // A handful of lucky games shouldn't outrank a proven pick.
+    picks.sort_by(|a, b| {
+        b.matchup
+            .smoothed_win_rate(settings)
+            .total_cmp(&a.matchup.smoothed_win_rate(settings))
+    });
+    picks
+}
+
+fn right_cell<'a>(text: String) -> Cell<'a> {
+    Cell::from(Line::from(text).alignment(Alignment::Right))
+}
+
+pub fn make<'a>(ctx: &AppContext) -> (Table<'a>, TableState) {
+    let header = Row::new([
+        Cell::from(tr(Text::Champion)),
+        right_cell(format!("{}▼", tr(Text::WinRate))),
+        right_cell(tr(Text::Matches).to_string()),
+    ])
+    .style(Style::default().fg(theme().muted).bold());
+
+    let rows = ctx.counter_picks.iter().map(|pick| {
+        let win_rate = pick.matchup.win_rate();
+        Row::new(vec![
+            Cell::from(pick.champion.name.clone()),
+            right_cell(format!("{:.2}%", win_rate * 100.0))
+                .style(Style::default().fg(util::win_rate_color(win_rate))),
+            right_cell(pick.matchup.matches.to_string()),
+        ])
+    });
+
+    let enemy = ctx.selected_champ.as_ref().map_or("", |c| c.name.as_str());
+    let candidates = ctx
+        .counter_pick_pool
+        .and_then(|p| ctx.pools.get(p))
+        .map_or(tr(Text::AllChampions), |p| p.name.as_str());
+    let mut block = Block::default()
+        .title(format!(
+            " {}: {enemy}, {}, {} ({candidates}) ",
+            tr(Text::CounterPicks),
+            ctx.counter_pick_role(),
+            ctx.region,
+        ))
+        .title_style(Style::default().bold())
+        .title_bottom(
+            Line::from(format!(
+                " {} ",
+                ctx.keymap.hint(tr(Text::CounterPicksHint), false)
+            ))
+            .right_aligned(),
+        )
+        .borders(Borders::ALL);
+    if ctx.counter_picks_loading.is_some() {
+        block = block.title_bottom(
+            Line::from(format!(" {} ", tr(Text::Loading)))
+                .style(Style::default().fg(theme().muted)),
+        );
+    }
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(14),
+            Constraint::Length(16),
+            Constraint::Length(9),
+        ],
+    )
+    .header(header)
+    .column_spacing(1)
+    .style(Style::default().fg(theme().text))
+    .row_highlight_style(
+        Style::default()
+            .fg(theme().accent)
+            .add_modifier(Modifier::ITALIC),
+    )
+    .highlight_symbol("> ")
+    .block(block);
+
+    (
+        table,
+        TableState::default().with_selected(ctx.counter_picks_scroll_pos),
+    )
+}
+
+impl AppContext<'_> {
+    /// The role the selected champion's build was found in, which is the
+    /// lane the counter pick has to play.
+    pub fn counter_pick_role(&self) -> Role {
+        self.selected_champ_role.unwrap_or(self.role)
+    }
+
+    /// The pool for the counter pick role, or else the active one.
+    fn counter_pick_default_pool(&self) -> Option<usize> {
+        let role = self.counter_pick_role();
+        self.pools
+            .iter()
+            .position(|p| p.role == Some(role))
+            .or_else(|| self.active_pool())
+    }
+
+    pub fn open_counter_picks(&mut self) {
+        if self.selected_champ.is_none() || self.mode == Mode::ARAM || self.mode == Mode::Arena {
+            return;
+        }
+        self.counter_pick_pool = self.counter_pick_default_pool();
+        self.load_counter_picks();
+        self.state = State::CounterPicks;
+    }
+
+    /// The chosen pool, or every champion, except for `enemy`.
+    fn counter_pick_candidates(&self, enemy: &ChampionShort) -> Vec<&ChampionShort> {
+        let candidates = match self.counter_pick_pool.and_then(|p| self.pools.get(p)) {
+            Some(pool) => pool.champions.iter().collect::<Vec<_>>(),
+            None => self.champ_data.iter().map(|(_, c)| c).collect(),
+        };
+        candidates
+            .into_iter()
+            .filter(|c| c.id != enemy.id)
+            .collect()
+    }
+
+    /// Starts ranking the chosen pool, or every champion, by win rate against
+    /// the selected champion. That's a request per champion, so it's done in
+    /// the background unless they're all cached.
+    fn load_counter_picks(&mut self) {
+        let Some(enemy) = self.selected_champ.clone() else {
+            return;
+        };
+        self.counter_picks.clear();
+        self.counter_picks_scroll_pos = None;
+
+        let request = self.api.all_matchups_request(
+            &self.counter_pick_candidates(&enemy),
+            self.counter_pick_role(),
+            self.region,
+            self.mode,
+        );
+        if request.is_cached() {
+            self.counter_picks_loading = None;
+            self.store_counter_picks(&enemy, request.fetch());
+            return;
+        }
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            // The receiver is gone if something else was asked for meanwhile.
+            let _ = sender.send(request.fetch());
+        });
+        self.counter_picks_loading = Some((enemy.id, receiver));
+    }
+
+    /// Picks up counter picks that finished loading in the background.
+    pub fn poll_counter_picks(&mut self) {
+        let Some((enemy_id, receiver)) = &self.counter_picks_loading else {
+            return;
+        };
+        match receiver.try_recv() {
+            Ok(fetched) => {
+                let enemy = self.selected_champ.clone().filter(|c| c.id == *enemy_id);
+                self.counter_picks_loading = None;
+                if let Some(enemy) = enemy {
+                    self.store_counter_picks(&enemy, fetched);
+                }
+            }
+            Err(TryRecvError::Disconnected) => self.counter_picks_loading = None,
+            Err(TryRecvError::Empty) => {}
+        }
+    }
+
+    fn store_counter_picks(&mut self, enemy: &ChampionShort, fetched: FetchedMatchups) {
+        match self.api.store_all_matchups(fetched) {
+            Ok(matchups) => {
+                self.counter_picks = rank(
+                    self.counter_pick_candidates(enemy),
+                    &matchups,
+                    enemy.key.parse::<i64>().unwrap_or_default(),
+                    self.counter_pick_role(),
+                    &self.api.stat_settings,
+                );
+            }
+            Err(e) => {
+                log::error!("Failed to load counter picks for {}: {e:?}", enemy.name);
+                self.last_error = Some(status_bar::describe_error(
+                    &e,
+                    &enemy.name,
+                    self.mode,
+                    &self.version,
+                ));
+            }
+        }
+        self.counter_picks_scroll_pos = (!self.counter_picks.is_empty()).then_some(0);
+    }
+
+    /// Switches between the pool and every champion.
+    pub fn toggle_counter_pick_candidates(&mut self) {
+        self.counter_pick_pool = match self.counter_pick_pool {
+            Some(_) => None,
+            None => self.counter_pick_default_pool(),
+        };
+        self.load_counter_picks();
+    }
+
+    pub fn next_counter_pick(&mut self) {
+        if let Some(pos) = self.counter_picks_scroll_pos
+            && pos + 1 < self.counter_picks.len()
+        {
+            self.counter_picks_scroll_pos = Some(pos + 1);
+        }
+    }
+
+    pub fn prev_counter_pick(&mut self) {
+        if let Some(pos) = self.counter_picks_scroll_pos
+            && pos > 0
+        {
+            self.counter_picks_scroll_pos = Some(pos - 1);
+        }
+    }
+
+    /// Opens the build for the highlighted pick, in the role it counters.
+    pub fn select_counter_pick(&mut self) {
+        if let Some(pick) = self
+            .counter_picks_scroll_pos
+            .and_then(|p| self.counter_picks.get(p))
+            .cloned()
+        {
+            self.role = self.counter_pick_role();
+            self.match_pos_to_role();
+            self.select_champion(&pick.champion);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENEMY: i64 = 99;
+
+    fn data(records: &[(i64, i32, i32)]) -> MatchupData {
+        MatchupData::new(
+            records
+                .iter()
+                .map(|&(champion_id, wins, matches)| Matchup {
+                    champion_id,
+                    wins,
+                    matches,
+                    winrate: f64::from(wins) / f64::from(matches),
+                })
+                .collect(),
+            records.iter().map(|(_, _, m)| m).sum(),
+        )
+    }
+
+    fn names(picks: &[CounterPick]) -> Vec<&str> {
+        picks.iter().map(|p| p.champion.name.as_str()).collect()
+    }
+
+    #[test]
+    fn ranks_by_smoothed_win_rate() {
+        let champions = [
+            util::test_champion("Ahri", "1", "Ahri"),
+            util::test_champion("Syndra", "2", "Syndra"),
+            util::test_champion("Zed", "3", "Zed"),
+            util::test_champion("Lux", "4", "Lux"),
+            util::test_champion("Annie", "5", "Annie"),
+        ];
+        let matchups = HashMap::from([
+            ("1".to_string(), (data(&[(ENEMY, 540, 1000)]), Role::Mid)),
+            // Undefeated, but in too few games to beat a proven pick.
+            (
+                "2".to_string(),
+                (data(&[(ENEMY, 3, 3), (7, 1, 2)]), Role::Mid),
+            ),
+            ("3".to_string(), (data(&[(ENEMY, 450, 1000)]), Role::Mid)),
+            // Only has data in another role.
+            (
+                "4".to_string(),
+                (data(&[(ENEMY, 900, 1000)]), Role::Support),
+            ),
+            // Has never played against the enemy.
+            ("5".to_string(), (data(&[(7, 600, 1000)]), Role::Mid)),
+        ]);
+
+        let picks = rank(
+            champions.iter().collect(),
+            &matchups,
+            ENEMY,
+            Role::Mid,
+            &StatSettings::DEFAULT,
+        );
+        assert_eq!(names(&picks), ["Ahri", "Syndra", "Zed"]);
+        assert_eq!(picks[0].matchup.matches, 1000);
+
+        // Without smoothing, the raw win rate decides.
+        let raw = StatSettings {
+            prior_matches: 0.0,
+            ..StatSettings::DEFAULT
+        };
+        let picks = rank(
+            champions.iter().collect(),
+            &matchups,
+            ENEMY,
+            Role::Mid,
+            &raw,
+        );
+        assert_eq!(names(&picks), ["Syndra", "Ahri", "Zed"]);
+    }
+
+    #[test]
+    fn ranks_nothing_without_data() {
+        let champion = util::test_champion("Ahri", "1", "Ahri");
+        assert!(
+            rank(
+                vec![&champion],
+                &HashMap::new(),
+                ENEMY,
+                Role::Mid,
+                &StatSettings::DEFAULT
+            )
+            .is_empty()
+        );
+    }
+}
@@ -5,6 +5,10 @@ use ratatui::{
     widgets::{Block, Borders, Cell, Row, Table, Widget},
 };
 
+use crate::context::{AppContext, State};
+use crate::i18n::{Text, tr};
+use crate::keymap::{Action, Keymap};
+
 macro_rules! left_cell {
     ($text:expr) => {
         Cell::from(Line::from($text).alignment(Alignment::Left))
@@ -16,90 +20,94 @@ macro_rules! right_cell {
         Cell::from(Line::from($text).alignment(Alignment::Right))
     };
 }
-#[cfg(not(target_os = "macos"))]
-macro_rules! alt_keypress {
-    ($key:literal) => {
-        concat!("Alt+", $key)
-    };
-}
 
-#[cfg(target_os = "macos")]
-macro_rules! alt_keypress {
-    ($key:literal) => {
-        concat!("Opt+", $key)
-    };
+/// One row per action, showing whatever the active keymap binds it to.
+fn cells(keymap: &Keymap) -> Vec<[String; 2]> {
+    let mut cells = Action::ALL
+        .iter()
+        .map(|action| [tr(action.text()).to_string(), keymap.describe(*action)])
+        .collect::<Vec<_>>();
+    // Keys that aren't configurable go right after the action they belong to.
+    let fixed = [
+        (Action::Pools, Text::PickFromPool, "1-9"),
+        (Action::LogViewer, Text::ExitLogViewer, "q"),
+    ];
+    for (after, text, key) in fixed.into_iter().rev() {
+        let index = Action::ALL
+            .iter()
+            .position(|a| *a == after)
+            .map_or(cells.len(), |i| i + 1);
+        cells.insert(index, [tr(text).to_string(), key.to_string()]);
+    }
+    cells
 }
 
-const CELLS: [[&str; 2]; 14] = [
-    ["Search", alt_keypress!("s")],
-    ["Champ Select", alt_keypress!("c")],
-    ["Mode Select", alt_keypress!("m")],
-    ["Role Select", alt_keypress!("r")],
-    ["Version Select", alt_keypress!("v")],
-    ["Region Select", alt_keypress!("w")],
-    ["Build Select", alt_keypress!("b")],
-    ["Hide Left Pane", alt_keypress!("h")],
-    ["Log Viewer", alt_keypress!("l")],
-    ["Exit Log Viewer", "Q"],
-    ["Back", "Esc"],
-    ["Send", "Enter"],
-    ["Quit", "Ctrl+Q"],
-    ["Help", "?"],
-];
-
 #[allow(clippy::cast_possible_truncation)]
-const fn left_size() -> u16 {
-    let mut max = 0u16;
-    let mut idx = 0usize;
-
-    while idx < CELLS.len() {
-        let len = CELLS[idx][0].len() as u16;
-        if len > max {
-            max = len;
-        }
-        idx += 1;
-    }
-
-    max + 1
+fn column_size(cells: &[[String; 2]], column: usize) -> u16 {
+    cells
+        .iter()
+        .map(|row| Line::from(row[column].as_str()).width())
+        .max()
+        .unwrap_or_default() as u16
 }
 
+/// The help menu, scrolled so that it fits in `max_height` rows.
 #[allow(clippy::cast_possible_truncation)]
-const fn right_size() -> u16 {
-    let mut max = 0u16;
-    let mut idx = 0usize;
+pub fn make(ctx: &AppContext, max_height: u16) -> (impl Widget, Rect) {
+    let cells = cells(&ctx.keymap);
+    let left_size = column_size(&cells, 0) + 1;
+    let right_size = column_size(&cells, 1);
+    // The menu is drawn inside a margin and a border on every side.
+    let visible = usize::from(max_height.saturating_sub(4)).min(cells.len());
+    let offset = ctx.help_scroll.min(cells.len() - visible);
 
-    while idx < CELLS.len() {
-        let len = CELLS[idx][1].len() as u16;
-        if len > max {
-            max = len;
-        }
-        idx += 1;
+    let mut block = Block::default()
+        .title(format!(" {} ", tr(Text::Help)))
+        .title_style(Style::default().bold())
+        .borders(Borders::ALL);
+    if visible < cells.len() {
+        block = block.title_bottom(
+            Line::from(format!(
+                " ↑↓ {}-{}/{} ",
+                offset + 1,
+                offset + visible,
+                cells.len()
+            ))
+            .right_aligned(),
+        );
     }
 
-    max
-}
-
-const CONSTRAINTS: [Constraint; 2] = [
-    Constraint::Length(left_size()),
-    Constraint::Length(right_size()),
-];
-
-#[allow(clippy::cast_possible_truncation)]
-pub fn make() -> (impl Widget, Rect) {
     (
         Table::new(
-            CELLS
-                .iter()
-                .map(|row| Row::new(vec![left_cell!(row[0]), right_cell!(row[1])])),
-            CONSTRAINTS,
+            cells.iter().skip(offset).take(visible).map(|row| {
+                Row::new(vec![
+                    left_cell!(row[0].clone()),
+                    right_cell!(row[1].clone()),
+                ])
+            }),
+            [
+                Constraint::Length(left_size),
+                Constraint::Length(right_size),
+            ],
         )
         .column_spacing(2)
-        .block(
-            Block::default()
-                .title(" Help ")
-                .title_style(Style::default().bold())
-                .borders(Borders::ALL),
-        ),
-        Rect::new(0, 0, left_size() + right_size() + 3, CELLS.len() as u16 + 1),
+        .block(block),
+        Rect::new(0, 0, left_size + right_size + 3, cells.len() as u16 + 1),
     )
 }
+
+impl AppContext<'_> {
+    pub fn show_help(&mut self) {
+        self.help_scroll = 0;
+        self.state = State::HelpMenu;
+    }
+
+    pub fn scroll_help_down(&mut self) {
+        let rows = cells(&self.keymap).len();
+        self.help_scroll = (self.help_scroll + 1).min(rows.saturating_sub(1));
+    }
+
+    pub fn scroll_help_up(&mut self) {
+        self.help_scroll = self.help_scroll.saturating_sub(1);
+    }
+}
@@ -0,0 +1,172 @@
+use ratatui::{
+    layout::Rect,
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Widget, Wrap},
+};
+use uggo_ugg_api::ItemTree;
+
+use crate::context::AppContext;
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
+use crate::util;
+
+/// Turns a ddragon stat key like `FlatPhysicalDamageMod` into a readable
+/// line like `+40 Physical Damage`.
+fn format_stat(key: &str, value: f64) -> String {
+    let percent = key.starts_with("Percent");
+    let trimmed = key
+        .trim_start_matches("Flat")
+        .trim_start_matches("Percent")
+        .trim_end_matches("Mod");
+
+    let mut label = String::new();
+    for c in trimmed.chars() {
+        if c.is_uppercase() && !label.is_empty() {
+            label.push(' ');
+        }
+        label.push(c);
+    }
+
+    let value = if percent { value * 100.0 } else { value };
+    let number = format_number(value.abs());
+    let sign = if number == "0" {
+        ""
+    } else if value < 0.0 {
+        "-"
+    } else {
+        "+"
+    };
+    let unit = if percent { "%" } else { "" };
+    format!("{sign}{number}{unit} {label}")
+}
+
+/// Formats a non-negative number with at most two decimals and no trailing
+/// zeros, so float noise like `7.000000000000001` shows as `7`.
+fn format_number(value: f64) -> String {
+    let formatted = format!("{value:.2}");
+    match formatted.split_once('.') {
+        Some((whole, decimals)) => match decimals.trim_end_matches('0') {
+            "" => whole.to_string(),
+            decimals => format!("{whole}.{decimals}"),
+        },
+        None => formatted,
+    }
+}
+
+fn push_tree_lines(lines: &mut Vec<Line<'_>>, tree: &ItemTree, depth: usize) {
+    for (index, component) in tree.components.iter().enumerate() {
+        let branch = if index + 1 == tree.components.len() {
+            "└ "
+        } else {
+            "├ "
+        };
+        lines.push(Line::from(vec![
+            Span::raw(format!("{}{branch}", "  ".repeat(depth))),
+            Span::raw(component.item.name.clone()),
+            Span::styled(
+                format!(" ({}g)", component.item.total_gold),
+                Style::default().fg(theme().highlight),
+            ),
+        ]));
+        push_tree_lines(lines, component, depth + 1);
+    }
+}
+
+pub fn make<'a>(ctx: &AppContext) -> Option<(impl Widget + 'a, Rect)> {
+    let tree = ctx
+        .highlighted_item()
+        .and_then(|id| ctx.api.item_tree(id))?;
+    let item = &tree.item;
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(
+                format!("{}: ", tr(Text::Cost)),
+                Style::default().fg(theme().muted),
+            ),
+            Span::styled(
+                format!("{}g", item.total_gold),
+                Style::default().fg(theme().highlight).bold(),
+            ),
+            Span::styled(
+                format!(
+                    " (+{}g, {} {}g)",
+                    item.cost,
+                    tr(Text::SellsFor),
+                    item.sell_gold
+                ),
+                Style::default().fg(theme().muted),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                format!("{}: ", tr(Text::Tags)),
+                Style::default().fg(theme().muted),
+            ),
+            Span::raw(item.tags.join(", ")),
+        ]),
+    ];
+
+    let mut stats = item.stats.iter().collect::<Vec<_>>();
+    stats.sort_by(|a, b| a.0.cmp(b.0));
+    lines.extend(
+        stats
+            .into_iter()
+            .map(|(key, value)| Line::from(format_stat(key, *value)).fg(theme().cyan)),
+    );
+
+    if !tree.components.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(format!("{}:", tr(Text::BuildsFrom))).bold());
+        push_tree_lines(&mut lines, &tree, 0);
+    }
+
+    lines.push(Line::from(""));
+    lines.extend(
+        util::strip_html(&item.description)
+            .lines()
+            .map(|l| Line::from(l.to_owned())),
+    );
+
+    Some((
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .style(Style::default().fg(theme().text))
+            .block(
+                Block::default()
+                    .title(format!(" {} ", item.name))
+                    .title_style(Style::default().bold())
+                    .borders(Borders::ALL),
+            ),
+        Rect::new(0, 0, 60, 20),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_stats_without_float_noise() {
+        assert_eq!(
+            format_stat("PercentMovementSpeedMod", 0.07),
+            "+7% Movement Speed"
+        );
+        assert_eq!(
+            format_stat("PercentAttackSpeedMod", 0.125),
+            "+12.5% Attack Speed"
+        );
+        assert_eq!(
+            format_stat("FlatPhysicalDamageMod", 40.0),
+            "+40 Physical Damage"
+        );
+        assert_eq!(format_stat("FlatArmorMod", 0.0), "0 Armor");
+        assert_eq!(format_stat("FlatArmorMod", -0.001), "0 Armor");
+        assert_eq!(format_stat("FlatArmorMod", -5.0), "-5 Armor");
+        assert_eq!(
+            format_stat("PercentMovementSpeedMod", -0.035),
+            "-3.5% Movement Speed"
+        );
+    }
+}
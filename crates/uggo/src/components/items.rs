@@ -2,98 +2,373 @@ use std::collections::HashMap;
 
 use ddragon::models::items::Item;
 use ratatui::{
-    style::{Style, Stylize},
-    widgets::{Block, Borders, List, ListItem, Widget},
+    layout::{Alignment, Constraint},
+    style::{Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Row, Table, Widget},
 };
 use ugg_types::{
-    arena_overview::{ArenaOverviewData, PrismaticItem},
+    arena_overview::ArenaOverviewData,
     default_overview::{LateItem, OverviewData},
+    overview::Overview,
+    stats::StatSettings,
 };
 
+use crate::context::{AppContext, BuildSort, State};
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
+use crate::util;
+
 fn make_item_block<'a>(title: &str) -> Block<'a> {
     Block::default()
-        .white()
+        .fg(theme().text)
         .title(format!(" {title} "))
         .title_style(Style::default().bold())
         .borders(Borders::ALL)
 }
 
 pub fn make_placeholder(title: Option<&str>) -> impl Widget {
-    make_item_block(title.unwrap_or("Items"))
+    make_item_block(title.unwrap_or(tr(Text::Items)))
+}
+
+/// An item shown in the items panel, with its own `(wins, matches)` when
+/// the data has per-item stats.
+struct ItemEntry {
+    id: i64,
+    stats: Option<(i64, i64)>,
+}
+
+fn known_ids(ids: &[i64], items: &HashMap<String, Item>) -> Vec<ItemEntry> {
+    ids.iter()
+        .filter(|i| items.contains_key(&i.to_string()))
+        .map(|id| ItemEntry {
+            id: *id,
+            stats: None,
+        })
+        .collect()
 }
 
-fn make_list_from_lateitems<'a>(
-    name: &str,
+fn known_with_stats(
+    entries: impl Iterator<Item = (i64, i64, i64)>,
+    items: &HashMap<String, Item>,
+    sort: BuildSort,
+    settings: &StatSettings,
+) -> Vec<ItemEntry> {
+    let mut known = entries
+        .filter(|(id, _, _)| items.contains_key(&id.to_string()))
+        .map(|(id, wins, matches)| ItemEntry {
+            id,
+            stats: Some((wins, matches)),
+        })
+        .collect::<Vec<_>>();
+    util::sort_by_stat(&mut known, sort, settings, |e| e.stats.unwrap_or_default());
+    known
+}
+
+fn late_items(
     late_items: &[LateItem],
     items: &HashMap<String, Item>,
-) -> List<'a> {
-    List::new(
-        late_items
-            .iter()
-            .filter_map(|i| {
-                items
-                    .get(&i.id.to_string())
-                    .map(|it| ListItem::new(it.name.clone()))
-            })
-            .collect::<Vec<_>>(),
+    sort: BuildSort,
+    settings: &StatSettings,
+) -> Vec<ItemEntry> {
+    known_with_stats(
+        late_items.iter().map(|i| (i.id, i.wins, i.matches)),
+        items,
+        sort,
+        settings,
     )
-    .block(make_item_block(name))
 }
 
-fn make_list_from_item_ids<'a>(
-    name: &str,
-    item_ids: &[i64],
+fn default_entries(
+    overview: &OverviewData,
     items: &HashMap<String, Item>,
-) -> List<'a> {
-    List::new(
-        item_ids
-            .iter()
-            .filter_map(|i| {
-                items
-                    .get(&i.to_string())
-                    .map(|it| ListItem::new(it.name.clone()))
-            })
-            .collect::<Vec<_>>(),
-    )
-    .block(make_item_block(name))
+    sort: BuildSort,
+    settings: &StatSettings,
+) -> [Vec<ItemEntry>; 5] {
+    [
+        known_ids(&overview.starting_items.item_ids, items),
+        known_ids(&overview.core_items.item_ids, items),
+        late_items(&overview.item_4_options, items, sort, settings),
+        late_items(&overview.item_5_options, items, sort, settings),
+        late_items(&overview.item_6_options, items, sort, settings),
+    ]
 }
 
-fn make_list_from_prismatic_items<'a>(
-    name: &str,
-    late_items: &[PrismaticItem],
+fn arena_entries(
+    overview: &ArenaOverviewData,
     items: &HashMap<String, Item>,
-) -> List<'a> {
-    List::new(
-        late_items
-            .iter()
-            .filter_map(|i| {
-                items
-                    .get(&i.id.to_string())
-                    .map(|it| ListItem::new(it.name.clone()))
-            })
-            .collect::<Vec<_>>(),
-    )
-    .block(make_item_block(name))
+    sort: BuildSort,
+    settings: &StatSettings,
+) -> [Vec<ItemEntry>; 5] {
+    [
+        known_ids(&overview.core_items.item_ids, items),
+        late_items(&overview.item_4_options, items, sort, settings),
+        late_items(&overview.item_5_options, items, sort, settings),
+        late_items(&overview.item_6_options, items, sort, settings),
+        known_with_stats(
+            overview
+                .prismatic_items
+                .iter()
+                .map(|i| (i.id, i.wins, i.matches)),
+            items,
+            sort,
+            settings,
+        ),
+    ]
+}
+
+/// Returns the ids shown in each item column, skipping anything ddragon
+/// doesn't know about so that positions line up with the rendered rows.
+pub fn columns(
+    overview: &Overview,
+    items: &HashMap<String, Item>,
+    sort: BuildSort,
+    settings: &StatSettings,
+) -> [Vec<i64>; 5] {
+    let entries = match overview {
+        Overview::Default(d) => default_entries(d, items, sort, settings),
+        Overview::Arena(a) => arena_entries(a, items, sort, settings),
+    };
+    entries.map(|column| column.iter().map(|e| e.id).collect())
+}
+
+struct ItemTable<'a> {
+    name: &'a str,
+    entries: &'a [ItemEntry],
+    highlight: Option<usize>,
+    /// `(wins, matches)` of the whole set, for sets like core items.
+    set_stats: Option<(i64, i64)>,
+    show_total: bool,
+}
+
+fn make_item_table<'a>(
+    table: &ItemTable,
+    items: &HashMap<String, Item>,
+    total_matches: i64,
+) -> Table<'a> {
+    let mut total = 0;
+    let rows = table
+        .entries
+        .iter()
+        .filter_map(|e| items.get(&e.id.to_string()).map(|it| (e, it)))
+        .enumerate()
+        .map(|(index, (entry, it))| {
+            total += it.gold.total;
+            let mut name = vec![Line::from(it.name.clone())];
+            if let Some((wins, matches)) = entry.stats {
+                name.push(Line::from(util::stat_spans(wins, matches, total_matches)));
+            }
+            let height = u16::try_from(name.len()).unwrap_or(1);
+            let row = Row::new(vec![
+                Cell::from(name),
+                Cell::from(Line::from(it.gold.total.to_string()).alignment(Alignment::Right)),
+            ])
+            .height(height);
+            if table.highlight == Some(index) {
+                row.style(
+                    Style::default()
+                        .fg(theme().accent)
+                        .add_modifier(Modifier::ITALIC),
+                )
+            } else {
+                row
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut block = make_item_block(table.name);
+    if let Some((wins, matches)) = table.set_stats
+        && matches > 0
+    {
+        block = block.title_bottom(Line::from(
+            [
+                vec![Span::raw(" ")],
+                util::stat_spans(wins, matches, total_matches),
+                vec![Span::raw(" ")],
+            ]
+            .concat(),
+        ));
+    }
+    if table.show_total && total > 0 {
+        block = block.title_bottom(
+            Line::from(format!(" {}: {total}g ", tr(Text::Total)))
+                .right_aligned()
+                .fg(theme().highlight),
+        );
+    }
+
+    Table::new(rows, [Constraint::Min(0), Constraint::Length(4)])
+        .column_spacing(1)
+        .block(block)
+}
+
+fn highlight_for(selected: Option<(usize, usize)>, column: usize) -> Option<usize> {
+    selected.filter(|(c, _)| *c == column).map(|(_, r)| r)
 }
 
-pub fn make_default(overview: &OverviewData, items: &HashMap<String, Item>) -> [impl Widget; 5] {
+pub fn make_default(
+    overview: &OverviewData,
+    items: &HashMap<String, Item>,
+    selected: Option<(usize, usize)>,
+    sort: BuildSort,
+    settings: &StatSettings,
+) -> [impl Widget; 5] {
+    let [starting, core, fourth, fifth, sixth] = default_entries(overview, items, sort, settings);
+    let starting_stats = (
+        overview.starting_items.wins,
+        overview.starting_items.matches,
+    );
+    let core_stats = (overview.core_items.wins, overview.core_items.matches);
     [
-        make_list_from_item_ids("Starting Items", &overview.starting_items.item_ids, items),
-        make_list_from_item_ids("Core Items", &overview.core_items.item_ids, items),
-        make_list_from_lateitems("4th Items", &overview.item_4_options, items),
-        make_list_from_lateitems("5th Items", &overview.item_5_options, items),
-        make_list_from_lateitems("6th Items", &overview.item_6_options, items),
+        (
+            0,
+            tr(Text::StartingItems),
+            &starting,
+            Some(starting_stats),
+            false,
+        ),
+        (1, tr(Text::CoreItems), &core, Some(core_stats), true),
+        (2, tr(Text::FourthItems), &fourth, None, false),
+        (3, tr(Text::FifthItems), &fifth, None, false),
+        (4, tr(Text::SixthItems), &sixth, None, false),
     ]
+    .map(|(column, name, entries, set_stats, show_total)| {
+        make_item_table(
+            &ItemTable {
+                name,
+                entries,
+                highlight: highlight_for(selected, column),
+                set_stats,
+                show_total,
+            },
+            items,
+            overview.matches,
+        )
+    })
 }
 
-pub fn make_arena(overview: &ArenaOverviewData, items: &HashMap<String, Item>) -> [impl Widget; 5] {
+pub fn make_arena(
+    overview: &ArenaOverviewData,
+    items: &HashMap<String, Item>,
+    selected: Option<(usize, usize)>,
+    sort: BuildSort,
+    settings: &StatSettings,
+) -> [impl Widget; 5] {
+    let [core, fourth, fifth, sixth, prismatic] = arena_entries(overview, items, sort, settings);
+    let core_stats = (overview.core_items.wins, overview.core_items.matches);
     [
-        // make_list_from_item_ids("Starting Items", &overview.starting_items.item_ids, items),
-        make_list_from_item_ids("2nd/3rd Items", &overview.core_items.item_ids, items),
-        make_list_from_lateitems("4th Items", &overview.item_4_options, items),
-        make_list_from_lateitems("5th Items", &overview.item_5_options, items),
-        make_list_from_lateitems("6th Items", &overview.item_6_options, items),
-        // make_list_from_lateitems("Consumables", &overview.consumables, items),
-        make_list_from_prismatic_items("Prismatic Items", &overview.prismatic_items, items),
+        (0, tr(Text::SecondThirdItems), &core, Some(core_stats), true),
+        (1, tr(Text::FourthItems), &fourth, None, false),
+        (2, tr(Text::FifthItems), &fifth, None, false),
+        (3, tr(Text::SixthItems), &sixth, None, false),
+        (4, tr(Text::PrismaticItems), &prismatic, None, false),
     ]
+    .map(|(column, name, entries, set_stats, show_total)| {
+        make_item_table(
+            &ItemTable {
+                name,
+                entries,
+                highlight: highlight_for(selected, column),
+                set_stats,
+                show_total,
+            },
+            items,
+            overview.matches,
+        )
+    })
+}
+
+impl AppContext<'_> {
+    fn item_columns(&self) -> Option<[Vec<i64>; 5]> {
+        self.selected_champ_overview
+            .as_ref()
+            .map(|o| columns(o, &self.api.items, self.build_sort, &self.api.stat_settings))
+    }
+
+    /// The row of item `column` drawn `line` lines below the top of its table.
+    pub fn item_row_at(&self, column: usize, line: u16) -> Option<usize> {
+        let entries = match self.selected_champ_overview.as_ref()? {
+            Overview::Default(d) => {
+                default_entries(d, &self.api.items, self.build_sort, &self.api.stat_settings)
+            }
+            Overview::Arena(a) => {
+                arena_entries(a, &self.api.items, self.build_sort, &self.api.stat_settings)
+            }
+        };
+        let mut bottom = 0;
+        for (row, entry) in entries.get(column)?.iter().enumerate() {
+            // Entries with their own stats take a second line.
+            bottom += if entry.stats.is_some() { 2 } else { 1 };
+            if line < bottom {
+                return Some(row);
+            }
+        }
+        None
+    }
+
+    pub fn focus_items(&mut self) {
+        if let Some(columns) = self.item_columns()
+            && let Some(column) = columns.iter().position(|c| !c.is_empty())
+        {
+            self.state = State::ItemSelect;
+            self.item_scroll_pos = Some((column, 0));
+        }
+    }
+
+    pub fn next_item(&mut self) {
+        if let Some((column, row)) = self.item_scroll_pos
+            && let Some(columns) = self.item_columns()
+            && row + 1 < columns[column].len()
+        {
+            self.item_scroll_pos = Some((column, row + 1));
+        }
+    }
+
+    pub fn prev_item(&mut self) {
+        if let Some((column, row)) = self.item_scroll_pos
+            && row > 0
+        {
+            self.item_scroll_pos = Some((column, row - 1));
+        }
+    }
+
+    fn move_item_column(&mut self, forward: bool) {
+        if let Some((column, row)) = self.item_scroll_pos
+            && let Some(columns) = self.item_columns()
+        {
+            let next = if forward {
+                (column + 1..columns.len()).find(|c| !columns[*c].is_empty())
+            } else {
+                (0..column).rev().find(|c| !columns[*c].is_empty())
+            };
+            if let Some(next) = next {
+                self.item_scroll_pos = Some((next, row.min(columns[next].len() - 1)));
+            }
+        }
+    }
+
+    pub fn next_item_column(&mut self) {
+        self.move_item_column(true);
+    }
+
+    pub fn prev_item_column(&mut self) {
+        self.move_item_column(false);
+    }
+
+    /// The id of the item currently highlighted in the items panel.
+    pub fn highlighted_item(&self) -> Option<i64> {
+        let (column, row) = self.item_scroll_pos?;
+        self.item_columns()?.get(column)?.get(row).copied()
+    }
+
+    pub fn show_item_detail(&mut self) {
+        if self.highlighted_item().is_some() {
+            self.state = State::ItemDetail;
+        }
+    }
+
+    pub fn leave_items(&mut self) {
+        self.item_scroll_pos = None;
+        self.return_to_initial(false);
+    }
 }
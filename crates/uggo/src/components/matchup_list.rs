@@ -0,0 +1,386 @@
+use std::collections::HashMap;
+
+use ddragon::models::champions::ChampionShort;
+use ratatui::{
+    crossterm::event::{Event, KeyEvent},
+    layout::{Alignment, Constraint},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Widget},
+};
+use tui_input::backend::crossterm::EventHandler;
+use ugg_types::matchups::Matchup;
+
+use crate::context::{AppContext, State};
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
+use crate::util;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MatchupColumn {
+    Champion,
+    #[default]
+    WinRate,
+    Matches,
+    PickShare,
+    VsAverage,
+}
+
+impl MatchupColumn {
+    const ALL: [Self; 5] = [
+        Self::Champion,
+        Self::WinRate,
+        Self::Matches,
+        Self::PickShare,
+        Self::VsAverage,
+    ];
+
+    fn title(self) -> &'static str {
+        match self {
+            Self::Champion => tr(Text::Champion),
+            Self::WinRate => tr(Text::WinRate),
+            Self::Matches => tr(Text::Matches),
+            Self::PickShare => tr(Text::PickRate),
+            Self::VsAverage => tr(Text::VsAverage),
+        }
+    }
+
+    fn compare(self, a: &MatchupEntry, b: &MatchupEntry) -> std::cmp::Ordering {
+        let win_rate =
+            |e: &MatchupEntry| util::ratio(e.matchup.wins.into(), e.matchup.matches.into());
+        match self {
+            Self::Champion => a.champion.name.cmp(&b.champion.name),
+            // Every opponent shares the same average, so sorting by the
+            // difference is the same as sorting by win rate.
+            Self::WinRate | Self::VsAverage => win_rate(a).total_cmp(&win_rate(b)),
+            Self::Matches | Self::PickShare => a.matchup.matches.cmp(&b.matchup.matches),
+        }
+    }
+}
+
+/// An opponent in the matchup table.
+#[derive(Debug, Clone)]
+pub struct MatchupEntry {
+    pub champion: ChampionShort,
+    pub matchup: Matchup,
+}
+
+fn sort_entries(entries: &mut [MatchupEntry], column: MatchupColumn, descending: bool) {
+    entries.sort_by(|a, b| {
+        let ordering = column.compare(a, b);
+        if descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+}
+
+/// Entries whose champion's name or English name contains `query`, ignoring
+/// case.
+fn filter_entries<'a>(
+    entries: &'a [MatchupEntry],
+    query: &str,
+    english_names: &HashMap<String, String>,
+) -> Vec<&'a MatchupEntry> {
+    let query = query.to_lowercase();
+    entries
+        .iter()
+        .filter(|e| {
+            e.champion.name.to_lowercase().contains(&query)
+                || english_names
+                    .get(&e.champion.id)
+                    .is_some_and(|n| n.to_lowercase().contains(&query))
+        })
+        .collect()
+}
+
+fn right_cell<'a>(text: String) -> Cell<'a> {
+    Cell::from(Line::from(text).alignment(Alignment::Right))
+}
+
+pub fn make_filter<'a>(ctx: &'a AppContext) -> impl Widget + 'a {
+    Paragraph::new(ctx.matchup_filter.value())
+        .style(Style::default().fg(theme().accent))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} ", tr(Text::Search)))
+                .title_style(Style::default().fg(theme().text).bold()),
+        )
+}
+
+pub fn make<'a>(ctx: &AppContext) -> (Table<'a>, TableState) {
+    let header = Row::new(MatchupColumn::ALL.iter().map(|column| {
+        let title = if *column == ctx.matchup_list_sort {
+            let arrow = if ctx.matchup_list_descending {
+                "▼"
+            } else {
+                "▲"
+            };
+            format!("{}{arrow}", column.title())
+        } else {
+            column.title().to_string()
+        };
+        Cell::from(title)
+    }))
+    .style(Style::default().fg(theme().muted).bold());
+
+    let total_matches = ctx
+        .matchup_list
+        .iter()
+        .map(|e| i64::from(e.matchup.matches))
+        .sum();
+    let average = util::ratio(
+        ctx.matchup_list
+            .iter()
+            .map(|e| i64::from(e.matchup.wins))
+            .sum(),
+        total_matches,
+    );
+
+    let rows = ctx.visible_matchups().into_iter().map(|entry| {
+        let wins = i64::from(entry.matchup.wins);
+        let matches = i64::from(entry.matchup.matches);
+        let win_rate = util::ratio(wins, matches);
+        let delta = win_rate - average;
+        Row::new(vec![
+            Cell::from(entry.champion.name.clone()),
+            right_cell(format!("{:.2}%", win_rate * 100.0))
+                .style(Style::default().fg(util::win_rate_color(win_rate))),
+            right_cell(matches.to_string()),
+            right_cell(format!(
+                "{:.2}%",
+                util::ratio(matches, total_matches) * 100.0
+            )),
+            right_cell(format!("{:+.2}%", delta * 100.0))
+                .style(Style::default().fg(util::win_rate_color(0.5 + delta))),
+        ])
+    });
+
+    let title = ctx.selected_champ.as_ref().map_or_else(
+        || format!(" {} ", tr(Text::Matchups)),
+        |champ| format!(" {}: {}, {} ", tr(Text::Matchups), champ.name, ctx.region),
+    );
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(14),
+            Constraint::Length(9),
+            Constraint::Length(9),
+            Constraint::Length(9),
+            Constraint::Length(9),
+        ],
+    )
+    .header(header)
+    .column_spacing(1)
+    .style(Style::default().fg(theme().text))
+    .row_highlight_style(
+        Style::default()
+            .fg(theme().accent)
+            .add_modifier(Modifier::ITALIC),
+    )
+    .highlight_symbol("> ")
+    .block(
+        Block::default()
+            .title(title)
+            .title_style(Style::default().bold())
+            .title_bottom(
+                Line::from(format!(
+                    " {} ",
+                    ctx.keymap.hint(tr(Text::MatchupListHint), true)
+                ))
+                .right_aligned(),
+            )
+            .borders(Borders::ALL),
+    );
+
+    (
+        table,
+        TableState::default().with_selected(ctx.matchup_list_scroll_pos),
+    )
+}
+
+impl AppContext<'_> {
+    /// Matchups whose opponent matches the filter box, in display order.
+    pub fn visible_matchups(&self) -> Vec<&MatchupEntry> {
+        filter_entries(
+            &self.matchup_list,
+            self.matchup_filter.value(),
+            &self.api.champ_names_en,
+        )
+    }
+
+    pub fn open_matchup_list(&mut self) {
+        let Some(matchups) = &self.selected_champ_matchups else {
+            return;
+        };
+        self.matchup_list = matchups
+            .all_matchups
+            .iter()
+            .filter_map(|m| {
+                self.champ_by_key
+                    .get(&m.champion_id.to_string())
+                    .map(|champion| MatchupEntry {
+                        champion: champion.clone(),
+                        matchup: m.clone(),
+                    })
+            })
+            .collect();
+        self.matchup_filter.reset();
+        self.sort_matchup_list();
+        self.reset_matchup_list_scroll();
+        self.state = State::MatchupList;
+    }
+
+    fn reset_matchup_list_scroll(&mut self) {
+        self.matchup_list_scroll_pos = (!self.visible_matchups().is_empty()).then_some(0);
+    }
+
+    fn sort_matchup_list(&mut self) {
+        sort_entries(
+            &mut self.matchup_list,
+            self.matchup_list_sort,
+            self.matchup_list_descending,
+        );
+    }
+
+    fn move_matchup_list_sort(&mut self, forward: bool) {
+        let columns = MatchupColumn::ALL;
+        let index = columns
+            .iter()
+            .position(|c| *c == self.matchup_list_sort)
+            .unwrap_or_default();
+        let next = if forward {
+            (index + 1) % columns.len()
+        } else {
+            (index + columns.len() - 1) % columns.len()
+        };
+        self.matchup_list_sort = columns[next];
+        self.matchup_list_descending = columns[next] != MatchupColumn::Champion;
+        self.sort_matchup_list();
+    }
+
+    pub fn next_matchup_list_sort(&mut self) {
+        self.move_matchup_list_sort(true);
+    }
+
+    pub fn prev_matchup_list_sort(&mut self) {
+        self.move_matchup_list_sort(false);
+    }
+
+    pub fn reverse_matchup_list(&mut self) {
+        self.matchup_list_descending = !self.matchup_list_descending;
+        self.sort_matchup_list();
+    }
+
+    pub fn next_matchup_list_entry(&mut self) {
+        if let Some(pos) = self.matchup_list_scroll_pos
+            && pos + 1 < self.visible_matchups().len()
+        {
+            self.matchup_list_scroll_pos = Some(pos + 1);
+        }
+    }
+
+    pub fn prev_matchup_list_entry(&mut self) {
+        if let Some(pos) = self.matchup_list_scroll_pos
+            && pos > 0
+        {
+            self.matchup_list_scroll_pos = Some(pos - 1);
+        }
+    }
+
+    pub fn on_matchup_filter_key(&mut self, key: KeyEvent) {
+        self.matchup_filter.handle_event(&Event::Key(key));
+        self.reset_matchup_list_scroll();
+    }
+
+    /// Clears the filter if there is one, otherwise closes the matchup list.
+    pub fn leave_matchup_list(&mut self) {
+        if self.matchup_filter.value().is_empty() {
+            self.state = State::ChampSelected;
+        } else {
+            self.matchup_filter.reset();
+            self.reset_matchup_list_scroll();
+        }
+    }
+
+    pub fn select_matchup_list_entry(&mut self) {
+        if let Some(champ) = self
+            .matchup_list_scroll_pos
+            .and_then(|p| self.visible_matchups().get(p).map(|e| e.champion.clone()))
+        {
+            self.select_champion(&champ);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(key: &str, name: &str, wins: i32, matches: i32) -> MatchupEntry {
+        MatchupEntry {
+            champion: util::test_champion(name, key, name),
+            matchup: Matchup {
+                champion_id: key.parse().unwrap(),
+                wins,
+                matches,
+                winrate: f64::from(wins) / f64::from(matches),
+            },
+        }
+    }
+
+    fn entries() -> Vec<MatchupEntry> {
+        vec![
+            entry("1", "Annie", 55, 100),
+            entry("2", "Zed", 480, 1000),
+            entry("3", "Kai'Sa", 310, 600),
+            entry("4", "Brand", 9, 20),
+        ]
+    }
+
+    fn names<'a>(entries: impl IntoIterator<Item = &'a MatchupEntry>) -> Vec<&'a str> {
+        entries
+            .into_iter()
+            .map(|e| e.champion.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn sorts_by_column() {
+        let mut list = entries();
+        sort_entries(&mut list, MatchupColumn::WinRate, true);
+        assert_eq!(names(&list), ["Annie", "Kai'Sa", "Zed", "Brand"]);
+        sort_entries(&mut list, MatchupColumn::WinRate, false);
+        assert_eq!(names(&list), ["Brand", "Zed", "Kai'Sa", "Annie"]);
+
+        // Every opponent shares the same average, so this matches win rate.
+        sort_entries(&mut list, MatchupColumn::VsAverage, true);
+        assert_eq!(names(&list), ["Annie", "Kai'Sa", "Zed", "Brand"]);
+
+        sort_entries(&mut list, MatchupColumn::Matches, true);
+        assert_eq!(names(&list), ["Zed", "Kai'Sa", "Annie", "Brand"]);
+        sort_entries(&mut list, MatchupColumn::PickShare, false);
+        assert_eq!(names(&list), ["Brand", "Annie", "Kai'Sa", "Zed"]);
+
+        sort_entries(&mut list, MatchupColumn::Champion, false);
+        assert_eq!(names(&list), ["Annie", "Brand", "Kai'Sa", "Zed"]);
+    }
+
+    #[test]
+    fn filters_by_either_name() {
+        let list = entries();
+        let english = HashMap::from([("Zed".to_string(), "Zed the Master".to_string())]);
+
+        assert_eq!(names(filter_entries(&list, "", &english)).len(), 4);
+        assert_eq!(names(filter_entries(&list, "KAI", &english)), ["Kai'Sa"]);
+        assert_eq!(
+            names(filter_entries(&list, "an", &english)),
+            ["Annie", "Brand"]
+        );
+        assert_eq!(names(filter_entries(&list, "master", &english)), ["Zed"]);
+        assert!(filter_entries(&list, "teemo", &english).is_empty());
+    }
+}
@@ -2,14 +2,18 @@ use std::{borrow::Cow, collections::HashMap};
 
 use ddragon::models::champions::ChampionShort;
 use ratatui::{
-    style::{Color, Style},
+    style::Style,
     widgets::{Paragraph, Widget},
 };
 use ugg_types::matchups::{Matchup, MatchupData};
+use ugg_types::stats::StatSettings;
+
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
 
 pub fn make_matchup_row<'a>(
     title: &'a str,
-    matchups: &'a [Matchup],
+    matchups: &[Matchup],
     champ_data: &'a HashMap<String, ChampionShort>,
 ) -> Paragraph<'a> {
     Paragraph::new(format!(
@@ -35,11 +39,20 @@ pub fn make_matchup_row<'a>(
 pub fn make<'a>(
     matchups: &'a MatchupData,
     champ_data: &'a HashMap<String, ChampionShort>,
+    settings: &StatSettings,
 ) -> [impl Widget + 'a; 2] {
     [
-        make_matchup_row("Best Matchups", &matchups.best_matchups, champ_data)
-            .style(Style::default().fg(Color::Cyan).bold()),
-        make_matchup_row("Worst Matchups", &matchups.worst_matchups, champ_data)
-            .style(Style::default().fg(Color::Red).bold()),
+        make_matchup_row(
+            tr(Text::BestMatchups),
+            &matchups.best_matchups(settings),
+            champ_data,
+        )
+        .style(Style::default().fg(theme().positive).bold()),
+        make_matchup_row(
+            tr(Text::WorstMatchups),
+            &matchups.worst_matchups(settings),
+            champ_data,
+        )
+        .style(Style::default().fg(theme().negative).bold()),
     ]
 }
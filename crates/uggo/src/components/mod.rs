@@ -1,18 +1,29 @@
+pub mod ability_detail;
 pub mod ability_order;
 pub mod app_border;
 pub mod augments;
 pub mod build_select;
+pub mod cache_maintenance;
 pub mod champ_list;
 pub mod champ_name;
 pub mod champ_synergy;
+pub mod compact;
+pub mod compare;
+pub mod counter_picks;
 pub mod help_menu;
+pub mod item_detail;
 pub mod items;
+pub mod matchup_list;
 pub mod matchups;
 pub mod mode_select;
+pub mod pool_select;
 pub mod region_select;
 pub mod role_select;
+pub mod rune_detail;
 pub mod rune_path;
 pub mod search;
 pub mod shards;
 pub mod spells;
+pub mod status_bar;
+pub mod tier_list;
 pub mod version_select;
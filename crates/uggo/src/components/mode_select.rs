@@ -1,30 +1,32 @@
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     widgets::{Block, Borders, List, ListItem, ListState},
 };
 use ugg_types::mappings::Mode;
 
 use crate::context::{AppContext, State};
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
 
 #[allow(clippy::cast_possible_truncation)]
 pub fn make<'a>(ctx: &AppContext) -> (List<'a>, ListState, Rect) {
     let mode_list = List::new(
         Mode::all()
             .iter()
-            .map(|m| ListItem::new(m.to_string()).style(Style::default().fg(Color::White)))
+            .map(|m| ListItem::new(m.to_string()).style(Style::default().fg(theme().text)))
             .collect::<Vec<_>>(),
     )
-    .style(Style::default().fg(Color::White).not_bold())
+    .style(Style::default().fg(theme().text).not_bold())
     .highlight_style(
         Style::default()
-            .fg(Color::Green)
+            .fg(theme().accent)
             .add_modifier(Modifier::ITALIC),
     )
     .highlight_symbol("> ")
     .block(
         Block::default()
-            .title(" Game Mode ")
+            .title(format!(" {} ", tr(Text::GameMode)))
             .title_style(Style::default().bold())
             .borders(Borders::ALL),
     );
@@ -69,6 +71,7 @@ impl AppContext<'_> {
         if let Some(mode) = self.mode_scroll_pos.and_then(|p| Mode::all().get(p)) {
             self.mode = *mode;
             self.state = State::Initial;
+            self.update_champ_list();
             if let Some(champ) = self.selected_champ.clone() {
                 self.select_champion(&champ);
                 self.state = State::ChampSelected;
@@ -0,0 +1,128 @@
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, List, ListItem, ListState},
+};
+
+use crate::context::AppContext;
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
+
+/// The title of pool `index`, e.g. ` Mid (1/3) `.
+fn title(ctx: &AppContext, index: usize) -> String {
+    let name = ctx.pools.get(index).map_or("", |p| p.name.as_str());
+    if ctx.pools.len() > 1 {
+        format!(" {name} ({}/{}) ", index + 1, ctx.pools.len())
+    } else {
+        format!(" {name} ")
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub fn make<'a>(ctx: &AppContext) -> (List<'a>, ListState, Rect) {
+    let (pool, pos) = ctx.pool_scroll_pos.unzip();
+    let items = match pool.and_then(|p| ctx.pools.get(p)) {
+        Some(pool) => pool
+            .champions
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                ListItem::new(format!("{} {}", i + 1, c.name))
+                    .style(Style::default().fg(theme().text))
+            })
+            .collect::<Vec<_>>(),
+        None => vec![ListItem::new(tr(Text::NoPools)).style(Style::default().fg(theme().muted))],
+    };
+
+    let pool_list = List::new(items)
+        .style(Style::default().fg(theme().text).not_bold())
+        .highlight_style(
+            Style::default()
+                .fg(theme().accent)
+                .add_modifier(Modifier::ITALIC),
+        )
+        .highlight_symbol("> ")
+        .block(
+            Block::default()
+                .title(pool.map_or_else(|| format!(" {} ", tr(Text::Pools)), |p| title(ctx, p)))
+                .title_style(Style::default().bold())
+                .borders(Borders::ALL),
+        );
+
+    // Sized for the largest pool so switching between them doesn't resize it.
+    let widest = ctx
+        .pools
+        .iter()
+        .enumerate()
+        .flat_map(|(p, pool)| {
+            pool.champions
+                .iter()
+                .enumerate()
+                .map(|(i, c)| Line::from(format!("{} {}", i + 1, c.name)).width() + 3)
+                .chain([Line::from(title(ctx, p)).width()])
+        })
+        .max()
+        .unwrap_or_else(|| Line::from(tr(Text::NoPools)).width() + 3);
+    let tallest = ctx
+        .pools
+        .iter()
+        .map(|p| p.champions.len())
+        .max()
+        .unwrap_or(1);
+
+    (
+        pool_list,
+        ListState::default().with_selected(pos),
+        Rect::new(0, 0, widest as u16 + 2, tallest as u16 + 2),
+    )
+}
+
+impl AppContext<'_> {
+    pub fn next_pool_champ(&mut self) {
+        if let Some((pool, pos)) = self.pool_scroll_pos
+            && pos + 1 < self.pools[pool].champions.len()
+        {
+            self.pool_scroll_pos = Some((pool, pos + 1));
+        }
+    }
+
+    pub fn prev_pool_champ(&mut self) {
+        if let Some((pool, pos)) = self.pool_scroll_pos
+            && pos > 0
+        {
+            self.pool_scroll_pos = Some((pool, pos - 1));
+        }
+    }
+
+    pub fn next_pool(&mut self) {
+        if let Some((pool, _)) = self.pool_scroll_pos {
+            self.pool_scroll_pos = Some(((pool + 1) % self.pools.len(), 0));
+        }
+    }
+
+    pub fn prev_pool(&mut self) {
+        if let Some((pool, _)) = self.pool_scroll_pos {
+            self.pool_scroll_pos = Some(((pool + self.pools.len() - 1) % self.pools.len(), 0));
+        }
+    }
+
+    /// Moves to the `index`th champion of the shown pool, returning whether
+    /// it exists.
+    pub fn set_pool_pos(&mut self, index: usize) -> bool {
+        match self.pool_scroll_pos {
+            Some((pool, _)) if index < self.pools[pool].champions.len() => {
+                self.pool_scroll_pos = Some((pool, index));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn select_pool_entry(&mut self) {
+        match self.pool_scroll_pos {
+            Some((pool, pos)) => self.select_pool_champion(pool, pos),
+            None => self.return_to_initial(false),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use ratatui::{
+    layout::Rect,
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Widget, Wrap},
+};
+
+use crate::components::rune_path::rune_color;
+use crate::context::AppContext;
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
+use crate::util;
+
+pub fn make<'a>(ctx: &AppContext) -> Option<(impl Widget + 'a, Rect)> {
+    let rune = ctx.highlighted_rune()?;
+
+    let mut lines = vec![
+        Line::from(format!("● {}", rune.parent))
+            .style(Style::default().fg(rune_color(&rune.parent)).bold()),
+        Line::from(""),
+    ];
+    lines.extend(
+        util::strip_html(&rune.rune.long_desc)
+            .lines()
+            .map(|l| Line::from(l.to_owned())),
+    );
+
+    let mut siblings = ctx
+        .api
+        .runes
+        .values()
+        .filter(|r| r.parent_id == rune.parent_id && r.slot == rune.slot)
+        .collect::<Vec<_>>();
+    siblings.sort_by_key(|r| r.index);
+
+    if siblings.len() > 1 {
+        lines.push(Line::from(""));
+        lines.push(Line::from(format!("{}:", tr(Text::SlotAlternatives))).bold());
+        for sibling in siblings {
+            let (marker, style) = if sibling.rune.id == rune.rune.id {
+                ("● ", Style::default().fg(theme().accent).bold())
+            } else {
+                ("· ", Style::default().bold())
+            };
+            lines.push(Line::from(vec![
+                Span::raw(marker),
+                Span::styled(sibling.rune.name.clone(), style),
+            ]));
+            lines.extend(
+                util::strip_html(&sibling.rune.short_desc)
+                    .lines()
+                    .map(|l| Line::from(format!("  {l}")).fg(theme().muted)),
+            );
+        }
+    }
+
+    Some((
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .style(Style::default().fg(theme().text))
+            .block(
+                Block::default()
+                    .title(format!(" {} ", rune.rune.name))
+                    .title_style(Style::default().bold())
+                    .borders(Borders::ALL),
+            ),
+        Rect::new(0, 0, 64, 22),
+    ))
+}
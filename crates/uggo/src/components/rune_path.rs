@@ -3,12 +3,15 @@ use std::collections::HashMap;
 use ddragon::models::runes::RuneElement;
 use ratatui::{
     layout::{Alignment, Constraint},
-    style::{Color, Style, Stylize},
-    text::Line,
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
     widgets::{Block, Borders, Cell, Row, Table, Widget},
 };
-use ugg_types::{default_overview::OverviewData, rune::RuneExtended};
+use ugg_types::{default_overview::OverviewData, overview::Overview, rune::RuneExtended};
 
+use crate::context::{AppContext, State};
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
 use crate::util;
 
 fn format_rune_position(rune: &RuneExtended<RuneElement>) -> String {
@@ -27,55 +30,153 @@ fn format_rune_position(rune: &RuneExtended<RuneElement>) -> String {
     position_message
 }
 
-fn rune_color(name: &str) -> Color {
+pub fn rune_color(name: &str) -> Color {
+    let theme = theme();
     match name {
-        "Precision" => Color::Yellow,
-        "Domination" => Color::Red,
-        "Sorcery" => Color::Blue,
-        "Resolve" => Color::Green,
-        "Inspiration" => Color::Cyan,
-        _ => Color::Gray,
+        "Precision" => theme.yellow,
+        "Domination" => theme.red,
+        "Sorcery" => theme.blue,
+        "Resolve" => theme.green,
+        "Inspiration" => theme.cyan,
+        _ => theme.muted,
     }
 }
 
 pub fn make_placeholder() -> impl Widget {
     Block::default()
-        .white()
-        .title(" Rune Paths ")
+        .fg(theme().text)
+        .title(format!(" {} ", tr(Text::RunePaths)))
         .title_style(Style::default().bold())
         .borders(Borders::ALL)
 }
 
 fn make_single_rune_path(
     grouped_runes: &(String, Vec<&RuneExtended<RuneElement>>),
+    highlight: Option<usize>,
+    stats: Option<Line<'static>>,
 ) -> impl Widget + use<> {
+    let mut block = Block::default()
+        .fg(theme().text)
+        .title(format!(" ● {} ", grouped_runes.0))
+        .title_style(Style::default().fg(rune_color(&grouped_runes.0)).bold())
+        .borders(Borders::ALL);
+    if let Some(stats) = stats {
+        block = block.title_bottom(stats);
+    }
+
     Table::new(
-        grouped_runes.1.iter().map(|rune| {
-            Row::new(vec![
+        grouped_runes.1.iter().enumerate().map(|(index, rune)| {
+            let row = Row::new(vec![
                 Cell::from(Line::from(format_rune_position(rune)).alignment(Alignment::Right)),
                 Cell::from(rune.rune.name.clone()),
-            ])
+            ]);
+            if highlight == Some(index) {
+                row.style(
+                    Style::default()
+                        .fg(theme().accent)
+                        .add_modifier(Modifier::ITALIC),
+                )
+            } else {
+                row
+            }
         }),
         [Constraint::Max(6), Constraint::Length(30)],
     )
-    .style(Style::default().fg(Color::White))
+    .style(Style::default().fg(theme().text))
     .column_spacing(1)
-    .block(
-        Block::default()
-            .white()
-            .title(format!(" ● {} ", grouped_runes.0))
-            .title_style(Style::default().fg(rune_color(&grouped_runes.0)).bold())
-            .borders(Borders::ALL),
-    )
+    .block(block)
 }
 
 pub fn make(
     overview: &OverviewData,
     runes: &HashMap<i64, RuneExtended<RuneElement>>,
+    selected: Option<(usize, usize)>,
 ) -> [impl Widget; 2] {
     let grouped_runes = util::group_runes(&overview.runes.rune_ids, runes);
+    let highlight_for = |path: usize| selected.filter(|(p, _)| *p == path).map(|(_, r)| r);
+    // The stats cover the whole page, so only show them once.
+    let stats = Line::from(
+        [
+            vec![Span::raw(" ")],
+            util::stat_spans(
+                overview.runes.wins,
+                overview.runes.matches,
+                overview.matches,
+            ),
+            vec![Span::raw(" ")],
+        ]
+        .concat(),
+    );
     [
-        make_single_rune_path(&grouped_runes[0]),
-        make_single_rune_path(&grouped_runes[1]),
+        make_single_rune_path(&grouped_runes[0], highlight_for(0), Some(stats)),
+        make_single_rune_path(&grouped_runes[1], highlight_for(1), None),
     ]
 }
+
+impl AppContext<'_> {
+    /// The rune ids shown in the primary and secondary paths, in display order.
+    pub fn rune_paths(&self) -> Option<[Vec<i64>; 2]> {
+        match self.selected_champ_overview.as_ref()? {
+            Overview::Default(overview) => Some(
+                util::group_runes(&overview.runes.rune_ids, &self.api.runes)
+                    .map(|(_, runes)| runes.iter().map(|r| r.rune.id).collect()),
+            ),
+            Overview::Arena(_) => None,
+        }
+    }
+
+    pub fn focus_runes(&mut self) {
+        if let Some(paths) = self.rune_paths()
+            && let Some(path) = paths.iter().position(|p| !p.is_empty())
+        {
+            self.state = State::RuneSelect;
+            self.rune_scroll_pos = Some((path, 0));
+        }
+    }
+
+    pub fn next_rune(&mut self) {
+        if let Some((path, row)) = self.rune_scroll_pos
+            && let Some(paths) = self.rune_paths()
+            && row + 1 < paths[path].len()
+        {
+            self.rune_scroll_pos = Some((path, row + 1));
+        }
+    }
+
+    pub fn prev_rune(&mut self) {
+        if let Some((path, row)) = self.rune_scroll_pos
+            && row > 0
+        {
+            self.rune_scroll_pos = Some((path, row - 1));
+        }
+    }
+
+    pub fn switch_rune_path(&mut self) {
+        if let Some((path, row)) = self.rune_scroll_pos
+            && let Some(paths) = self.rune_paths()
+        {
+            let other = 1 - path;
+            if !paths[other].is_empty() {
+                self.rune_scroll_pos = Some((other, row.min(paths[other].len() - 1)));
+            }
+        }
+    }
+
+    /// The rune currently highlighted in the rune paths panel.
+    pub fn highlighted_rune(&self) -> Option<&RuneExtended<RuneElement>> {
+        let (path, row) = self.rune_scroll_pos?;
+        let id = self.rune_paths()?.get(path)?.get(row).copied()?;
+        self.api.runes.get(&id)
+    }
+
+    pub fn show_rune_detail(&mut self) {
+        if self.highlighted_rune().is_some() {
+            self.state = State::RuneDetail;
+        }
+    }
+
+    pub fn leave_runes(&mut self) {
+        self.rune_scroll_pos = None;
+        self.return_to_initial(false);
+    }
+}
@@ -1,23 +1,25 @@
 use ratatui::{
     crossterm::event::{Event, KeyEvent},
-    style::{Color, Style},
+    style::Style,
     widgets::{Block, Borders, Paragraph, Widget},
 };
 use tui_input::backend::crossterm::EventHandler;
 
 use crate::context::{AppContext, State};
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
 
 pub fn make<'a>(ctx: &'a AppContext) -> impl Widget + 'a {
     Paragraph::new(ctx.input.value())
         .style(match ctx.state {
-            State::TextInput => Style::default().fg(Color::Green),
-            _ => Style::default().fg(Color::White),
+            State::TextInput => Style::default().fg(theme().accent),
+            _ => Style::default().fg(theme().text),
         })
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" Search ")
-                .title_style(Style::default().fg(Color::White).bold()),
+                .title(format!(" {} ", tr(Text::Search)))
+                .title_style(Style::default().fg(theme().text).bold()),
         )
 }
 
@@ -1,41 +1,46 @@
 use ratatui::{
     layout::{Alignment, Constraint},
     style::{Color, Style, Stylize},
-    text::{Line, Text},
+    text::{Line, Span},
     widgets::{Block, Borders, Cell, Row, Table, Widget},
 };
+use ugg_types::mappings::Shard;
 
-const fn shard_color(shard: i64) -> Color {
-    match shard {
-        5001 | 5011 => Color::Green,
-        5002 => Color::Red,
-        5003 | 5013 => Color::Magenta,
-        5005 => Color::Yellow,
-        5008 => Color::Blue,
-        5010 => Color::White,
-        _ => Color::Gray,
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
+
+fn shard_color(shard: i64) -> Color {
+    let theme = theme();
+    match Shard::try_from(shard) {
+        Ok(Shard::Health | Shard::HealthScaling) => theme.green,
+        Ok(Shard::Armor) => theme.red,
+        Ok(Shard::MagicResist | Shard::Tenacity) => theme.magenta,
+        Ok(Shard::AttackSpeed) => theme.yellow,
+        Ok(Shard::AdaptiveForce) => theme.blue,
+        Ok(Shard::MoveSpeed) => theme.text,
+        Ok(Shard::AbilityHaste) | Err(()) => theme.muted,
     }
 }
 
-const fn shard_text(id: i64) -> &'static str {
-    match id {
-        5001 => "+10-180 Health",
-        5002 => "+6 Armor",
-        5003 => "+8 Magic Resist",
-        5005 => "+10% Attack Speed",
-        5007 => "+8 Ability Haste",
-        5008 => "+9 Adaptive Force",
-        5010 => "+2% Move Speed",
-        5011 => "65 Health",
-        5013 => "+10% Tenacity and Slow Resist",
-        _ => "Unknown",
-    }
+pub fn shard_text(id: i64) -> &'static str {
+    tr(match Shard::try_from(id) {
+        Ok(Shard::HealthScaling) => Text::ShardHealthScaling,
+        Ok(Shard::Armor) => Text::ShardArmor,
+        Ok(Shard::MagicResist) => Text::ShardMagicResist,
+        Ok(Shard::AttackSpeed) => Text::ShardAttackSpeed,
+        Ok(Shard::AbilityHaste) => Text::ShardAbilityHaste,
+        Ok(Shard::AdaptiveForce) => Text::ShardAdaptiveForce,
+        Ok(Shard::MoveSpeed) => Text::ShardMoveSpeed,
+        Ok(Shard::Health) => Text::ShardHealth,
+        Ok(Shard::Tenacity) => Text::ShardTenacity,
+        Err(()) => Text::Unknown,
+    })
 }
 
 pub fn make_placeholder() -> impl Widget {
     Block::default()
-        .white()
-        .title(" Shards & Spells ")
+        .fg(theme().text)
+        .title(format!(" {} ", tr(Text::ShardsAndSpells)))
         .title_style(Style::default().bold())
         .borders(Borders::ALL)
 }
@@ -43,7 +48,7 @@ pub fn make_placeholder() -> impl Widget {
 fn make_shard_row(name: &'_ str, shard: i64) -> Row<'_> {
     Row::new(vec![
         Cell::from(Line::from(name).alignment(Alignment::Right)),
-        Cell::from(Text::styled(
+        Cell::from(Span::styled(
             "●",
             Style::default().fg(shard_color(shard)).bold(),
         )),
@@ -54,14 +59,14 @@ fn make_shard_row(name: &'_ str, shard: i64) -> Row<'_> {
 pub fn make(shards: &[i64]) -> impl Widget {
     Table::new(
         vec![
-            make_shard_row("Offense", shards[0]),
-            make_shard_row("Flex", shards[1]),
-            make_shard_row("Defense", shards[2]),
+            make_shard_row(tr(Text::ShardOffense), shards[0]),
+            make_shard_row(tr(Text::ShardFlex), shards[1]),
+            make_shard_row(tr(Text::ShardDefense), shards[2]),
         ],
         [
             Constraint::Length(7),
             Constraint::Length(1),
-            Constraint::Length(20),
+            Constraint::Min(0),
         ],
     )
     .column_spacing(1)
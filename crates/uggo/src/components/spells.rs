@@ -1,44 +1,66 @@
 use ratatui::{
-    style::{Color, Style},
+    style::Style,
     text::{Line, Span},
 };
 
+use ugg_types::default_overview::SummonerSpells;
+
 use crate::context::AppContext;
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
+use crate::util;
 
-fn spell_dot(spell: &'_ str) -> Span<'_> {
+// Matched on spell ids rather than names, since names are localized.
+fn spell_dot<'a>(spell: i64) -> Span<'a> {
+    let theme = theme();
     Span::styled(
         " ● ",
         Style::default().fg(match spell {
-            "Flash" | "Barrier" | "Exhaust" => Color::Yellow,
-            "Ghost" | "Clarity" => Color::Blue,
-            "Cleanse" | "Dash" => Color::Cyan,
-            "Teleport" => Color::Magenta,
-            "Heal" => Color::Green,
-            "Smite" | "Ignite" => Color::Red,
-            "Mark" => Color::White,
-            _ => Color::Gray,
+            // Flash, Arena Flash, Barrier, Exhaust
+            4 | 2202 | 21 | 3 => theme.yellow,
+            // Ghost, Clarity
+            6 | 13 => theme.blue,
+            // Cleanse
+            1 => theme.cyan,
+            // Teleport
+            12 => theme.magenta,
+            // Heal
+            7 => theme.green,
+            // Smite, Ignite
+            11 | 14 => theme.red,
+            // Mark
+            32 | 39 => theme.text,
+            _ => theme.muted,
         }),
     )
 }
 
-pub fn make<'a>(ctx: &'a AppContext, spells: &'a [i64]) -> Line<'a> {
+pub fn make<'a>(ctx: &'a AppContext, spells: &'a SummonerSpells, total_matches: i64) -> Line<'a> {
+    let stats = util::stat_spans(spells.wins, spells.matches, total_matches);
+    let spells = &spells.spell_ids;
     let spell_1 = ctx
         .api
         .summoner_spells
         .get(&spells[0])
-        .map_or("Unknown", |s| s.as_str());
+        .map_or(tr(Text::Unknown), |s| s.as_str());
     let spell_2 = ctx
         .api
         .summoner_spells
         .get(&spells[1])
-        .map_or("Unknown", |s| s.as_str());
+        .map_or(tr(Text::Unknown), |s| s.as_str());
 
-    Line::from(vec![
-        Span::styled("Spells:", Style::default().fg(Color::White)),
-        spell_dot(spell_1),
-        Span::styled(spell_1, Style::default().fg(Color::White)),
-        Span::styled(" +", Style::default().fg(Color::White)),
-        spell_dot(spell_2),
-        Span::styled(spell_2, Style::default().fg(Color::White)),
-    ])
+    Line::from(
+        vec![
+            Span::styled(tr(Text::Spells), Style::default().fg(theme().text)),
+            spell_dot(spells[0]),
+            Span::styled(spell_1, Style::default().fg(theme().text)),
+            Span::styled(" +", Style::default().fg(theme().text)),
+            spell_dot(spells[1]),
+            Span::styled(spell_2, Style::default().fg(theme().text)),
+            Span::raw("  "),
+        ]
+        .into_iter()
+        .chain(stats)
+        .collect::<Vec<_>>(),
+    )
 }
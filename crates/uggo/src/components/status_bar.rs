@@ -0,0 +1,97 @@
+use ratatui::{
+    style::Style,
+    text::{Line, Span},
+};
+use ugg_types::mappings::Mode;
+use uggo_ugg_api::{ErrorKind, UggError};
+
+use crate::context::AppContext;
+use crate::i18n::{Text, tr};
+use crate::keymap::Action;
+use crate::theme::theme;
+
+/// Explains a failed request in plain language.
+pub fn describe_error(error: &UggError, champ: &str, mode: Mode, patch: &str) -> String {
+    tr(match error.kind() {
+        ErrorKind::NoData => Text::ErrorNoData,
+        ErrorKind::TimedOut => Text::ErrorTimedOut,
+        ErrorKind::Offline => Text::ErrorOffline,
+        ErrorKind::BadData => Text::ErrorBadData,
+        ErrorKind::Other => Text::ErrorOther,
+    })
+    .replace("{champ}", champ)
+    .replace("{mode}", &mode.to_string())
+    .replace("{patch}", patch)
+}
+
+/// The last error, if any, and how to retry it.
+pub fn make_left<'a>(ctx: &AppContext) -> Line<'a> {
+    ctx.last_error.as_ref().map_or_else(Line::default, |error| {
+        Line::from(vec![
+            Span::styled(
+                format!("{error} "),
+                Style::default().fg(theme().error).bold(),
+            ),
+            Span::styled(
+                format!(
+                    "[{}: {}]",
+                    ctx.keymap.describe(Action::Retry),
+                    tr(Text::Retry)
+                ),
+                Style::default().fg(theme().muted),
+            ),
+        ])
+    })
+}
+
+/// Whether the League client is connected, and where the current build came
+/// from.
+pub fn make_right<'a>(ctx: &AppContext) -> Line<'a> {
+    let mut spans = Vec::new();
+    if let Some(client) = &ctx.client {
+        let (status, color) = if client.is_connected() {
+            (Text::Connected, theme().positive)
+        } else {
+            (Text::Disconnected, theme().muted)
+        };
+        spans.push(Span::styled(
+            format!("{}: ", tr(Text::Client)),
+            Style::default().fg(theme().muted),
+        ));
+        spans.push(Span::styled(tr(status), Style::default().fg(color)));
+    }
+    if let Some(fetch) = ctx.api.last_fetch() {
+        if !spans.is_empty() {
+            spans.push(Span::styled(" · ", Style::default().fg(theme().muted)));
+        }
+        spans.push(Span::styled(
+            format!(
+                "{}: {} · {}: {}",
+                tr(Text::Rank),
+                fetch.rank,
+                tr(Text::Cache),
+                tr(if fetch.cached {
+                    Text::Cached
+                } else {
+                    Text::Live
+                }),
+            ),
+            Style::default().fg(theme().muted),
+        ));
+    }
+    Line::from(spans).right_aligned()
+}
+
+impl AppContext<'_> {
+    /// Fetches the selected champion and any failed rankings again, e.g.
+    /// after a timeout.
+    pub fn retry(&mut self) {
+        if self.champ_rankings_failed.take().is_some() {
+            self.last_error = None;
+            self.update_champ_list();
+        }
+        if let Some(champ) = self.selected_champ.clone() {
+            self.select_champion(&champ);
+        }
+    }
+}
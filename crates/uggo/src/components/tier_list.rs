@@ -0,0 +1,256 @@
+use ratatui::{
+    layout::{Alignment, Constraint},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Cell, Row, Table, TableState},
+};
+use ugg_types::rankings::Tier;
+use uggo_ugg_api::TierListEntry;
+
+use crate::context::{AppContext, State};
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
+use crate::util;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TierListColumn {
+    Champion,
+    Role,
+    #[default]
+    Tier,
+    WinRate,
+    PickRate,
+    BanRate,
+    Matches,
+}
+
+impl TierListColumn {
+    const ALL: [Self; 7] = [
+        Self::Champion,
+        Self::Role,
+        Self::Tier,
+        Self::WinRate,
+        Self::PickRate,
+        Self::BanRate,
+        Self::Matches,
+    ];
+
+    fn title(self) -> &'static str {
+        match self {
+            Self::Champion => tr(Text::Champion),
+            Self::Role => tr(Text::Role),
+            Self::Tier => tr(Text::Tier),
+            Self::WinRate => tr(Text::WinRate),
+            Self::PickRate => tr(Text::PickRate),
+            Self::BanRate => tr(Text::BanRate),
+            Self::Matches => tr(Text::Matches),
+        }
+    }
+
+    fn compare(self, a: &TierListEntry, b: &TierListEntry) -> std::cmp::Ordering {
+        match self {
+            Self::Champion => a.champion.name.cmp(&b.champion.name),
+            Self::Role => a.role.to_string().cmp(&b.role.to_string()),
+            // Lower ranks are better, so flip these to keep "best first".
+            Self::Tier => b.data.rank.cmp(&a.data.rank),
+            Self::WinRate => a.data.win_rate().total_cmp(&b.data.win_rate()),
+            Self::PickRate => a.data.pick_rate().total_cmp(&b.data.pick_rate()),
+            Self::BanRate => a.data.ban_rate().total_cmp(&b.data.ban_rate()),
+            Self::Matches => a.data.matches.cmp(&b.data.matches),
+        }
+    }
+}
+
+fn tier_color(tier: Tier) -> Color {
+    let theme = theme();
+    match tier {
+        Tier::SPlus => theme.magenta,
+        Tier::S => theme.yellow,
+        Tier::A => theme.green,
+        Tier::B => theme.text,
+        Tier::C => theme.muted,
+        Tier::D => theme.red,
+    }
+}
+
+fn right_cell<'a>(text: String) -> Cell<'a> {
+    Cell::from(Line::from(text).alignment(Alignment::Right))
+}
+
+pub fn make<'a>(ctx: &AppContext) -> (Table<'a>, TableState) {
+    let header = Row::new(TierListColumn::ALL.iter().map(|column| {
+        let title = if *column == ctx.tier_list_sort {
+            let arrow = if ctx.tier_list_descending {
+                "▼"
+            } else {
+                "▲"
+            };
+            format!("{}{arrow}", column.title())
+        } else {
+            column.title().to_string()
+        };
+        Cell::from(title)
+    }))
+    .style(Style::default().fg(theme().muted).bold());
+
+    let rows = ctx.tier_list.iter().map(|entry| {
+        let tier = entry.data.tier();
+        Row::new(vec![
+            Cell::from(entry.champion.name.clone()),
+            Cell::from(entry.role.to_string()),
+            Cell::from(tier.to_string()).style(Style::default().fg(tier_color(tier)).bold()),
+            right_cell(format!("{:.2}%", entry.data.win_rate() * 100.0))
+                .style(Style::default().fg(util::win_rate_color(entry.data.win_rate()))),
+            right_cell(format!("{:.2}%", entry.data.pick_rate() * 100.0)),
+            right_cell(format!("{:.2}%", entry.data.ban_rate() * 100.0)),
+            right_cell(entry.data.matches.to_string()),
+        ])
+    });
+
+    let mut block = Block::default()
+        .title(format!(
+            " {}: {}, {}, {} ",
+            tr(Text::TierList),
+            ctx.role,
+            ctx.region,
+            ctx.mode
+        ))
+        .title_style(Style::default().bold())
+        .title_bottom(
+            Line::from(format!(
+                " {} ",
+                ctx.keymap.hint(tr(Text::TierListHint), false)
+            ))
+            .right_aligned(),
+        )
+        .borders(Borders::ALL);
+    if ctx.champ_rankings_loading.is_some() {
+        block = block.title_bottom(
+            Line::from(format!(" {} ", tr(Text::Loading)))
+                .style(Style::default().fg(theme().muted)),
+        );
+    }
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(14),
+            Constraint::Length(9),
+            Constraint::Length(5),
+            Constraint::Length(9),
+            Constraint::Length(9),
+            Constraint::Length(9),
+            Constraint::Length(9),
+        ],
+    )
+    .header(header)
+    .column_spacing(1)
+    .style(Style::default().fg(theme().text))
+    .row_highlight_style(
+        Style::default()
+            .fg(theme().accent)
+            .add_modifier(Modifier::ITALIC),
+    )
+    .highlight_symbol("> ")
+    .block(block);
+
+    (
+        table,
+        TableState::default().with_selected(ctx.tier_list_scroll_pos),
+    )
+}
+
+impl AppContext<'_> {
+    /// Opens the tier list, which fills in once rankings for every champion
+    /// have loaded.
+    pub fn open_tier_list(&mut self) {
+        self.state = State::TierList;
+        self.tier_list.clear();
+        self.tier_list_scroll_pos = None;
+        if self.champ_rankings_key == Some((self.region, self.mode)) {
+            self.update_tier_list();
+        } else {
+            // Asking for the tier list again is a retry.
+            self.champ_rankings_failed = None;
+            self.fetch_champ_rankings();
+        }
+    }
+
+    /// Lists the loaded rankings for the current role.
+    pub fn update_tier_list(&mut self) {
+        self.tier_list = self.api.build_tier_list(&self.champ_rankings, self.role);
+        self.sort_tier_list();
+        self.tier_list_scroll_pos = (!self.tier_list.is_empty()).then_some(0);
+    }
+
+    fn sort_tier_list(&mut self) {
+        let column = self.tier_list_sort;
+        self.tier_list.sort_by(|a, b| {
+            let ordering = column.compare(a, b);
+            if self.tier_list_descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+    }
+
+    fn move_tier_list_sort(&mut self, forward: bool) {
+        let columns = TierListColumn::ALL;
+        let index = columns
+            .iter()
+            .position(|c| *c == self.tier_list_sort)
+            .unwrap_or_default();
+        let next = if forward {
+            (index + 1) % columns.len()
+        } else {
+            (index + columns.len() - 1) % columns.len()
+        };
+        self.tier_list_sort = columns[next];
+        // Names and roles read best A-Z, everything else best-first.
+        self.tier_list_descending = !matches!(
+            columns[next],
+            TierListColumn::Champion | TierListColumn::Role
+        );
+        self.sort_tier_list();
+    }
+
+    pub fn next_tier_list_sort(&mut self) {
+        self.move_tier_list_sort(true);
+    }
+
+    pub fn prev_tier_list_sort(&mut self) {
+        self.move_tier_list_sort(false);
+    }
+
+    pub fn reverse_tier_list(&mut self) {
+        self.tier_list_descending = !self.tier_list_descending;
+        self.sort_tier_list();
+    }
+
+    pub fn next_tier_list_entry(&mut self) {
+        if let Some(pos) = self.tier_list_scroll_pos
+            && pos + 1 < self.tier_list.len()
+        {
+            self.tier_list_scroll_pos = Some(pos + 1);
+        }
+    }
+
+    pub fn prev_tier_list_entry(&mut self) {
+        if let Some(pos) = self.tier_list_scroll_pos
+            && pos > 0
+        {
+            self.tier_list_scroll_pos = Some(pos - 1);
+        }
+    }
+
+    pub fn select_tier_list_entry(&mut self) {
+        if let Some(champ) = self
+            .tier_list_scroll_pos
+            .and_then(|p| self.tier_list.get(p))
+            .map(|e| e.champion.clone())
+        {
+            self.select_champion(&champ);
+        }
+    }
+}
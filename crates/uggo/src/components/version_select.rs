@@ -1,7 +1,9 @@
 use crate::context::AppContext;
+use crate::i18n::{Text, tr};
+use crate::theme::theme;
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     widgets::{Block, Borders, List, ListItem, ListState},
 };
 
@@ -11,19 +13,19 @@ pub fn make<'a>(ctx: &AppContext) -> (List<'a>, ListState, Rect) {
         ctx.api
             .allowed_versions
             .iter()
-            .map(|m| ListItem::new(m.ddragon.clone()).style(Style::default().fg(Color::White)))
+            .map(|m| ListItem::new(m.ddragon.clone()).style(Style::default().fg(theme().text)))
             .collect::<Vec<_>>(),
     )
-    .style(Style::default().fg(Color::White).not_bold())
+    .style(Style::default().fg(theme().text).not_bold())
     .highlight_style(
         Style::default()
-            .fg(Color::Green)
+            .fg(theme().accent)
             .add_modifier(Modifier::ITALIC),
     )
     .highlight_symbol("> ")
     .block(
         Block::default()
-            .title(" Game Version ")
+            .title(format!(" {} ", tr(Text::GameVersion)))
             .title_style(Style::default().bold())
             .borders(Borders::ALL),
     );
@@ -57,7 +59,12 @@ impl AppContext<'_> {
             .and_then(|p| allowed_versions.get(p))
             && self.version != version.ddragon
         {
-            *self = Self::new_with_version(&version.ddragon)?;
+            let compare_pin = self.compare_pin.take();
+            let client = self.client.take();
+            *self =
+                Self::new_with_version(self.config.clone(), self.keymap.clone(), &version.ddragon)?;
+            self.compare_pin = compare_pin;
+            self.client = client;
         }
         Ok(())
     }
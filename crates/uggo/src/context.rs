@@ -1,9 +1,14 @@
 use std::collections::HashMap;
+use std::sync::mpsc::Receiver;
 
 #[cfg(debug_assertions)]
 use std::time::Duration;
 
-use ddragon::models::champions::ChampionShort;
+use anyhow::anyhow;
+use ddragon::models::{
+    champion::{Champion, Tag},
+    champions::ChampionShort,
+};
 use ratatui::widgets::ListItem;
 use tui_input::Input;
 use tui_logger::TuiWidgetState;
@@ -12,11 +17,25 @@ use ugg_types::{
     mappings::{Build, Mode, Region, Role},
     matchups::MatchupData,
     overview::Overview,
+    rankings::RankingData,
 };
 use uggo_config::Config;
-use uggo_lol_client::LOLClientAPI;
-use uggo_ugg_api::{UggApi, UggApiBuilder};
+use uggo_lol_client::ClientWatcher;
+use uggo_ugg_api::{
+    CacheUsage, FetchedMatchups, FetchedRankings, TierListEntry, UggApi, UggApiBuilder,
+};
 
+use crate::components::{
+    compact::CompactPage,
+    compare::CompareSide,
+    counter_picks::CounterPick,
+    matchup_list::{MatchupColumn, MatchupEntry},
+    status_bar,
+    tier_list::TierListColumn,
+};
+use crate::keymap::Keymap;
+use crate::mouse::MouseAreas;
+use crate::pools::ChampionPool;
 use crate::transpose::Transposable;
 use crate::util;
 
@@ -31,13 +50,45 @@ pub enum State {
     RegionSelect,
     RoleSelect,
     BuildSelect,
+    PoolSelect,
+    CacheMaintenance,
+    ItemSelect,
+    ItemDetail,
+    AbilityDetail,
+    RuneSelect,
+    RuneDetail,
+    TierList,
+    MatchupList,
+    CounterPicks,
+    Compare,
     HelpMenu,
     Logger,
 }
 
+/// How lists of build options (late items, augments, ...) are ordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BuildSort {
+    #[default]
+    WinRate,
+    Popularity,
+}
+
+impl BuildSort {
+    #[must_use]
+    pub const fn toggle(self) -> Self {
+        match self {
+            Self::WinRate => Self::Popularity,
+            Self::Popularity => Self::WinRate,
+        }
+    }
+}
+
 pub struct AppContext<'a> {
     pub api: UggApi,
-    pub client_api: Option<LOLClientAPI>,
+    pub config: Config,
+    pub keymap: Keymap,
+    /// The League client connection, if importing runes is turned on.
+    pub client: Option<ClientWatcher>,
     pub state: State,
     pub show_left_pane: bool,
     pub champ_scroll_pos: Option<usize>,
@@ -45,10 +96,29 @@ pub struct AppContext<'a> {
     pub champ_by_key: HashMap<String, ChampionShort>,
     pub list_indices: Vec<usize>,
     pub champ_list: Vec<ListItem<'a>>,
+    pub champ_role_filter: Option<Role>,
+    pub champ_class_filter: Option<Tag>,
+    pub show_champ_win_rates: bool,
+    /// Ids of the favorite champions, in the order they're pinned.
+    pub favorites: Vec<String>,
+    pub pools: Vec<ChampionPool>,
+    /// The pool last picked from, which number keys pick from next.
+    pub active_pool: Option<usize>,
+    pub pool_scroll_pos: Option<(usize, usize)>,
+    /// Whether picking a champion updates the client's current rune page.
+    pub import_runes: bool,
+    /// Per-role rankings by champion id, used by the role filter and win rates.
+    pub champ_rankings: HashMap<String, HashMap<Role, RankingData>>,
+    pub champ_rankings_key: Option<(Region, Mode)>,
+    /// Rankings being fetched in the background, and who they're for.
+    pub champ_rankings_loading: Option<((Region, Mode), Receiver<FetchedRankings>)>,
+    /// Rankings that failed to load, which aren't fetched again until retried.
+    pub champ_rankings_failed: Option<(Region, Mode)>,
     pub selected_champ: Option<ChampionShort>,
     pub selected_champ_overview: Option<Overview>,
     pub selected_champ_role: Option<Role>,
     pub selected_champ_matchups: Option<MatchupData>,
+    pub selected_champ_details: Option<Champion>,
     pub input: Input,
     pub mode: Mode,
     pub mode_scroll_pos: Option<usize>,
@@ -60,13 +130,46 @@ pub struct AppContext<'a> {
     pub role_scroll_pos: Option<usize>,
     pub build: Build,
     pub build_scroll_pos: Option<usize>,
+    pub build_sort: BuildSort,
+    pub item_scroll_pos: Option<(usize, usize)>,
+    pub rune_scroll_pos: Option<(usize, usize)>,
+    pub ability_detail_scroll: u16,
+    pub help_scroll: usize,
+    pub tier_list: Vec<TierListEntry>,
+    pub tier_list_sort: TierListColumn,
+    pub tier_list_descending: bool,
+    pub tier_list_scroll_pos: Option<usize>,
+    pub matchup_list: Vec<MatchupEntry>,
+    pub matchup_list_sort: MatchupColumn,
+    pub matchup_list_descending: bool,
+    pub matchup_list_scroll_pos: Option<usize>,
+    pub matchup_filter: Input,
+    pub counter_picks: Vec<CounterPick>,
+    /// The pool counter picks are taken from, or every champion if `None`.
+    pub counter_pick_pool: Option<usize>,
+    pub counter_picks_scroll_pos: Option<usize>,
+    /// Matchups being fetched in the background, and the id of the champion
+    /// they're being countered against.
+    pub counter_picks_loading: Option<(String, Receiver<FetchedMatchups>)>,
+    pub cache_usage: Vec<CacheUsage>,
+    pub cache_scroll_pos: Option<usize>,
+    /// How many patches pruning the cache keeps.
+    pub cache_keep: usize,
+    /// The build pinned for comparison, kept across patch changes.
+    pub compare_pin: Option<CompareSide>,
+    pub compact_page: CompactPage,
+    pub mouse_areas: MouseAreas,
+    /// Whether the item or rune detail popup was opened by hovering.
+    pub hover_detail: bool,
+    /// The last failed request, in plain language, for the status bar.
+    pub last_error: Option<String>,
     pub logger_state: TuiWidgetState,
     #[cfg(debug_assertions)]
     pub last_render_duration: Option<Duration>,
 }
 
 impl AppContext<'_> {
-    fn create(api: UggApi) -> Self {
+    fn create(api: UggApi, config: Config, keymap: Keymap) -> anyhow::Result<Self> {
         let version = api.current_version.clone();
         let version_index = api
             .allowed_versions
@@ -87,68 +190,180 @@ impl AppContext<'_> {
             .map(|c| (c.key.clone(), c.clone()))
             .collect::<HashMap<_, _>>();
 
+        let settings = config.settings().clone();
+        let mode = settings.mode.unwrap_or_default();
+        let region = settings.region.unwrap_or(Region::World);
+        let role = settings.role.unwrap_or_default();
+        let build = settings.build.unwrap_or_default();
+
         let mut app_context = Self {
             api,
-            client_api: LOLClientAPI::new().ok(),
+            config,
+            keymap,
+            client: if settings.import_runes.unwrap_or(true) {
+                ClientWatcher::spawn()
+                    .inspect_err(|e| log::error!("Failed to watch for the League client: {e:?}"))
+                    .ok()
+            } else {
+                None
+            },
             state: State::Initial,
-            show_left_pane: true,
+            show_left_pane: settings.show_left_pane.unwrap_or(true),
             champ_scroll_pos: None,
             champ_data: ordered_champ_data,
             champ_by_key,
             list_indices: Vec::new(),
             champ_list: Vec::new(),
+            champ_role_filter: None,
+            champ_class_filter: None,
+            show_champ_win_rates: settings.show_champ_win_rates.unwrap_or_default(),
+            favorites: Vec::new(),
+            pools: Vec::new(),
+            active_pool: None,
+            pool_scroll_pos: None,
+            import_runes: settings.import_runes.unwrap_or(true),
+            champ_rankings: HashMap::new(),
+            champ_rankings_key: None,
+            champ_rankings_loading: None,
+            champ_rankings_failed: None,
             input: Input::default(),
             selected_champ: None,
             selected_champ_overview: None,
             selected_champ_role: None,
             selected_champ_matchups: None,
-            mode: Mode::Normal,
-            mode_scroll_pos: None,
+            selected_champ_details: None,
+            mode,
+            mode_scroll_pos: Mode::all().iter().position(|m| m == &mode),
             version,
             version_scroll_pos: version_index,
-            region: Region::World,
-            region_scroll_pos: Region::all().iter().position(|r| r == &Region::World),
-            role: Role::Automatic,
-            role_scroll_pos: Role::all().iter().position(|r| r == &Role::Automatic),
-            build: Build::Recommended,
-            build_scroll_pos: Build::all().iter().position(|r| r == &Build::Recommended),
+            region,
+            region_scroll_pos: Region::all().iter().position(|r| r == &region),
+            role,
+            role_scroll_pos: Role::all().iter().position(|r| r == &role),
+            build,
+            build_scroll_pos: Build::all().iter().position(|r| r == &build),
+            build_sort: if settings.sort_by_popularity == Some(true) {
+                BuildSort::Popularity
+            } else {
+                BuildSort::WinRate
+            },
+            item_scroll_pos: None,
+            rune_scroll_pos: None,
+            ability_detail_scroll: 0,
+            help_scroll: 0,
+            tier_list: Vec::new(),
+            tier_list_sort: TierListColumn::default(),
+            tier_list_descending: true,
+            tier_list_scroll_pos: None,
+            matchup_list: Vec::new(),
+            matchup_list_sort: MatchupColumn::default(),
+            matchup_list_descending: true,
+            matchup_list_scroll_pos: None,
+            matchup_filter: Input::default(),
+            counter_picks: Vec::new(),
+            counter_pick_pool: None,
+            counter_picks_scroll_pos: None,
+            counter_picks_loading: None,
+            cache_usage: Vec::new(),
+            cache_scroll_pos: None,
+            cache_keep: 2,
+            compare_pin: None,
+            last_error: None,
+            compact_page: CompactPage::default(),
+            mouse_areas: MouseAreas::default(),
+            hover_detail: false,
             logger_state: TuiWidgetState::default(),
             #[cfg(debug_assertions)]
             last_render_duration: None,
         };
+        app_context.load_pools()?;
         app_context.update_champ_list();
 
-        app_context
+        Ok(app_context)
     }
 
-    pub fn new_with_version(version: &str) -> anyhow::Result<Self> {
-        let config = Config::new()?;
-        let api = UggApiBuilder::new()
-            .version(version)
+    /// An API builder using the configured cache, rank, locale and win rate
+    /// thresholds.
+    pub fn api_builder(config: &Config) -> UggApiBuilder {
+        let mut builder = UggApiBuilder::new()
             .cache_dir(config.cache())
-            .build()?;
-        Ok(Self::create(api))
+            .stat_settings(config.stat_settings());
+        if let Some(rank) = config.settings().rank {
+            builder = builder.rank(rank);
+        }
+        if let Some(locale) = config.locale() {
+            builder = builder.locale(locale);
+        }
+        builder
+    }
+
+    /// Builds the API for the configured patch, or the patch from the last
+    /// session, or the latest one.
+    pub fn load_api(config: &Config) -> anyhow::Result<UggApi> {
+        let api = Self::api_builder(config).build()?;
+        let pinned = config.settings().patch.as_deref();
+        let Some(patch) = pinned.or(config.session().patch.as_deref()) else {
+            return Ok(api);
+        };
+
+        let ugg_patch = patch.replace('.', "_");
+        let Some(version) = api.allowed_versions.iter().find(|v| v.ugg == ugg_patch) else {
+            // A patch from the last session may have since lost its data.
+            return if pinned.is_some() {
+                Err(anyhow!("no build data is available for patch {patch}"))
+            } else {
+                Ok(api)
+            };
+        };
+        if version.ddragon == api.current_version {
+            Ok(api)
+        } else {
+            Ok(Self::api_builder(config)
+                .version(&version.ddragon)
+                .build()?)
+        }
     }
 
-    pub fn new() -> anyhow::Result<Self> {
-        let config = Config::new()?;
-        let api = UggApiBuilder::new().cache_dir(config.cache()).build()?;
-        Ok(Self::create(api))
+    pub fn new_with_version(config: Config, keymap: Keymap, version: &str) -> anyhow::Result<Self> {
+        let api = Self::api_builder(&config).version(version).build()?;
+        Self::create(api, config, keymap)
+    }
+
+    pub fn new(config: Config, keymap: Keymap) -> anyhow::Result<Self> {
+        let api = Self::load_api(&config)?;
+        let mut app_context = Self::create(api, config, keymap)?;
+        app_context.restore_champion();
+        Ok(app_context)
     }
 
     pub fn update_champ_list(&mut self) {
-        (self.list_indices, self.champ_list) = self
+        self.load_champ_rankings();
+        let query = self.input.value().to_lowercase();
+        let mut matches = self
             .champ_data
             .iter()
             .filter(|(_, c)| {
-                c.name
-                    .to_lowercase()
-                    .contains(&self.input.value().to_lowercase())
+                c.name.to_lowercase().contains(&query)
+                    || self
+                        .api
+                        .champ_names_en
+                        .get(&c.id)
+                        .is_some_and(|n| n.to_lowercase().contains(&query))
             })
-            .map(|(i, c)| (i, ListItem::new(c.name.clone())))
+            .filter(|(_, c)| self.matches_champ_filters(c))
+            .collect::<Vec<_>>();
+        // Favorites are pinned to the top, the rest stay alphabetical.
+        matches.sort_by_key(|(_, c)| self.favorite_rank(c));
+        (self.list_indices, self.champ_list) = matches
+            .into_iter()
+            .map(|(i, c)| (*i, self.champ_list_item(c)))
             .unzip();
     }
 
+    pub fn toggle_build_sort(&mut self) {
+        self.build_sort = self.build_sort.toggle();
+    }
+
     pub fn return_to_initial(&mut self, reset_champ_scroll: bool) {
         self.state = State::Initial;
         if reset_champ_scroll {
@@ -156,14 +371,72 @@ impl AppContext<'_> {
         }
     }
 
+    /// Picks up the League client starting, restarting or closing.
+    pub fn update_client(&mut self) {
+        if let Some(client) = &mut self.client
+            && client.update()
+        {
+            if client.is_connected() {
+                log::info!("Connected to the League client");
+            } else {
+                log::info!("Disconnected from the League client");
+            }
+        }
+    }
+
+    /// Opens one of the list popups, starting from the current selection.
+    pub fn open_popup(&mut self, state: State) {
+        self.state = state;
+        match state {
+            State::ModeSelect => {
+                self.mode_scroll_pos = Some(self.mode_scroll_pos.unwrap_or_default());
+            }
+            State::VersionSelect => {
+                self.version_scroll_pos = Some(self.version_scroll_pos.unwrap_or_default());
+            }
+            State::RegionSelect => self.match_pos_to_region(),
+            State::RoleSelect => self.match_pos_to_role(),
+            State::PoolSelect => self.pool_scroll_pos = self.active_pool().map(|p| (p, 0)),
+            State::CacheMaintenance => {
+                self.load_cache_usage();
+                self.cache_scroll_pos = Some(0);
+            }
+            State::BuildSelect => {
+                self.build_scroll_pos = Some(self.build_scroll_pos.unwrap_or_default());
+            }
+            _ => {}
+        }
+    }
+
     pub fn select_champion(&mut self, champ: &ChampionShort) {
         self.champ_scroll_pos = None;
+        self.item_scroll_pos = None;
+        self.rune_scroll_pos = None;
         self.selected_champ = Some(champ.clone());
-        (self.selected_champ_overview, self.selected_champ_role) = self
+        let mut details_error = None;
+        if self
+            .selected_champ_details
+            .as_ref()
+            .is_none_or(|d| d.id != champ.id)
+        {
+            self.selected_champ_details = self
+                .api
+                .get_champion(champ)
+                .map_err(|e| {
+                    log::error!("Failed to load details for {}: {e:?}", champ.name);
+                    details_error = Some(e);
+                })
+                .ok();
+        }
+        let stats = self
             .api
-            .get_stats(champ, self.role, self.region, self.mode, self.build)
-            .ok()
-            .transpose();
+            .get_stats(champ, self.role, self.region, self.mode, self.build);
+        self.last_error = stats.as_ref().err().map(|e| {
+            log::error!("Failed to load {}: {e:?}", champ.name);
+            status_bar::describe_error(e, &champ.name, self.mode, &self.version)
+        });
+        (self.selected_champ_overview, self.selected_champ_role) = stats.ok().transpose();
+        let mut matchups_error = None;
         if self.mode == Mode::ARAM || self.mode == Mode::Arena {
             self.selected_champ_matchups = None;
         } else {
@@ -171,11 +444,28 @@ impl AppContext<'_> {
                 .api
                 .get_matchups(champ, self.role, self.region, self.mode)
                 .map(|v| v.0)
+                .map_err(|e| {
+                    log::error!("Failed to load matchups for {}: {e:?}", champ.name);
+                    matchups_error = Some(e);
+                })
                 .ok();
         }
+        // The build itself failing matters most, so only mention these when
+        // it loaded.
+        if self.last_error.is_none()
+            && let Some(e) = details_error.or(matchups_error)
+        {
+            self.last_error = Some(status_bar::describe_error(
+                &e,
+                &champ.name,
+                self.mode,
+                &self.version,
+            ));
+        }
 
-        if let Some(Overview::Default(ref overview)) = self.selected_champ_overview
-            && let Some(ref api) = self.client_api
+        if self.import_runes
+            && let Some(Overview::Default(ref overview)) = self.selected_champ_overview
+            && let Some(api) = self.client.as_ref().and_then(ClientWatcher::client)
             && let Some(data) = api.get_current_rune_page()
         {
             let (primary_style_id, sub_style_id, selected_perk_ids) = util::generate_perk_array(
@@ -193,6 +483,11 @@ impl AppContext<'_> {
             );
         }
 
+        // Small windows show one section at a time; jump straight to the build.
+        if self.compact_page == CompactPage::Champions {
+            self.compact_page = CompactPage::Runes;
+        }
+
         self.state = State::ChampSelected;
     }
 
@@ -1,6 +1,12 @@
-use ratatui::crossterm::event::{self, Event, KeyCode, KeyModifiers};
+use ratatui::{
+    crossterm::event::{
+        self, Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+    },
+    layout::Position,
+};
 
 use crate::context::{AppContext, State};
+use crate::keymap::Action;
 
 const fn keycode_to_logger_event(key: &event::KeyEvent) -> Option<tui_logger::TuiWidgetEvent> {
     match key.code {
@@ -21,143 +27,297 @@ const fn keycode_to_logger_event(key: &event::KeyEvent) -> Option<tui_logger::Tu
 }
 
 pub fn handle_events(ctx: &mut AppContext) -> anyhow::Result<bool> {
-    if event::poll(std::time::Duration::from_millis(50))?
-        && let Event::Key(key) = event::read()?
-    {
-        // Ignore release events to fix undesired double-input issues.
-        // https://github.com/ratatui-org/ratatui/issues/347
-        if key.kind == event::KeyEventKind::Release {
-            return Ok(false);
+    if event::poll(std::time::Duration::from_millis(50))? {
+        match event::read()? {
+            Event::Key(key) => return handle_key(ctx, key),
+            Event::Mouse(mouse) => return handle_mouse(ctx, mouse),
+            _ => {}
         }
+    }
+    Ok(false)
+}
+
+fn handle_key(ctx: &mut AppContext, key: KeyEvent) -> anyhow::Result<bool> {
+    // Ignore release events to fix undesired double-input issues.
+    // https://github.com/ratatui-org/ratatui/issues/347
+    if key.kind == event::KeyEventKind::Release {
+        return Ok(false);
+    }
+
+    if key.kind == event::KeyEventKind::Press && ctx.keymap.is(Action::Quit, &key) {
+        return Ok(true);
+    }
+
+    // Using the keyboard takes over from a detail popup opened by hovering.
+    ctx.hover_detail = false;
+
+    let key = match ctx.state {
+        State::Initial | State::ChampSelected | State::TextInput | State::Logger => key,
+        State::MatchupList => ctx.keymap.navigation(key, true),
+        _ => ctx.keymap.navigation(key, false),
+    };
+    dispatch(ctx, key)
+}
 
-        if key.kind == event::KeyEventKind::Press
-            && key.code == KeyCode::Char('q')
-            && key.modifiers.contains(KeyModifiers::CONTROL)
+/// Champion names have no digits, so 1 to 9 on the main screen pick from
+/// the active pool rather than starting a search.
+fn pool_number(key: &KeyEvent) -> Option<usize> {
+    match key.code {
+        KeyCode::Char(c @ '1'..='9')
+            if !key
+                .modifiers
+                .intersects(KeyModifiers::ALT | KeyModifiers::CONTROL) =>
         {
-            return Ok(true);
+            c.to_digit(10).map(|d| d as usize)
         }
+        _ => None,
+    }
+}
 
-        match ctx.state {
-            State::ChampSelected | State::Initial => {
-                if key.modifiers.contains(KeyModifiers::ALT) {
-                    match key.code {
-                        KeyCode::Char('s') => {
-                            ctx.state = State::TextInput;
-                            ctx.show_left_pane = true;
-                        }
-                        KeyCode::Char('c') => {
-                            ctx.state = State::ChampScroll;
-                            ctx.show_left_pane = true;
-                            if !ctx.champ_list.is_empty() {
-                                ctx.champ_scroll_pos = Some(0);
-                            }
-                        }
-                        KeyCode::Char('m') => {
-                            ctx.state = State::ModeSelect;
-                            ctx.mode_scroll_pos = Some(ctx.mode_scroll_pos.unwrap_or_default());
-                        }
-                        KeyCode::Char('v') => {
-                            ctx.state = State::VersionSelect;
-                            ctx.version_scroll_pos =
-                                Some(ctx.version_scroll_pos.unwrap_or_default());
-                        }
-                        KeyCode::Char('w') => {
-                            ctx.state = State::RegionSelect;
-                            ctx.match_pos_to_region();
-                        }
-                        KeyCode::Char('r') => {
-                            ctx.state = State::RoleSelect;
-                            ctx.match_pos_to_role();
-                        }
-                        KeyCode::Char('b') => {
-                            ctx.state = State::BuildSelect;
-                            ctx.build_scroll_pos = Some(ctx.build_scroll_pos.unwrap_or_default());
-                        }
-                        KeyCode::Char('h') => {
-                            ctx.show_left_pane = !ctx.show_left_pane;
-                        }
-                        KeyCode::Char('l') => {
-                            ctx.state = State::Logger;
-                        }
-                        _ => {}
-                    }
-                } else {
-                    match key.code {
-                        KeyCode::Char('?') => {
-                            ctx.state = State::HelpMenu;
-                        }
-                        KeyCode::Esc | KeyCode::Enter => {}
-                        _ => {
-                            ctx.state = State::TextInput;
-                            ctx.show_left_pane = true;
-                            ctx.on_search_keypress(key);
-                        }
-                    }
-                }
+fn run_shortcut(ctx: &mut AppContext, action: Action) {
+    match action {
+        Action::Search => {
+            ctx.state = State::TextInput;
+            ctx.show_left_pane = true;
+        }
+        Action::ChampSelect => {
+            ctx.state = State::ChampScroll;
+            ctx.show_left_pane = true;
+            if !ctx.champ_list.is_empty() {
+                ctx.champ_scroll_pos = Some(0);
             }
-            State::TextInput => match key.code {
-                KeyCode::Esc => ctx.return_to_initial(true),
-                KeyCode::Enter => ctx.on_search_submit(),
-                KeyCode::Backspace => ctx.on_search_backspace(key),
-                _ => ctx.on_search_keypress(key),
-            },
-            State::ChampScroll => match key.code {
-                KeyCode::Esc => ctx.return_to_initial(true),
-                KeyCode::Up => ctx.prev_champ(),
-                KeyCode::Down => ctx.next_champ(),
-                KeyCode::Enter => ctx.select_champ(),
-                KeyCode::Char('s') => ctx.go_to_search(),
-                _ => {}
-            },
-            State::ModeSelect => match key.code {
-                KeyCode::Esc => ctx.return_to_initial(false),
-                KeyCode::Up => ctx.prev_mode(),
-                KeyCode::Down => ctx.next_mode(),
-                KeyCode::Enter => ctx.select_mode(),
-                _ => {}
-            },
-            State::VersionSelect => match key.code {
-                KeyCode::Esc => ctx.return_to_initial(false),
-                KeyCode::Up => ctx.prev_version(),
-                KeyCode::Down => ctx.next_version(),
-                KeyCode::Enter => ctx.select_version()?,
-                _ => {}
-            },
-            State::RegionSelect => match key.code {
-                KeyCode::Esc => ctx.return_to_initial(false),
-                KeyCode::Up => ctx.prev_region(),
-                KeyCode::Down => ctx.next_region(),
-                KeyCode::Enter => ctx.select_region(),
-                _ => {}
-            },
-            State::RoleSelect => match key.code {
-                KeyCode::Esc => ctx.return_to_initial(false),
-                KeyCode::Up => ctx.prev_role(),
-                KeyCode::Down => ctx.next_role(),
-                KeyCode::Enter => ctx.select_role(),
-                _ => {}
-            },
-            State::BuildSelect => match key.code {
-                KeyCode::Esc => ctx.return_to_initial(false),
-                KeyCode::Up => ctx.prev_build(),
-                KeyCode::Down => ctx.next_build(),
-                KeyCode::Enter => ctx.select_build(),
-                _ => {}
-            },
-            State::HelpMenu => {
-                if let KeyCode::Esc = key.code {
-                    ctx.return_to_initial(false);
-                }
+        }
+        Action::ModeSelect => ctx.open_popup(State::ModeSelect),
+        Action::VersionSelect => ctx.open_popup(State::VersionSelect),
+        Action::RegionSelect => ctx.open_popup(State::RegionSelect),
+        Action::RoleSelect => ctx.open_popup(State::RoleSelect),
+        Action::BuildSelect => ctx.open_popup(State::BuildSelect),
+        Action::Pools => ctx.open_popup(State::PoolSelect),
+        Action::ItemDetails => ctx.focus_items(),
+        Action::AbilityDetails => ctx.show_ability_detail(),
+        Action::RuneDetails => ctx.focus_runes(),
+        Action::TierList => ctx.open_tier_list(),
+        Action::Matchups => ctx.open_matchup_list(),
+        Action::CounterPicks => ctx.open_counter_picks(),
+        Action::PinBuild => ctx.pin_comparison(),
+        Action::Compare => ctx.open_comparison(),
+        Action::RoleFilter => ctx.cycle_champ_role_filter(),
+        Action::ClassFilter => ctx.cycle_champ_class_filter(),
+        Action::ToggleWinRates => ctx.toggle_champ_win_rates(),
+        Action::ToggleSort => ctx.toggle_build_sort(),
+        Action::HideLeftPane => ctx.show_left_pane = !ctx.show_left_pane,
+        Action::LogViewer => ctx.state = State::Logger,
+        Action::Help => ctx.show_help(),
+        Action::Retry => ctx.retry(),
+        Action::Cache => ctx.open_popup(State::CacheMaintenance),
+        Action::NextSection => ctx.next_compact_page(),
+        Action::PrevSection => ctx.prev_compact_page(),
+        Action::Up
+        | Action::Down
+        | Action::Left
+        | Action::Right
+        | Action::Back
+        | Action::Select
+        | Action::ReverseSort
+        | Action::SwitchCandidates
+        | Action::Quit => {}
+    }
+}
+
+fn dispatch(ctx: &mut AppContext, key: KeyEvent) -> anyhow::Result<bool> {
+    match ctx.state {
+        State::ChampSelected | State::Initial => {
+            if let Some(action) = ctx.keymap.shortcut(&key) {
+                run_shortcut(ctx, action);
+            } else if let Some(number) = pool_number(&key) {
+                ctx.quick_switch(number);
+            } else if !key
+                .modifiers
+                .intersects(KeyModifiers::ALT | KeyModifiers::CONTROL)
+                && !matches!(key.code, KeyCode::Esc | KeyCode::Enter)
+            {
+                ctx.state = State::TextInput;
+                ctx.show_left_pane = true;
+                ctx.on_search_keypress(key);
+            }
+        }
+        State::TextInput => match key.code {
+            KeyCode::Esc => ctx.return_to_initial(true),
+            KeyCode::Enter => ctx.on_search_submit(),
+            KeyCode::Backspace => ctx.on_search_backspace(key),
+            _ => ctx.on_search_keypress(key),
+        },
+        State::ChampScroll => match key.code {
+            KeyCode::Esc => ctx.return_to_initial(true),
+            KeyCode::Up => ctx.prev_champ(),
+            KeyCode::Down => ctx.next_champ(),
+            KeyCode::Enter => ctx.select_champ(),
+            _ if ctx.keymap.is_in_popup(Action::Search, &key, false) => ctx.go_to_search(),
+            _ => {}
+        },
+        State::ModeSelect => match key.code {
+            KeyCode::Esc => ctx.return_to_initial(false),
+            KeyCode::Up => ctx.prev_mode(),
+            KeyCode::Down => ctx.next_mode(),
+            KeyCode::Enter => ctx.select_mode(),
+            _ => {}
+        },
+        State::VersionSelect => match key.code {
+            KeyCode::Esc => ctx.return_to_initial(false),
+            KeyCode::Up => ctx.prev_version(),
+            KeyCode::Down => ctx.next_version(),
+            KeyCode::Enter => ctx.select_version()?,
+            _ => {}
+        },
+        State::RegionSelect => match key.code {
+            KeyCode::Esc => ctx.return_to_initial(false),
+            KeyCode::Up => ctx.prev_region(),
+            KeyCode::Down => ctx.next_region(),
+            KeyCode::Enter => ctx.select_region(),
+            _ => {}
+        },
+        State::RoleSelect => match key.code {
+            KeyCode::Esc => ctx.return_to_initial(false),
+            KeyCode::Up => ctx.prev_role(),
+            KeyCode::Down => ctx.next_role(),
+            KeyCode::Enter => ctx.select_role(),
+            _ => {}
+        },
+        State::BuildSelect => match key.code {
+            KeyCode::Esc => ctx.return_to_initial(false),
+            KeyCode::Up => ctx.prev_build(),
+            KeyCode::Down => ctx.next_build(),
+            KeyCode::Enter => ctx.select_build(),
+            _ => {}
+        },
+        State::PoolSelect => match key.code {
+            KeyCode::Esc => ctx.return_to_initial(false),
+            KeyCode::Up => ctx.prev_pool_champ(),
+            KeyCode::Down => ctx.next_pool_champ(),
+            KeyCode::Left => ctx.prev_pool(),
+            KeyCode::Right => ctx.next_pool(),
+            KeyCode::Enter => ctx.select_pool_entry(),
+            KeyCode::Char(c @ '1'..='9') if ctx.set_pool_pos(c as usize - '1' as usize) => {
+                ctx.select_pool_entry();
+            }
+            _ => {}
+        },
+        State::CacheMaintenance => match key.code {
+            KeyCode::Esc => ctx.return_to_initial(false),
+            KeyCode::Up => ctx.prev_cache_action(),
+            KeyCode::Down => ctx.next_cache_action(),
+            KeyCode::Left => ctx.keep_fewer_patches(),
+            KeyCode::Right => ctx.keep_more_patches(),
+            KeyCode::Enter => ctx.run_cache_action(),
+            _ => {}
+        },
+        State::ItemSelect => match key.code {
+            KeyCode::Esc => ctx.leave_items(),
+            KeyCode::Up => ctx.prev_item(),
+            KeyCode::Down => ctx.next_item(),
+            KeyCode::Left => ctx.prev_item_column(),
+            KeyCode::Right => ctx.next_item_column(),
+            KeyCode::Enter => ctx.show_item_detail(),
+            _ => {}
+        },
+        State::ItemDetail => {
+            if let KeyCode::Esc | KeyCode::Enter = key.code {
+                ctx.state = State::ItemSelect;
+            }
+        }
+        State::RuneSelect => match key.code {
+            KeyCode::Esc => ctx.leave_runes(),
+            KeyCode::Up => ctx.prev_rune(),
+            KeyCode::Down => ctx.next_rune(),
+            KeyCode::Left | KeyCode::Right => ctx.switch_rune_path(),
+            KeyCode::Enter => ctx.show_rune_detail(),
+            _ => {}
+        },
+        State::RuneDetail => {
+            if let KeyCode::Esc | KeyCode::Enter = key.code {
+                ctx.state = State::RuneSelect;
             }
-            State::Logger => match key.code {
-                KeyCode::Char('q') => ctx.return_to_initial(false),
-                _ => {
-                    if let Some(event) = keycode_to_logger_event(&key) {
-                        ctx.logger_state.transition(event);
-                    }
-                }
-            },
         }
+        State::TierList => match key.code {
+            KeyCode::Esc => ctx.return_to_initial(false),
+            KeyCode::Up => ctx.prev_tier_list_entry(),
+            KeyCode::Down => ctx.next_tier_list_entry(),
+            KeyCode::Left => ctx.prev_tier_list_sort(),
+            KeyCode::Right => ctx.next_tier_list_sort(),
+            KeyCode::Enter => ctx.select_tier_list_entry(),
+            _ if ctx.keymap.is_in_popup(Action::ReverseSort, &key, false) => {
+                ctx.reverse_tier_list();
+            }
+            _ => {}
+        },
+        State::MatchupList => match key.code {
+            KeyCode::Esc => ctx.leave_matchup_list(),
+            KeyCode::Up => ctx.prev_matchup_list_entry(),
+            KeyCode::Down => ctx.next_matchup_list_entry(),
+            KeyCode::Left => ctx.prev_matchup_list_sort(),
+            KeyCode::Right => ctx.next_matchup_list_sort(),
+            KeyCode::Enter => ctx.select_matchup_list_entry(),
+            _ if ctx.keymap.is_in_popup(Action::ReverseSort, &key, true) => {
+                ctx.reverse_matchup_list();
+            }
+            _ => ctx.on_matchup_filter_key(key),
+        },
+        State::CounterPicks => match key.code {
+            KeyCode::Esc => ctx.state = State::ChampSelected,
+            KeyCode::Up => ctx.prev_counter_pick(),
+            KeyCode::Down => ctx.next_counter_pick(),
+            KeyCode::Enter => ctx.select_counter_pick(),
+            _ if ctx
+                .keymap
+                .is_in_popup(Action::SwitchCandidates, &key, false) =>
+            {
+                ctx.toggle_counter_pick_candidates();
+            }
+            _ => {}
+        },
+        State::Compare => match key.code {
+            KeyCode::Esc | KeyCode::Enter => ctx.state = State::ChampSelected,
+            _ if ctx.keymap.is_in_popup(Action::PinBuild, &key, false) => ctx.pin_comparison(),
+            _ => {}
+        },
+        State::AbilityDetail => match key.code {
+            KeyCode::Esc | KeyCode::Enter => ctx.return_to_initial(false),
+            KeyCode::Up => ctx.scroll_ability_detail_up(),
+            KeyCode::Down => ctx.scroll_ability_detail_down(),
+            _ => {}
+        },
+        State::HelpMenu => match key.code {
+            KeyCode::Esc => ctx.return_to_initial(false),
+            KeyCode::Up => ctx.scroll_help_up(),
+            KeyCode::Down => ctx.scroll_help_down(),
+            _ => {}
+        },
+        State::Logger => match key.code {
+            KeyCode::Char('q') => ctx.return_to_initial(false),
+            _ => {
+                if let Some(event) = keycode_to_logger_event(&key) {
+                    ctx.logger_state.transition(event);
+                }
+            }
+        },
     }
     Ok(false)
 }
+
+fn handle_mouse(ctx: &mut AppContext, mouse: MouseEvent) -> anyhow::Result<bool> {
+    let position = Position::new(mouse.column, mouse.row);
+    match mouse.kind {
+        MouseEventKind::ScrollDown => return ctx.on_mouse_scroll(position, true),
+        MouseEventKind::ScrollUp => return ctx.on_mouse_scroll(position, false),
+        MouseEventKind::Moved => ctx.on_mouse_hover(position),
+        MouseEventKind::Down(MouseButton::Left) => return ctx.on_mouse_click(position),
+        _ => {}
+    }
+    Ok(false)
+}
+
+/// Feeds a key press through the keyboard handler, so that mouse actions
+/// behave exactly like their shortcuts.
+pub fn press(ctx: &mut AppContext, code: KeyCode) -> anyhow::Result<bool> {
+    dispatch(ctx, KeyEvent::new(code, KeyModifiers::NONE))
+}
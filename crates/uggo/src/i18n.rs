@@ -0,0 +1,611 @@
+use std::sync::OnceLock;
+
+static LANGUAGE: OnceLock<Language> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Language {
+    #[default]
+    English,
+    Korean,
+    Portuguese,
+}
+
+impl Language {
+    #[must_use]
+    pub fn from_locale(locale: &str) -> Self {
+        match locale.split('_').next().unwrap_or_default() {
+            "ko" => Self::Korean,
+            "pt" => Self::Portuguese,
+            _ => Self::English,
+        }
+    }
+}
+
+/// Sets the language used for UI text. Only the first call has any effect.
+pub fn set_language(language: Language) {
+    let _ = LANGUAGE.set(language);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Text {
+    // Help menu
+    Search,
+    ChampSelect,
+    ModeSelect,
+    RoleSelect,
+    VersionSelect,
+    RegionSelect,
+    BuildSelect,
+    HideLeftPane,
+    LogViewer,
+    ExitLogViewer,
+    Back,
+    Send,
+    ReverseSort,
+    SwitchCandidates,
+    Quit,
+    Help,
+    RuneDetails,
+    ItemDetails,
+    ToggleSort,
+    TierList,
+    Matchups,
+    CounterPicks,
+    PinBuild,
+    Compare,
+    RoleFilter,
+    ClassFilter,
+    ToggleWinRates,
+    AbilityDetails,
+    NextSection,
+    PrevSection,
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Pools,
+    PickFromPool,
+    ManageCache,
+
+    // Pane titles
+    RunePaths,
+    ShardsAndSpells,
+    AbilityOrder,
+    Items,
+    StartingItems,
+    CoreItems,
+    FourthItems,
+    FifthItems,
+    SixthItems,
+    SecondThirdItems,
+    PrismaticItems,
+    Augments,
+    SilverAugments,
+    GoldAugments,
+    PrismaticAugments,
+    ChampSynergies,
+    Champions,
+    GameMode,
+    GameVersion,
+    Region,
+    Role,
+    OverviewKind,
+    BestMatchups,
+    WorstMatchups,
+
+    // Labels
+    NoResults,
+    Selected,
+    Build,
+    Mode,
+    Patch,
+    LowSampleSize,
+    Spells,
+    TooSmall,
+    ShardOffense,
+    ShardFlex,
+    ShardDefense,
+    Total,
+    Cost,
+    SellsFor,
+    Tags,
+    BuildsFrom,
+    MaxOrder,
+    Passive,
+    Cooldown,
+    SlotAlternatives,
+    Champion,
+    Tier,
+    WinRate,
+    PickRate,
+    BanRate,
+    Matches,
+    TierListHint,
+    MatchupListHint,
+    CounterPicksHint,
+    AllChampions,
+    Loading,
+    VsAverage,
+    Pinned,
+    Current,
+    CompareHint,
+    SortBy,
+    Popularity,
+    Retry,
+    Rank,
+    Cache,
+    Cached,
+    Live,
+    Client,
+    Connected,
+    Disconnected,
+    Favorites,
+    NoPools,
+    SharedFiles,
+    Freed,
+    RemovedPatches,
+    PruneCache,
+    ClearCache,
+    CacheHint,
+
+    // Errors, with `{champ}`, `{mode}` and `{patch}` filled in
+    ErrorNoData,
+    ErrorTimedOut,
+    ErrorOffline,
+    ErrorBadData,
+    ErrorOther,
+
+    // Champion classes
+    Assassin,
+    Fighter,
+    Mage,
+    Marksman,
+    SupportClass,
+    Tank,
+
+    // Shard descriptions
+    ShardHealthScaling,
+    ShardArmor,
+    ShardMagicResist,
+    ShardAttackSpeed,
+    ShardAbilityHaste,
+    ShardAdaptiveForce,
+    ShardMoveSpeed,
+    ShardHealth,
+    ShardTenacity,
+    Unknown,
+}
+
+/// Returns the translation of `text` for the active language.
+pub fn tr(text: Text) -> &'static str {
+    match LANGUAGE.get().copied().unwrap_or_default() {
+        Language::English => english(text),
+        Language::Korean => korean(text),
+        Language::Portuguese => portuguese(text),
+    }
+}
+
+const fn english(text: Text) -> &'static str {
+    match text {
+        Text::Search => "Search",
+        Text::ChampSelect => "Champ Select",
+        Text::ModeSelect => "Mode Select",
+        Text::RoleSelect => "Role Select",
+        Text::VersionSelect => "Version Select",
+        Text::RegionSelect => "Region Select",
+        Text::BuildSelect => "Build Select",
+        Text::HideLeftPane => "Hide Left Pane",
+        Text::LogViewer => "Log Viewer",
+        Text::ExitLogViewer => "Exit Log Viewer",
+        Text::Back => "Back",
+        Text::Send => "Send",
+        Text::ReverseSort => "Reverse Sort",
+        Text::SwitchCandidates => "Switch Pool/All Champions",
+        Text::Quit => "Quit",
+        Text::Help => "Help",
+        Text::RuneDetails => "Rune Details",
+        Text::ItemDetails => "Item Details",
+        Text::ToggleSort => "Toggle Sort",
+        Text::TierList => "Tier List",
+        Text::Matchups => "Matchups",
+        Text::CounterPicks => "Counter Picks",
+        Text::PinBuild => "Pin for Comparison",
+        Text::Compare => "Compare with Pinned",
+        Text::RoleFilter => "Filter by Role",
+        Text::ClassFilter => "Filter by Class",
+        Text::ToggleWinRates => "Show Win Rates",
+        Text::AbilityDetails => "Ability Details",
+        Text::NextSection => "Next Section (Small Window)",
+        Text::PrevSection => "Previous Section (Small Window)",
+        Text::MoveUp => "Move Up",
+        Text::MoveDown => "Move Down",
+        Text::MoveLeft => "Move Left",
+        Text::MoveRight => "Move Right",
+        Text::Pools => "Champion Pools",
+        Text::PickFromPool => "Pick From Pool",
+        Text::ManageCache => "Manage Cache",
+        Text::RunePaths => "Rune Paths",
+        Text::ShardsAndSpells => "Shards & Spells",
+        Text::AbilityOrder => "Ability Order",
+        Text::Items => "Items",
+        Text::StartingItems => "Starting Items",
+        Text::CoreItems => "Core Items",
+        Text::FourthItems => "4th Items",
+        Text::FifthItems => "5th Items",
+        Text::SixthItems => "6th Items",
+        Text::SecondThirdItems => "2nd/3rd Items",
+        Text::PrismaticItems => "Prismatic Items",
+        Text::Augments => "Augments",
+        Text::SilverAugments => "Silver Augments",
+        Text::GoldAugments => "Gold Augments",
+        Text::PrismaticAugments => "Prismatic Augments",
+        Text::ChampSynergies => "Champ Synergies",
+        Text::Champions => "Champions",
+        Text::GameMode => "Game Mode",
+        Text::GameVersion => "Game Version",
+        Text::Region => "Region",
+        Text::Role => "Role",
+        Text::OverviewKind => "Overview Kind",
+        Text::BestMatchups => "Best Matchups",
+        Text::WorstMatchups => "Worst Matchups",
+        Text::NoResults => "No results :(",
+        Text::Selected => "Selected",
+        Text::Build => "Build",
+        Text::Mode => "Mode",
+        Text::Patch => "Patch",
+        Text::LowSampleSize => "Warning: Low Sample Size",
+        Text::Spells => "Spells:",
+        Text::TooSmall => "Please resize the window to at least 40x16! Ctrl+Q to exit.",
+        Text::ShardOffense => "Offense",
+        Text::ShardFlex => "Flex",
+        Text::ShardDefense => "Defense",
+        Text::Total => "Total",
+        Text::Cost => "Cost",
+        Text::SellsFor => "sells for",
+        Text::Tags => "Tags",
+        Text::BuildsFrom => "Builds From",
+        Text::MaxOrder => "Max Order",
+        Text::Passive => "Passive",
+        Text::Cooldown => "Cooldown",
+        Text::SlotAlternatives => "Other runes in this slot",
+        Text::Champion => "Champion",
+        Text::Tier => "Tier",
+        Text::WinRate => "Win Rate",
+        Text::PickRate => "Pick Rate",
+        Text::BanRate => "Ban Rate",
+        Text::Matches => "Matches",
+        Text::TierListHint => "{left}/{right} Sort, {reverse_sort} Reverse, {select} Open",
+        Text::MatchupListHint => {
+            "Type to Filter, {left}/{right} Sort, {reverse_sort} Reverse, {select} Open"
+        }
+        Text::CounterPicksHint => "{switch_candidates} Pool/All Champions, {select} Open",
+        Text::AllChampions => "All Champions",
+        Text::Loading => "Loading…",
+        Text::VsAverage => "vs Avg",
+        Text::Pinned => "Pinned",
+        Text::Current => "Current",
+        Text::CompareHint => "Highlighted Entries Differ, {pin_build} Pin Current, {back} Back",
+        Text::SortBy => "Sort",
+        Text::Popularity => "Popularity",
+        Text::Retry => "Retry",
+        Text::Rank => "Rank",
+        Text::Cache => "Cache",
+        Text::Cached => "cached",
+        Text::Live => "live",
+        Text::Client => "Client",
+        Text::Connected => "connected",
+        Text::Disconnected => "disconnected",
+        Text::Favorites => "Favorites",
+        Text::NoPools => "Add favorites or pools to config.toml",
+        Text::SharedFiles => "Shared by All Patches",
+        Text::Freed => "Freed",
+        Text::RemovedPatches => "Removed Patches",
+        Text::PruneCache => "Prune, Patches to Keep",
+        Text::ClearCache => "Clear Everything",
+        Text::CacheHint => "{left}/{right} Patches to Keep, {select} Run",
+        Text::ErrorNoData => "No {mode} data for {champ} on patch {patch}",
+        Text::ErrorTimedOut => "u.gg timed out loading {champ}",
+        Text::ErrorOffline => "Couldn't reach u.gg, check your connection",
+        Text::ErrorBadData => "u.gg sent {champ} data that couldn't be read",
+        Text::ErrorOther => "Couldn't load {champ}",
+        Text::Assassin => "Assassin",
+        Text::Fighter => "Fighter",
+        Text::Mage => "Mage",
+        Text::Marksman => "Marksman",
+        Text::SupportClass => "Support",
+        Text::Tank => "Tank",
+        Text::ShardHealthScaling => "+10-180 Health",
+        Text::ShardArmor => "+6 Armor",
+        Text::ShardMagicResist => "+8 Magic Resist",
+        Text::ShardAttackSpeed => "+10% Attack Speed",
+        Text::ShardAbilityHaste => "+8 Ability Haste",
+        Text::ShardAdaptiveForce => "+9 Adaptive Force",
+        Text::ShardMoveSpeed => "+2% Move Speed",
+        Text::ShardHealth => "65 Health",
+        Text::ShardTenacity => "+10% Tenacity and Slow Resist",
+        Text::Unknown => "Unknown",
+    }
+}
+
+const fn korean(text: Text) -> &'static str {
+    match text {
+        Text::Search => "검색",
+        Text::ChampSelect => "챔피언 선택",
+        Text::ModeSelect => "모드 선택",
+        Text::RoleSelect => "포지션 선택",
+        Text::VersionSelect => "버전 선택",
+        Text::RegionSelect => "지역 선택",
+        Text::BuildSelect => "빌드 선택",
+        Text::HideLeftPane => "왼쪽 패널 숨기기",
+        Text::LogViewer => "로그 보기",
+        Text::ExitLogViewer => "로그 보기 종료",
+        Text::Back => "뒤로",
+        Text::Send => "확인",
+        Text::ReverseSort => "정렬 역순",
+        Text::SwitchCandidates => "폭/전체 챔피언 전환",
+        Text::Quit => "종료",
+        Text::Help => "도움말",
+        Text::RuneDetails => "룬 정보",
+        Text::ItemDetails => "아이템 정보",
+        Text::ToggleSort => "정렬 전환",
+        Text::TierList => "티어 목록",
+        Text::Matchups => "상대 전적",
+        Text::CounterPicks => "카운터 픽",
+        Text::PinBuild => "비교용으로 고정",
+        Text::Compare => "고정된 빌드와 비교",
+        Text::RoleFilter => "포지션 필터",
+        Text::ClassFilter => "역할군 필터",
+        Text::ToggleWinRates => "승률 표시",
+        Text::AbilityDetails => "스킬 정보",
+        Text::NextSection => "다음 섹션 (작은 창)",
+        Text::PrevSection => "이전 섹션 (작은 창)",
+        Text::MoveUp => "위로 이동",
+        Text::MoveDown => "아래로 이동",
+        Text::MoveLeft => "왼쪽으로 이동",
+        Text::MoveRight => "오른쪽으로 이동",
+        Text::Pools => "챔피언 폭",
+        Text::PickFromPool => "폭에서 선택",
+        Text::ManageCache => "캐시 관리",
+        Text::RunePaths => "룬",
+        Text::ShardsAndSpells => "파편 및 주문",
+        Text::AbilityOrder => "스킬 순서",
+        Text::Items => "아이템",
+        Text::StartingItems => "시작 아이템",
+        Text::CoreItems => "핵심 아이템",
+        Text::FourthItems => "4번째 아이템",
+        Text::FifthItems => "5번째 아이템",
+        Text::SixthItems => "6번째 아이템",
+        Text::SecondThirdItems => "2/3번째 아이템",
+        Text::PrismaticItems => "프리즘 아이템",
+        Text::Augments => "증강",
+        Text::SilverAugments => "실버 증강",
+        Text::GoldAugments => "골드 증강",
+        Text::PrismaticAugments => "프리즘 증강",
+        Text::ChampSynergies => "챔피언 시너지",
+        Text::Champions | Text::Champion => "챔피언",
+        Text::GameMode => "게임 모드",
+        Text::GameVersion => "게임 버전",
+        Text::Region => "지역",
+        Text::Role => "포지션",
+        Text::OverviewKind => "빌드 종류",
+        Text::BestMatchups => "유리한 상대",
+        Text::WorstMatchups => "불리한 상대",
+        Text::NoResults => "결과 없음 :(",
+        Text::Selected => "선택됨",
+        Text::Build => "빌드",
+        Text::Mode => "모드",
+        Text::Patch => "패치",
+        Text::LowSampleSize => "경고: 표본 수 부족",
+        Text::Spells => "주문:",
+        Text::TooSmall => "창 크기를 최소 40x16으로 조정하세요! Ctrl+Q로 종료.",
+        Text::ShardOffense => "공격",
+        Text::ShardFlex => "적응",
+        Text::ShardDefense => "방어",
+        Text::Total => "합계",
+        Text::Cost => "가격",
+        Text::SellsFor => "판매가",
+        Text::Tags => "분류",
+        Text::BuildsFrom => "하위 아이템",
+        Text::MaxOrder => "스킬 마스터 순서",
+        Text::Passive => "패시브",
+        Text::Cooldown => "재사용 대기시간",
+        Text::SlotAlternatives => "같은 슬롯의 다른 룬",
+        Text::Tier => "티어",
+        Text::WinRate => "승률",
+        Text::PickRate => "픽률",
+        Text::BanRate => "밴률",
+        Text::Matches => "게임 수",
+        Text::TierListHint => "{left}/{right} 정렬, {reverse_sort} 역순, {select} 열기",
+        Text::MatchupListHint => {
+            "입력하여 필터, {left}/{right} 정렬, {reverse_sort} 역순, {select} 열기"
+        }
+        Text::CounterPicksHint => "{switch_candidates} 폭/전체 챔피언, {select} 열기",
+        Text::AllChampions => "전체 챔피언",
+        Text::Loading => "불러오는 중…",
+        Text::VsAverage => "평균 대비",
+        Text::Pinned => "고정됨",
+        Text::Current => "현재",
+        Text::CompareHint => "강조된 항목이 다름, {pin_build} 현재 빌드 고정, {back} 뒤로",
+        Text::SortBy => "정렬",
+        Text::Popularity => "인기순",
+        Text::Retry => "다시 시도",
+        Text::Rank => "랭크",
+        Text::Cache => "캐시",
+        Text::Cached => "캐시됨",
+        Text::Live => "실시간",
+        Text::Client => "클라이언트",
+        Text::Connected => "연결됨",
+        Text::Disconnected => "연결 끊김",
+        Text::Favorites => "즐겨찾기",
+        Text::NoPools => "config.toml에 즐겨찾기나 챔피언 폭을 추가하세요",
+        Text::SharedFiles => "모든 패치 공용",
+        Text::Freed => "확보한 용량",
+        Text::RemovedPatches => "삭제한 패치",
+        Text::PruneCache => "정리, 유지할 패치 수",
+        Text::ClearCache => "모두 삭제",
+        Text::CacheHint => "{left}/{right} 유지할 패치 수, {select} 실행",
+        Text::ErrorNoData => "패치 {patch}에 {champ}의 {mode} 데이터가 없습니다",
+        Text::ErrorTimedOut => "{champ} 불러오기 중 u.gg 응답 시간 초과",
+        Text::ErrorOffline => "u.gg에 연결할 수 없습니다. 네트워크를 확인하세요",
+        Text::ErrorBadData => "u.gg에서 받은 {champ} 데이터를 읽을 수 없습니다",
+        Text::ErrorOther => "{champ}을(를) 불러올 수 없습니다",
+        Text::Assassin => "암살자",
+        Text::Fighter => "전사",
+        Text::Mage => "마법사",
+        Text::Marksman => "원거리 딜러",
+        Text::SupportClass => "서포터",
+        Text::Tank => "탱커",
+        Text::ShardHealthScaling => "+10-180 체력",
+        Text::ShardArmor => "+6 방어력",
+        Text::ShardMagicResist => "+8 마법 저항력",
+        Text::ShardAttackSpeed => "+10% 공격 속도",
+        Text::ShardAbilityHaste => "+8 스킬 가속",
+        Text::ShardAdaptiveForce => "+9 적응형 능력치",
+        Text::ShardMoveSpeed => "+2% 이동 속도",
+        Text::ShardHealth => "65 체력",
+        Text::ShardTenacity => "+10% 강인함 및 둔화 저항",
+        Text::Unknown => "알 수 없음",
+    }
+}
+
+const fn portuguese(text: Text) -> &'static str {
+    match text {
+        Text::Search => "Buscar",
+        Text::ChampSelect => "Selecionar Campeão",
+        Text::ModeSelect => "Selecionar Modo",
+        Text::RoleSelect => "Selecionar Rota",
+        Text::VersionSelect => "Selecionar Versão",
+        Text::RegionSelect => "Selecionar Região",
+        Text::BuildSelect => "Selecionar Build",
+        Text::HideLeftPane => "Ocultar Painel Esquerdo",
+        Text::LogViewer => "Visualizar Logs",
+        Text::ExitLogViewer => "Sair dos Logs",
+        Text::Back => "Voltar",
+        Text::Send => "Enviar",
+        Text::ReverseSort => "Inverter Ordem",
+        Text::SwitchCandidates => "Alternar Grupo/Todos os Campeões",
+        Text::Quit => "Sair",
+        Text::Help => "Ajuda",
+        Text::RuneDetails => "Detalhes das Runas",
+        Text::ItemDetails => "Detalhes do Item",
+        Text::ToggleSort => "Alternar Ordenação",
+        Text::TierList => "Tier List",
+        Text::Matchups => "Confrontos",
+        Text::CounterPicks => "Counters",
+        Text::PinBuild => "Fixar para Comparar",
+        Text::Compare => "Comparar com Fixado",
+        Text::RoleFilter => "Filtrar por Rota",
+        Text::ClassFilter => "Filtrar por Classe",
+        Text::ToggleWinRates => "Mostrar Taxa de Vitória",
+        Text::AbilityDetails => "Detalhes das Habilidades",
+        Text::NextSection => "Próxima Seção (Janela Pequena)",
+        Text::PrevSection => "Seção Anterior (Janela Pequena)",
+        Text::MoveUp => "Mover para Cima",
+        Text::MoveDown => "Mover para Baixo",
+        Text::MoveLeft => "Mover para a Esquerda",
+        Text::MoveRight => "Mover para a Direita",
+        Text::Pools => "Grupos de Campeões",
+        Text::PickFromPool => "Escolher do Grupo",
+        Text::ManageCache => "Gerenciar Cache",
+        Text::RunePaths => "Runas",
+        Text::ShardsAndSpells => "Fragmentos e Feitiços",
+        Text::AbilityOrder => "Ordem de Habilidades",
+        Text::Items => "Itens",
+        Text::StartingItems => "Itens Iniciais",
+        Text::CoreItems => "Itens Principais",
+        Text::FourthItems => "4º Item",
+        Text::FifthItems => "5º Item",
+        Text::SixthItems => "6º Item",
+        Text::SecondThirdItems => "2º/3º Itens",
+        Text::PrismaticItems => "Itens Prismáticos",
+        Text::Augments => "Aprimoramentos",
+        Text::SilverAugments => "Aprim. de Prata",
+        Text::GoldAugments => "Aprim. de Ouro",
+        Text::PrismaticAugments => "Aprim. Prismáticos",
+        Text::ChampSynergies => "Sinergias",
+        Text::Champions => "Campeões",
+        Text::GameMode => "Modo de Jogo",
+        Text::GameVersion => "Versão do Jogo",
+        Text::Region => "Região",
+        Text::Role => "Rota",
+        Text::OverviewKind => "Tipo de Build",
+        Text::BestMatchups => "Melhores Confrontos",
+        Text::WorstMatchups => "Piores Confrontos",
+        Text::NoResults => "Sem resultados :(",
+        Text::Selected => "Selecionado",
+        Text::Build => "Build",
+        Text::Mode => "Modo",
+        Text::Patch => "Patch",
+        Text::LowSampleSize => "Aviso: Amostra Pequena",
+        Text::Spells => "Feitiços:",
+        Text::TooSmall => "Redimensione a janela para pelo menos 40x16! Ctrl+Q para sair.",
+        Text::ShardOffense => "Ataque",
+        Text::ShardFlex => "Flex",
+        Text::ShardDefense => "Defesa",
+        Text::Total => "Total",
+        Text::Cost => "Custo",
+        Text::SellsFor => "vende por",
+        Text::Tags => "Categorias",
+        Text::BuildsFrom => "Componentes",
+        Text::MaxOrder => "Ordem de Maximização",
+        Text::Passive => "Passiva",
+        Text::Cooldown => "Recarga",
+        Text::SlotAlternatives => "Outras runas neste espaço",
+        Text::Champion => "Campeão",
+        Text::Tier => "Tier",
+        Text::WinRate => "Taxa de Vitória",
+        Text::PickRate => "Taxa de Escolha",
+        Text::BanRate => "Taxa de Banimento",
+        Text::Matches => "Partidas",
+        Text::TierListHint => "{left}/{right} Ordenar, {reverse_sort} Inverter, {select} Abrir",
+        Text::MatchupListHint => {
+            "Digite para Filtrar, {left}/{right} Ordenar, {reverse_sort} Inverter, {select} Abrir"
+        }
+        Text::CounterPicksHint => "{switch_candidates} Grupo/Todos os Campeões, {select} Abrir",
+        Text::AllChampions => "Todos os Campeões",
+        Text::Loading => "Carregando…",
+        Text::VsAverage => "vs Média",
+        Text::Pinned => "Fixado",
+        Text::Current => "Atual",
+        Text::CompareHint => "Itens Destacados Diferem, {pin_build} Fixar Atual, {back} Voltar",
+        Text::SortBy => "Ordenar",
+        Text::Popularity => "Popularidade",
+        Text::Retry => "Tentar novamente",
+        Text::Rank => "Elo",
+        Text::Cache => "Cache",
+        Text::Cached => "em cache",
+        Text::Live => "ao vivo",
+        Text::Client => "Cliente",
+        Text::Connected => "conectado",
+        Text::Disconnected => "desconectado",
+        Text::Favorites => "Favoritos",
+        Text::NoPools => "Adicione favoritos ou grupos ao config.toml",
+        Text::SharedFiles => "Compartilhado por Todos os Patches",
+        Text::Freed => "Liberado",
+        Text::RemovedPatches => "Patches Removidos",
+        Text::PruneCache => "Limpar, Patches a Manter",
+        Text::ClearCache => "Apagar Tudo",
+        Text::CacheHint => "{left}/{right} Patches a Manter, {select} Executar",
+        Text::ErrorNoData => "Sem dados de {mode} para {champ} no patch {patch}",
+        Text::ErrorTimedOut => "O u.gg demorou demais para carregar {champ}",
+        Text::ErrorOffline => "Não foi possível acessar o u.gg, verifique sua conexão",
+        Text::ErrorBadData => "O u.gg enviou dados de {champ} que não puderam ser lidos",
+        Text::ErrorOther => "Não foi possível carregar {champ}",
+        Text::Assassin => "Assassino",
+        Text::Fighter => "Lutador",
+        Text::Mage => "Mago",
+        Text::Marksman => "Atirador",
+        Text::SupportClass => "Suporte",
+        Text::Tank => "Tanque",
+        Text::ShardHealthScaling => "+10-180 de Vida",
+        Text::ShardArmor => "+6 de Armadura",
+        Text::ShardMagicResist => "+8 de Resist. Mágica",
+        Text::ShardAttackSpeed => "+10% de Vel. de Ataque",
+        Text::ShardAbilityHaste => "+8 de Acel. de Habilidade",
+        Text::ShardAdaptiveForce => "+9 de Força Adaptativa",
+        Text::ShardMoveSpeed => "+2% de Vel. de Movimento",
+        Text::ShardHealth => "65 de Vida",
+        Text::ShardTenacity => "+10% de Tenacidade e Resist. a Lentidão",
+        Text::Unknown => "Desconhecido",
+    }
+}
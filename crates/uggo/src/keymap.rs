@@ -0,0 +1,671 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use anyhow::{anyhow, bail};
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::i18n::Text;
+
+/// Everything a key can be bound to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Search,
+    ChampSelect,
+    ModeSelect,
+    RoleSelect,
+    VersionSelect,
+    RegionSelect,
+    BuildSelect,
+    RuneDetails,
+    ItemDetails,
+    ToggleSort,
+    AbilityDetails,
+    TierList,
+    Matchups,
+    CounterPicks,
+    PinBuild,
+    Compare,
+    RoleFilter,
+    ClassFilter,
+    ToggleWinRates,
+    Pools,
+    HideLeftPane,
+    Retry,
+    Cache,
+    NextSection,
+    PrevSection,
+    LogViewer,
+    Help,
+    Up,
+    Down,
+    Left,
+    Right,
+    Back,
+    Select,
+    ReverseSort,
+    SwitchCandidates,
+    Quit,
+}
+
+impl Action {
+    /// In the order they're listed in the help menu.
+    pub const ALL: [Self; 36] = [
+        Self::Search,
+        Self::ChampSelect,
+        Self::ModeSelect,
+        Self::RoleSelect,
+        Self::VersionSelect,
+        Self::RegionSelect,
+        Self::BuildSelect,
+        Self::RuneDetails,
+        Self::ItemDetails,
+        Self::ToggleSort,
+        Self::AbilityDetails,
+        Self::TierList,
+        Self::Matchups,
+        Self::CounterPicks,
+        Self::PinBuild,
+        Self::Compare,
+        Self::RoleFilter,
+        Self::ClassFilter,
+        Self::ToggleWinRates,
+        Self::Pools,
+        Self::HideLeftPane,
+        Self::Retry,
+        Self::Cache,
+        Self::NextSection,
+        Self::PrevSection,
+        Self::LogViewer,
+        Self::Help,
+        Self::Up,
+        Self::Down,
+        Self::Left,
+        Self::Right,
+        Self::Back,
+        Self::Select,
+        Self::ReverseSort,
+        Self::SwitchCandidates,
+        Self::Quit,
+    ];
+
+    /// The name used for the action in `config.toml`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Search => "search",
+            Self::ChampSelect => "champ_select",
+            Self::ModeSelect => "mode_select",
+            Self::RoleSelect => "role_select",
+            Self::VersionSelect => "version_select",
+            Self::RegionSelect => "region_select",
+            Self::BuildSelect => "build_select",
+            Self::RuneDetails => "rune_details",
+            Self::ItemDetails => "item_details",
+            Self::ToggleSort => "toggle_sort",
+            Self::AbilityDetails => "ability_details",
+            Self::TierList => "tier_list",
+            Self::Matchups => "matchups",
+            Self::CounterPicks => "counter_picks",
+            Self::PinBuild => "pin_build",
+            Self::Compare => "compare",
+            Self::RoleFilter => "role_filter",
+            Self::ClassFilter => "class_filter",
+            Self::ToggleWinRates => "toggle_win_rates",
+            Self::Pools => "pools",
+            Self::HideLeftPane => "hide_left_pane",
+            Self::Retry => "retry",
+            Self::Cache => "cache",
+            Self::NextSection => "next_section",
+            Self::PrevSection => "prev_section",
+            Self::LogViewer => "log_viewer",
+            Self::Help => "help",
+            Self::Up => "up",
+            Self::Down => "down",
+            Self::Left => "left",
+            Self::Right => "right",
+            Self::Back => "back",
+            Self::Select => "select",
+            Self::ReverseSort => "reverse_sort",
+            Self::SwitchCandidates => "switch_candidates",
+            Self::Quit => "quit",
+        }
+    }
+
+    pub const fn text(self) -> Text {
+        match self {
+            Self::Search => Text::Search,
+            Self::ChampSelect => Text::ChampSelect,
+            Self::ModeSelect => Text::ModeSelect,
+            Self::RoleSelect => Text::RoleSelect,
+            Self::VersionSelect => Text::VersionSelect,
+            Self::RegionSelect => Text::RegionSelect,
+            Self::BuildSelect => Text::BuildSelect,
+            Self::RuneDetails => Text::RuneDetails,
+            Self::ItemDetails => Text::ItemDetails,
+            Self::ToggleSort => Text::ToggleSort,
+            Self::AbilityDetails => Text::AbilityDetails,
+            Self::TierList => Text::TierList,
+            Self::Matchups => Text::Matchups,
+            Self::CounterPicks => Text::CounterPicks,
+            Self::PinBuild => Text::PinBuild,
+            Self::Compare => Text::Compare,
+            Self::RoleFilter => Text::RoleFilter,
+            Self::ClassFilter => Text::ClassFilter,
+            Self::ToggleWinRates => Text::ToggleWinRates,
+            Self::Pools => Text::Pools,
+            Self::HideLeftPane => Text::HideLeftPane,
+            Self::Retry => Text::Retry,
+            Self::Cache => Text::ManageCache,
+            Self::NextSection => Text::NextSection,
+            Self::PrevSection => Text::PrevSection,
+            Self::LogViewer => Text::LogViewer,
+            Self::Help => Text::Help,
+            Self::Up => Text::MoveUp,
+            Self::Down => Text::MoveDown,
+            Self::Left => Text::MoveLeft,
+            Self::Right => Text::MoveRight,
+            Self::Back => Text::Back,
+            Self::Select => Text::Send,
+            Self::ReverseSort => Text::ReverseSort,
+            Self::SwitchCandidates => Text::SwitchCandidates,
+            Self::Quit => Text::Quit,
+        }
+    }
+
+    /// Navigation is only handled inside lists and popups, so it can share
+    /// keys with the shortcuts used on the main screen.
+    const fn is_navigation(self) -> bool {
+        matches!(
+            self,
+            Self::Up | Self::Down | Self::Left | Self::Right | Self::Back | Self::Select
+        )
+    }
+
+    /// Actions that only do something inside a particular list or popup, so
+    /// they can share keys with main screen shortcuts and with each other.
+    const fn is_popup_only(self) -> bool {
+        matches!(self, Self::ReverseSort | Self::SwitchCandidates)
+    }
+
+    /// Whether the action is also handled in a list or popup, alongside
+    /// navigation.
+    const fn is_popup_key(self) -> bool {
+        self.is_popup_only() || matches!(self, Self::Search | Self::PinBuild)
+    }
+
+    /// Whether the two actions are ever active at the same time, so that
+    /// they can't share a key. Quit is active everywhere.
+    fn overlaps(self, other: Self) -> bool {
+        if self == Self::Quit || other == Self::Quit {
+            return true;
+        }
+        match (self.is_navigation(), other.is_navigation()) {
+            (true, true) => true,
+            (true, false) => other.is_popup_key(),
+            (false, true) => self.is_popup_key(),
+            (false, false) => !self.is_popup_only() && !other.is_popup_only(),
+        }
+    }
+
+    /// The key the list and popup handlers expect for navigation.
+    const fn navigation_code(self) -> Option<KeyCode> {
+        match self {
+            Self::Up => Some(KeyCode::Up),
+            Self::Down => Some(KeyCode::Down),
+            Self::Left => Some(KeyCode::Left),
+            Self::Right => Some(KeyCode::Right),
+            Self::Back => Some(KeyCode::Esc),
+            Self::Select => Some(KeyCode::Enter),
+            _ => None,
+        }
+    }
+}
+
+/// A key along with the modifiers that have to be held.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyBinding {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    const fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self { code, modifiers }
+    }
+
+    const fn key(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::NONE)
+    }
+
+    const fn char(c: char) -> Self {
+        Self::key(KeyCode::Char(c))
+    }
+
+    const fn alt(c: char) -> Self {
+        Self::new(KeyCode::Char(c), KeyModifiers::ALT)
+    }
+
+    /// Drops the differences terminals introduce, like Shift being reported
+    /// alongside an uppercase letter.
+    fn normalize(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let mut modifiers =
+            modifiers.intersection(KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT);
+        let code = match code {
+            KeyCode::Char(c) => {
+                modifiers.remove(KeyModifiers::SHIFT);
+                if modifiers.contains(KeyModifiers::CONTROL) {
+                    KeyCode::Char(c.to_ascii_lowercase())
+                } else {
+                    KeyCode::Char(c)
+                }
+            }
+            KeyCode::BackTab => {
+                modifiers.remove(KeyModifiers::SHIFT);
+                KeyCode::BackTab
+            }
+            code => code,
+        };
+        Self::new(code, modifiers)
+    }
+
+    /// Whether the key types a character when nothing else is held.
+    fn is_typed(self) -> bool {
+        matches!(self.code, KeyCode::Char(_))
+            && !self
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+    }
+
+    fn matches(self, key: &KeyEvent) -> bool {
+        Self::normalize(key.code, key.modifiers) == self
+    }
+
+    /// Parses a binding like `alt+s`, `ctrl+q`, `shift+tab`, `F5` or `/`.
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        // `+` on its own, or after a modifier, is the key rather than a separator.
+        let (modifiers, key) = match value.strip_suffix('+') {
+            Some("") => ("", "+"),
+            Some(rest) if rest.ends_with('+') => (&rest[..rest.len() - 1], "+"),
+            _ => value.rsplit_once('+').unwrap_or(("", value)),
+        };
+
+        let mut parsed = KeyModifiers::NONE;
+        for modifier in modifiers.split('+').filter(|m| !m.is_empty()) {
+            parsed |= match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" | "opt" | "option" | "meta" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return None,
+            };
+        }
+
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) if parsed.contains(KeyModifiers::SHIFT) => {
+                KeyCode::Char(c.to_ascii_uppercase())
+            }
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match key.to_ascii_lowercase().as_str() {
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "enter" | "return" => KeyCode::Enter,
+                "esc" | "escape" => KeyCode::Esc,
+                "tab" if parsed.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+                "tab" => KeyCode::Tab,
+                "backtab" => KeyCode::BackTab,
+                "space" => KeyCode::Char(' '),
+                "backspace" => KeyCode::Backspace,
+                "delete" | "del" => KeyCode::Delete,
+                "insert" => KeyCode::Insert,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                other => KeyCode::F(other.strip_prefix('f')?.parse().ok()?),
+            },
+        };
+        Some(Self::normalize(code, parsed))
+    }
+}
+
+impl Display for KeyBinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(
+                f,
+                "{}+",
+                if cfg!(target_os = "macos") {
+                    "Opt"
+                } else {
+                    "Alt"
+                }
+            )?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "Shift+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) if self.modifiers.contains(KeyModifiers::CONTROL) => {
+                write!(f, "{}", c.to_ascii_uppercase())
+            }
+            KeyCode::Char(c) => write!(f, "{c}"),
+            KeyCode::BackTab => write!(f, "Shift+Tab"),
+            KeyCode::Up => write!(f, "↑"),
+            KeyCode::Down => write!(f, "↓"),
+            KeyCode::Left => write!(f, "←"),
+            KeyCode::Right => write!(f, "→"),
+            code => write!(f, "{code}"),
+        }
+    }
+}
+
+/// Bindings every preset shares.
+fn common() -> Vec<(Action, KeyBinding)> {
+    vec![
+        (Action::Retry, KeyBinding::key(KeyCode::F(5))),
+        (Action::NextSection, KeyBinding::key(KeyCode::Tab)),
+        (Action::PrevSection, KeyBinding::key(KeyCode::BackTab)),
+        (Action::Help, KeyBinding::char('?')),
+        (Action::Up, KeyBinding::key(KeyCode::Up)),
+        (Action::Down, KeyBinding::key(KeyCode::Down)),
+        (Action::Left, KeyBinding::key(KeyCode::Left)),
+        (Action::Right, KeyBinding::key(KeyCode::Right)),
+        (Action::Back, KeyBinding::key(KeyCode::Esc)),
+        (Action::Select, KeyBinding::key(KeyCode::Enter)),
+        (Action::ReverseSort, KeyBinding::char('r')),
+        (Action::ReverseSort, KeyBinding::key(KeyCode::Tab)),
+        (Action::SwitchCandidates, KeyBinding::key(KeyCode::Tab)),
+        (
+            Action::Quit,
+            KeyBinding::new(KeyCode::Char('q'), KeyModifiers::CONTROL),
+        ),
+    ]
+}
+
+/// The letter each main screen shortcut uses in both presets.
+const SHORTCUT_LETTERS: [(Action, char); 22] = [
+    (Action::ChampSelect, 'c'),
+    (Action::ModeSelect, 'm'),
+    (Action::RoleSelect, 'r'),
+    (Action::VersionSelect, 'v'),
+    (Action::RegionSelect, 'w'),
+    (Action::BuildSelect, 'b'),
+    (Action::RuneDetails, 'u'),
+    (Action::ItemDetails, 'i'),
+    (Action::ToggleSort, 'o'),
+    (Action::AbilityDetails, 'a'),
+    (Action::TierList, 't'),
+    (Action::Matchups, 'x'),
+    (Action::CounterPicks, 'd'),
+    (Action::PinBuild, 'p'),
+    (Action::Compare, 'e'),
+    (Action::RoleFilter, 'f'),
+    (Action::ClassFilter, 'k'),
+    (Action::ToggleWinRates, 'n'),
+    (Action::Pools, 'g'),
+    (Action::HideLeftPane, 'h'),
+    (Action::Cache, 'z'),
+    (Action::LogViewer, 'l'),
+];
+
+/// Arrow keys to move around, and Alt+letter shortcuts so that typing
+/// anywhere on the main screen searches.
+fn arrows() -> Vec<(Action, KeyBinding)> {
+    let mut bindings = vec![(Action::Search, KeyBinding::alt('s'))];
+    bindings.extend(
+        SHORTCUT_LETTERS
+            .iter()
+            .map(|(action, c)| (*action, KeyBinding::alt(*c))),
+    );
+    bindings.extend(common());
+    bindings
+}
+
+/// hjkl to move around, `/` to search and plain letter shortcuts, for
+/// terminals where Alt is taken.
+fn vim() -> Vec<(Action, KeyBinding)> {
+    let mut bindings = vec![(Action::Search, KeyBinding::char('/'))];
+    bindings.extend(
+        SHORTCUT_LETTERS
+            .iter()
+            .map(|(action, c)| (*action, KeyBinding::char(*c))),
+    );
+    bindings.extend(common());
+    bindings.extend([
+        (Action::Up, KeyBinding::char('k')),
+        (Action::Down, KeyBinding::char('j')),
+        (Action::Left, KeyBinding::char('h')),
+        (Action::Right, KeyBinding::char('l')),
+    ]);
+    bindings
+}
+
+pub const PRESETS: [&str; 2] = ["arrows", "vim"];
+
+/// Which keys trigger which actions.
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: Vec<(Action, KeyBinding)>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self { bindings: arrows() }
+    }
+}
+
+impl Keymap {
+    /// Builds the keymap from a preset and `[keys]` overrides, where each
+    /// override replaces every binding the preset had for that action.
+    pub fn new(preset: Option<&str>, keys: &BTreeMap<String, Vec<String>>) -> anyhow::Result<Self> {
+        let mut bindings = match preset.map(str::to_ascii_lowercase).as_deref() {
+            None | Some("arrows" | "default") => arrows(),
+            Some("vim") => vim(),
+            Some(other) => bail!(
+                "Unknown keymap preset \"{other}\", expected one of {}",
+                PRESETS.join(", ")
+            ),
+        };
+
+        for (name, values) in keys {
+            let action = Action::ALL
+                .into_iter()
+                .find(|a| a.name() == name)
+                .ok_or_else(|| anyhow!("Unknown action \"{name}\" in [keys]"))?;
+            bindings.retain(|(a, _)| *a != action);
+            for value in values {
+                let binding = KeyBinding::parse(value).ok_or_else(|| {
+                    anyhow!("Invalid key \"{value}\" for {name} in [keys], expected something like alt+s, ctrl+q, F5 or /")
+                })?;
+                bindings.push((action, binding));
+            }
+        }
+
+        let keymap = Self { bindings };
+        keymap.check_conflicts()?;
+        Ok(keymap)
+    }
+
+    /// Two actions can only share a key when they're never active at the
+    /// same time, like navigation and main screen shortcuts.
+    fn check_conflicts(&self) -> anyhow::Result<()> {
+        for (i, (first, key)) in self.bindings.iter().enumerate() {
+            for (second, other) in &self.bindings[i + 1..] {
+                if key == other && first != second && first.overlaps(*second) {
+                    bail!(
+                        "{key} is bound to both {} and {} in [keys]",
+                        first.name(),
+                        second.name()
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn is(&self, action: Action, key: &KeyEvent) -> bool {
+        self.bindings
+            .iter()
+            .any(|(a, binding)| *a == action && binding.matches(key))
+    }
+
+    /// The main screen shortcut bound to `key`.
+    pub fn shortcut(&self, key: &KeyEvent) -> Option<Action> {
+        self.bindings
+            .iter()
+            .find(|(action, binding)| {
+                !action.is_navigation() && !action.is_popup_only() && binding.matches(key)
+            })
+            .map(|(action, _)| *action)
+    }
+
+    /// Rewrites a navigation key to the arrow, Esc or Enter key lists and
+    /// popups handle. While typing, only bindings that aren't plain letters
+    /// are used.
+    pub fn navigation(&self, key: KeyEvent, typing: bool) -> KeyEvent {
+        self.bindings
+            .iter()
+            .filter(|(action, binding)| action.is_navigation() && (!typing || !binding.is_typed()))
+            .find(|(_, binding)| binding.matches(&key))
+            .and_then(|(action, _)| action.navigation_code())
+            .map_or(key, |code| KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    /// Whether `key` triggers `action` in a list or popup, where plain letters
+    /// are typed instead while `typing`.
+    pub fn is_in_popup(&self, action: Action, key: &KeyEvent, typing: bool) -> bool {
+        self.bindings.iter().any(|(a, binding)| {
+            *a == action && (!typing || !binding.is_typed()) && binding.matches(key)
+        })
+    }
+
+    /// Fills in `{action}` placeholders in a hint, like `{select} Open`, with
+    /// the first key bound to that action. Plain letters are skipped while
+    /// `typing`, since they're typed instead.
+    pub fn hint(&self, text: &str, typing: bool) -> String {
+        let mut hint = text.to_string();
+        for action in Action::ALL {
+            let placeholder = format!("{{{}}}", action.name());
+            if hint.contains(&placeholder) {
+                let key = self
+                    .bindings
+                    .iter()
+                    .find(|(a, binding)| *a == action && (!typing || !binding.is_typed()))
+                    .map_or_else(|| "-".to_string(), |(_, binding)| binding.to_string());
+                hint = hint.replace(&placeholder, &key);
+            }
+        }
+        hint
+    }
+
+    /// Every key bound to `action`, for the help menu.
+    pub fn describe(&self, action: Action) -> String {
+        self.bindings
+            .iter()
+            .filter(|(a, _)| *a == action)
+            .map(|(_, binding)| binding.to_string())
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(pairs: &[(&str, &[&str])]) -> BTreeMap<String, Vec<String>> {
+        pairs
+            .iter()
+            .map(|(action, keys)| {
+                (
+                    (*action).to_string(),
+                    keys.iter().map(ToString::to_string).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parses_bindings() {
+        let parse = |v| KeyBinding::parse(v).unwrap();
+        assert_eq!(parse("alt+s"), KeyBinding::alt('s'));
+        assert_eq!(
+            parse("Ctrl+Q"),
+            KeyBinding::new(KeyCode::Char('q'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(parse("shift+tab"), KeyBinding::key(KeyCode::BackTab));
+        assert_eq!(parse("shift+t"), KeyBinding::char('T'));
+        assert_eq!(parse("F5"), KeyBinding::key(KeyCode::F(5)));
+        assert_eq!(parse("alt++"), KeyBinding::alt('+'));
+        assert_eq!(parse("+"), KeyBinding::char('+'));
+        assert!(KeyBinding::parse("hyper+s").is_none());
+        assert!(KeyBinding::parse("fx").is_none());
+    }
+
+    #[test]
+    fn presets_have_no_conflicts() {
+        for preset in PRESETS {
+            Keymap::new(Some(preset), &BTreeMap::new()).unwrap();
+        }
+        assert!(Keymap::new(Some("emacs"), &BTreeMap::new()).is_err());
+    }
+
+    #[test]
+    fn overrides_replace_preset_bindings() {
+        let keymap = Keymap::new(None, &keys(&[("tier_list", &["alt+j", "F2"])])).unwrap();
+        assert_eq!(
+            keymap.describe(Action::TierList),
+            format!("{} / F2", KeyBinding::alt('j'))
+        );
+
+        let alt_t = KeyEvent::new(KeyCode::Char('t'), KeyModifiers::ALT);
+        assert_eq!(keymap.shortcut(&alt_t), None);
+    }
+
+    #[test]
+    fn detects_conflicts() {
+        let err = Keymap::new(None, &keys(&[("tier_list", &["alt+s"])])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "{} is bound to both search and tier_list in [keys]",
+                KeyBinding::alt('s')
+            )
+        );
+
+        // Navigation and main screen shortcuts are never active together.
+        assert!(Keymap::new(Some("vim"), &keys(&[("up", &["t"])])).is_ok());
+        assert!(Keymap::new(None, &keys(&[("up", &["ctrl+q"])])).is_err());
+        assert!(Keymap::new(None, &keys(&[("unknown", &["x"])])).is_err());
+
+        // Popup keys can't clash with navigation, but each belongs to a
+        // different popup, so they can share with each other and shortcuts.
+        assert!(Keymap::new(None, &keys(&[("reverse_sort", &["up"])])).is_err());
+        assert!(Keymap::new(None, &keys(&[("pin_build", &["enter"])])).is_err());
+        assert!(Keymap::new(None, &keys(&[("reverse_sort", &["alt+t", "tab"])])).is_ok());
+    }
+
+    #[test]
+    fn fills_in_hints() {
+        let vim = Keymap::new(Some("vim"), &BTreeMap::new()).unwrap();
+        assert_eq!(
+            vim.hint("{left}/{right} Sort, {reverse_sort} Reverse", false),
+            "←/→ Sort, r Reverse"
+        );
+        assert_eq!(vim.hint("{reverse_sort} Reverse", true), "Tab Reverse");
+        assert_eq!(vim.hint("{pin_build} Pin", false), "p Pin");
+
+        let keymap = Keymap::new(None, &keys(&[("switch_candidates", &[])])).unwrap();
+        assert_eq!(keymap.hint("{switch_candidates} Switch", false), "- Switch");
+        let tab = KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE);
+        assert!(!keymap.is_in_popup(Action::SwitchCandidates, &tab, false));
+        assert!(keymap.is_in_popup(Action::ReverseSort, &tab, true));
+        assert_eq!(keymap.shortcut(&tab), Some(Action::NextSection));
+    }
+}
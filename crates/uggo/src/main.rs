@@ -8,8 +8,10 @@ use mimalloc::MiMalloc;
 #[global_allocator]
 static GLOBAL: MiMalloc = MiMalloc;
 
+use clap::Parser;
 use ratatui::crossterm::{
     ExecutableCommand,
+    event::{DisableMouseCapture, EnableMouseCapture},
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::prelude::*;
@@ -18,9 +20,16 @@ use std::io::stdout;
 #[cfg(debug_assertions)]
 use std::time::Instant;
 
+mod cli;
 mod components;
 mod context;
 mod events;
+mod i18n;
+mod keymap;
+mod mouse;
+mod pools;
+mod session;
+mod theme;
 mod transpose;
 mod ui;
 mod util;
@@ -43,24 +52,67 @@ const HIDE_TARGETS: [&str; 13] = [
     "ureq_proto::util",
 ];
 
+/// Puts the terminal back the way it was when dropped, so that errors and
+/// panics don't leave it in raw mode on the alternate screen.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> std::io::Result<Self> {
+        enable_raw_mode()?;
+        let guard = Self;
+        stdout().execute(EnterAlternateScreen)?;
+        stdout().execute(EnableMouseCapture)?;
+        Ok(guard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+        let _ = stdout().execute(DisableMouseCapture);
+        let _ = stdout().execute(LeaveAlternateScreen);
+    }
+}
+
 fn main() -> anyhow::Result<()> {
+    let cli = cli::Cli::parse();
+    let mut config = cli.load_config()?;
+    if let Some(locale) = config.locale() {
+        i18n::set_language(i18n::Language::from_locale(locale));
+    }
+    if let Some(command) = cli.command {
+        return command.run(&config);
+    }
+    config.restore_session();
+    let settings = config.settings();
+    let keymap = keymap::Keymap::new(settings.keymap.as_deref(), &settings.keys)?;
+    theme::set_theme(theme::Theme::new(
+        settings.theme.as_deref(),
+        &settings.colors,
+    )?);
+
     tui_logger::init_logger(log::LevelFilter::Trace)?;
     tui_logger::set_default_level(log::LevelFilter::Trace);
     for target in HIDE_TARGETS {
         tui_logger::set_level_for_target(target, log::LevelFilter::Error);
     }
 
-    enable_raw_mode()?;
-    stdout().execute(EnterAlternateScreen)?;
+    let guard = TerminalGuard::enter()?;
     let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
 
-    let mut app_context = AppContext::new()?;
+    let mut app_context = AppContext::new(config, keymap)?;
     let mut should_quit = false;
     while !should_quit {
         #[cfg(debug_assertions)]
         let start_render = Instant::now();
 
-        terminal.draw(|frame| ui::render(frame, &app_context))?;
+        app_context.update_client();
+        app_context.poll_champ_rankings();
+        app_context.poll_counter_picks();
+
+        let mut mouse_areas = mouse::MouseAreas::default();
+        terminal.draw(|frame| mouse_areas = ui::render(frame, &app_context))?;
+        app_context.set_mouse_areas(mouse_areas);
 
         #[cfg(debug_assertions)]
         app_context.set_render_duration(start_render.elapsed());
@@ -68,7 +120,7 @@ fn main() -> anyhow::Result<()> {
         should_quit = events::handle_events(&mut app_context)?;
     }
 
-    disable_raw_mode()?;
-    stdout().execute(LeaveAlternateScreen)?;
+    drop(guard);
+    app_context.save_session()?;
     Ok(())
 }
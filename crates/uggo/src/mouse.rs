@@ -0,0 +1,175 @@
+use ratatui::{
+    crossterm::event::KeyCode,
+    layout::{Position, Rect},
+    text::Line,
+};
+use ugg_types::mappings::{Build, Mode, Region, Role};
+
+use crate::context::{AppContext, State};
+use crate::events;
+
+/// Where the clickable parts of the last frame were drawn.
+#[derive(Debug, Clone, Default)]
+pub struct MouseAreas {
+    pub search: Rect,
+    /// The rows of the champion list, and how far it was scrolled.
+    pub champ_list: (Rect, usize),
+    /// Labels that open a popup when clicked.
+    pub labels: Vec<(Rect, State)>,
+    /// The rows of the open popup, and how far it was scrolled.
+    pub popup: Option<(Rect, usize)>,
+    /// The inside of each item column, in the order of `item_scroll_pos`.
+    pub item_columns: [Rect; 5],
+    /// The inside of each rune path, in the order of `rune_scroll_pos`.
+    pub rune_paths: [Rect; 2],
+}
+
+/// Lays `segments` out left to right from `(x, y)`, returning the area of
+/// every segment that opens a popup.
+pub fn label_areas(x: u16, y: u16, segments: &[(String, Option<State>)]) -> Vec<(Rect, State)> {
+    let mut x = x;
+    let mut areas = Vec::new();
+    for (text, state) in segments {
+        let width = u16::try_from(Line::from(text.as_str()).width()).unwrap_or(u16::MAX);
+        if let Some(state) = state {
+            areas.push((Rect::new(x, y, width, 1), *state));
+        }
+        x = x.saturating_add(width);
+    }
+    areas
+}
+
+/// The row under `position` in a list drawn in `area` and scrolled by `offset`.
+fn row_at(position: Position, (area, offset): (Rect, usize)) -> Option<usize> {
+    area.contains(position)
+        .then(|| offset + usize::from(position.y - area.y))
+}
+
+impl AppContext<'_> {
+    pub fn set_mouse_areas(&mut self, areas: MouseAreas) {
+        self.mouse_areas = areas;
+    }
+
+    /// Moves the open popup's selection, returning whether `index` exists.
+    fn set_popup_pos(&mut self, index: usize) -> bool {
+        let (pos, len) = match self.state {
+            State::PoolSelect => return self.set_pool_pos(index),
+            State::CacheMaintenance => return self.set_cache_pos(index),
+            State::ModeSelect => (&mut self.mode_scroll_pos, Mode::all().len()),
+            State::VersionSelect => (
+                &mut self.version_scroll_pos,
+                self.api.allowed_versions.len(),
+            ),
+            State::RegionSelect => (&mut self.region_scroll_pos, Region::all().len()),
+            State::RoleSelect => (&mut self.role_scroll_pos, Role::all().len()),
+            State::BuildSelect => (&mut self.build_scroll_pos, Build::all().len()),
+            _ => return false,
+        };
+        if index < len {
+            *pos = Some(index);
+        }
+        index < len
+    }
+
+    const fn champ_list_focused(&self) -> bool {
+        matches!(
+            self.state,
+            State::Initial | State::ChampSelected | State::TextInput | State::ChampScroll
+        )
+    }
+
+    fn item_at(&self, position: Position) -> Option<(usize, usize)> {
+        let (column, area) = self
+            .mouse_areas
+            .item_columns
+            .iter()
+            .enumerate()
+            .find(|(_, area)| area.contains(position))?;
+        self.item_row_at(column, position.y - area.y)
+            .map(|row| (column, row))
+    }
+
+    fn rune_at(&self, position: Position) -> Option<(usize, usize)> {
+        let paths = self.rune_paths()?;
+        let (path, area) = self
+            .mouse_areas
+            .rune_paths
+            .iter()
+            .enumerate()
+            .find(|(_, area)| area.contains(position))?;
+        let row = usize::from(position.y - area.y);
+        (row < paths[path].len()).then_some((path, row))
+    }
+
+    /// Scrolls the champion list under the cursor, or whatever list has focus.
+    pub fn on_mouse_scroll(&mut self, position: Position, down: bool) -> anyhow::Result<bool> {
+        if self.champ_list_focused() {
+            if self.mouse_areas.champ_list.0.contains(position) && !self.champ_list.is_empty() {
+                self.state = State::ChampScroll;
+                match self.champ_scroll_pos {
+                    None => self.champ_scroll_pos = Some(0),
+                    Some(_) if down => self.next_champ(),
+                    Some(_) => self.prev_champ(),
+                }
+            }
+            return Ok(false);
+        }
+        events::press(self, if down { KeyCode::Down } else { KeyCode::Up })
+    }
+
+    pub fn on_mouse_click(&mut self, position: Position) -> anyhow::Result<bool> {
+        if let Some(popup) = self.mouse_areas.popup {
+            // Clicking outside of an open popup closes it.
+            return match row_at(position, popup) {
+                Some(index) if self.set_popup_pos(index) => events::press(self, KeyCode::Enter),
+                Some(_) => Ok(false),
+                None => events::press(self, KeyCode::Esc),
+            };
+        }
+        if !self.champ_list_focused() {
+            return Ok(false);
+        }
+
+        if let Some(index) = row_at(position, self.mouse_areas.champ_list)
+            .filter(|index| *index < self.champ_list.len())
+        {
+            self.champ_scroll_pos = Some(index);
+            self.select_champ();
+        } else if self.mouse_areas.search.contains(position) {
+            self.go_to_search();
+        } else if let Some((_, state)) = self
+            .mouse_areas
+            .labels
+            .iter()
+            .find(|(area, _)| area.contains(position))
+        {
+            self.open_popup(*state);
+        }
+        Ok(false)
+    }
+
+    /// Shows the details of the item or rune under the cursor, and hides
+    /// them again once the cursor moves away.
+    pub fn on_mouse_hover(&mut self, position: Position) {
+        if !self.hover_detail && !matches!(self.state, State::Initial | State::ChampSelected) {
+            return;
+        }
+
+        if let Some(pos) = self.item_at(position) {
+            self.rune_scroll_pos = None;
+            self.item_scroll_pos = Some(pos);
+            self.state = State::ItemDetail;
+            self.hover_detail = true;
+        } else if let Some(pos) = self.rune_at(position) {
+            self.item_scroll_pos = None;
+            self.rune_scroll_pos = Some(pos);
+            self.state = State::RuneDetail;
+            self.hover_detail = true;
+        } else if self.hover_detail {
+            self.hover_detail = false;
+            self.item_scroll_pos = None;
+            self.rune_scroll_pos = None;
+            self.return_to_initial(false);
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use anyhow::anyhow;
+use ddragon::models::champions::ChampionShort;
+use ugg_types::mappings::Role;
+
+use crate::context::AppContext;
+use crate::i18n::{Text, tr};
+
+/// A pool from `config.toml` with its champions looked up.
+#[derive(Debug, Clone)]
+pub struct ChampionPool {
+    pub name: String,
+    pub role: Option<Role>,
+    pub champions: Vec<ChampionShort>,
+}
+
+impl AppContext<'_> {
+    fn find_champions(&self, names: &[String], origin: &str) -> anyhow::Result<Vec<ChampionShort>> {
+        names
+            .iter()
+            .map(|name| {
+                self.api.match_champ(name).cloned().ok_or_else(|| {
+                    anyhow!("Unknown champion \"{name}\" in {origin} in config.toml")
+                })
+            })
+            .collect()
+    }
+
+    /// Looks up the favorites and pools from the config. Favorites are
+    /// offered as a pool of their own, ahead of the rest.
+    pub fn load_pools(&mut self) -> anyhow::Result<()> {
+        let settings = self.config.settings();
+        let favorites = self.find_champions(&settings.favorites, "favorites")?;
+        let mut pools = Vec::new();
+        if !favorites.is_empty() {
+            pools.push(ChampionPool {
+                name: tr(Text::Favorites).to_string(),
+                role: None,
+                champions: favorites.clone(),
+            });
+        }
+        for pool in &settings.pools {
+            pools.push(ChampionPool {
+                name: pool.name.clone(),
+                role: pool.role,
+                champions: self
+                    .find_champions(&pool.champions, &format!("pool \"{}\"", pool.name))?,
+            });
+        }
+
+        self.favorites = favorites.into_iter().map(|c| c.id).collect();
+        self.pools = pools;
+        Ok(())
+    }
+
+    pub fn is_favorite(&self, champ: &ChampionShort) -> bool {
+        self.favorites.contains(&champ.id)
+    }
+
+    /// Where `champ` is pinned in the champion list, favorites first.
+    pub fn favorite_rank(&self, champ: &ChampionShort) -> usize {
+        self.favorites
+            .iter()
+            .position(|id| *id == champ.id)
+            .unwrap_or(usize::MAX)
+    }
+
+    /// The pool number keys pick from: the one last picked from, or else
+    /// the first one for the current role.
+    pub fn active_pool(&self) -> Option<usize> {
+        self.active_pool
+            .filter(|i| *i < self.pools.len())
+            .or_else(|| self.pools.iter().position(|p| p.role == Some(self.role)))
+            .or_else(|| (!self.pools.is_empty()).then_some(0))
+    }
+
+    /// Selects a champion from a pool, switching to the pool's role.
+    pub fn select_pool_champion(&mut self, pool: usize, index: usize) {
+        let Some(entry) = self.pools.get(pool) else {
+            return;
+        };
+        let Some(champ) = entry.champions.get(index).cloned() else {
+            return;
+        };
+        if let Some(role) = entry.role {
+            self.role = role;
+            self.match_pos_to_role();
+        }
+        self.active_pool = Some(pool);
+        self.select_champion(&champ);
+    }
+
+    /// Jumps to the `number`th champion of the active pool, counting from 1.
+    pub fn quick_switch(&mut self, number: usize) {
+        if let Some(pool) = self.active_pool()
+            && number > 0
+        {
+            self.select_pool_champion(pool, number - 1);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use uggo_config::{ConfigError, Session};
+
+use crate::context::AppContext;
+
+impl AppContext<'_> {
+    /// Re-selects the champion from the last session, if there was one.
+    pub fn restore_champion(&mut self) {
+        let Some(id) = self.config.session().champion.clone() else {
+            return;
+        };
+        if let Some(champ) = self.api.champ_data.values().find(|c| c.id == id).cloned() {
+            self.select_champion(&champ);
+        }
+    }
+
+    /// Saves what's on screen so the next start picks up where this one
+    /// left off.
+    pub fn save_session(&self) -> Result<(), ConfigError> {
+        // Only a patch that was picked is remembered, so a new patch is
+        // still used once it comes out.
+        let patch = self
+            .api
+            .allowed_versions
+            .iter()
+            .position(|v| v.ddragon == self.version)
+            .filter(|index| *index > 0)
+            .map(|index| self.api.allowed_versions[index].ugg.replace('_', "."));
+
+        self.config.save_session(&Session {
+            champion: self.selected_champ.as_ref().map(|c| c.id.clone()),
+            mode: Some(self.mode),
+            region: Some(self.region),
+            role: Some(self.role),
+            build: Some(self.build),
+            patch,
+            show_left_pane: Some(self.show_left_pane),
+        })
+    }
+}
@@ -0,0 +1,244 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use anyhow::{anyhow, bail};
+use ratatui::style::Color;
+
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+/// Environment variable that turns colours off, see <https://no-color.org>.
+const NO_COLOR_ENV: &str = "NO_COLOR";
+
+/// The colours used across the UI. Semantic colours come first; the rest
+/// are a palette for things that are simply told apart by colour, like
+/// rune trees, shards, spells and abilities.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Regular text.
+    pub text: Color,
+    /// Labels, hints and other secondary text.
+    pub muted: Color,
+    /// The selected entry, focused input and champion name.
+    pub accent: Color,
+    /// Things that stand out, like costs and differences between builds.
+    pub highlight: Color,
+    pub error: Color,
+    /// Behind popups.
+    pub background: Color,
+    /// Win rate scale, from clearly good to clearly bad.
+    pub positive: Color,
+    pub slightly_positive: Color,
+    pub slightly_negative: Color,
+    pub negative: Color,
+    pub red: Color,
+    pub green: Color,
+    pub yellow: Color,
+    pub blue: Color,
+    pub magenta: Color,
+    pub cyan: Color,
+}
+
+pub const PRESETS: [&str; 5] = ["dark", "light", "high-contrast", "colorblind", "none"];
+
+impl Theme {
+    /// The original colours, for dark terminals.
+    const DARK: Self = Self {
+        text: Color::White,
+        muted: Color::Gray,
+        accent: Color::Green,
+        highlight: Color::Yellow,
+        error: Color::Red,
+        background: Color::Black,
+        positive: Color::Green,
+        slightly_positive: Color::LightGreen,
+        slightly_negative: Color::Yellow,
+        negative: Color::Red,
+        red: Color::Red,
+        green: Color::Green,
+        yellow: Color::Yellow,
+        blue: Color::Blue,
+        magenta: Color::Magenta,
+        cyan: Color::Cyan,
+    };
+
+    /// Darker shades that stay readable on a light background.
+    const LIGHT: Self = Self {
+        text: Color::Black,
+        muted: Color::DarkGray,
+        accent: Color::Rgb(0, 120, 0),
+        highlight: Color::Rgb(160, 90, 0),
+        error: Color::Rgb(180, 0, 0),
+        background: Color::White,
+        positive: Color::Rgb(0, 120, 0),
+        slightly_positive: Color::Rgb(60, 140, 60),
+        slightly_negative: Color::Rgb(160, 90, 0),
+        negative: Color::Rgb(180, 0, 0),
+        red: Color::Rgb(180, 0, 0),
+        green: Color::Rgb(0, 120, 0),
+        yellow: Color::Rgb(160, 110, 0),
+        blue: Color::Rgb(0, 60, 180),
+        magenta: Color::Rgb(150, 0, 150),
+        cyan: Color::Rgb(0, 120, 140),
+    };
+
+    /// Bright colours only, with nothing dimmed.
+    const HIGH_CONTRAST: Self = Self {
+        text: Color::White,
+        muted: Color::White,
+        accent: Color::LightYellow,
+        highlight: Color::LightCyan,
+        error: Color::LightRed,
+        background: Color::Black,
+        positive: Color::LightGreen,
+        slightly_positive: Color::LightGreen,
+        slightly_negative: Color::LightYellow,
+        negative: Color::LightRed,
+        red: Color::LightRed,
+        green: Color::LightGreen,
+        yellow: Color::LightYellow,
+        blue: Color::LightBlue,
+        magenta: Color::LightMagenta,
+        cyan: Color::LightCyan,
+    };
+
+    /// The Okabe-Ito palette, which stays distinct with the common forms of
+    /// colour blindness. Good and bad are blue and orange instead of green
+    /// and red.
+    const COLORBLIND: Self = Self {
+        text: Color::White,
+        muted: Color::Gray,
+        accent: Color::Rgb(86, 180, 233),
+        highlight: Color::Rgb(240, 228, 66),
+        error: Color::Rgb(213, 94, 0),
+        background: Color::Black,
+        positive: Color::Rgb(0, 114, 178),
+        slightly_positive: Color::Rgb(86, 180, 233),
+        slightly_negative: Color::Rgb(230, 159, 0),
+        negative: Color::Rgb(213, 94, 0),
+        red: Color::Rgb(213, 94, 0),
+        green: Color::Rgb(0, 158, 115),
+        yellow: Color::Rgb(240, 228, 66),
+        blue: Color::Rgb(0, 114, 178),
+        magenta: Color::Rgb(204, 121, 167),
+        cyan: Color::Rgb(86, 180, 233),
+    };
+
+    /// The terminal's own colours everywhere, leaving bold, italics and
+    /// markers to tell things apart.
+    const NONE: Self = Self {
+        text: Color::Reset,
+        muted: Color::Reset,
+        accent: Color::Reset,
+        highlight: Color::Reset,
+        error: Color::Reset,
+        background: Color::Reset,
+        positive: Color::Reset,
+        slightly_positive: Color::Reset,
+        slightly_negative: Color::Reset,
+        negative: Color::Reset,
+        red: Color::Reset,
+        green: Color::Reset,
+        yellow: Color::Reset,
+        blue: Color::Reset,
+        magenta: Color::Reset,
+        cyan: Color::Reset,
+    };
+
+    /// Builds a theme from a preset and `[theme]` overrides. `NO_COLOR`
+    /// wins over both.
+    pub fn new(preset: Option<&str>, colors: &BTreeMap<String, String>) -> anyhow::Result<Self> {
+        let theme = Self::configured(preset, colors)?;
+        if std::env::var_os(NO_COLOR_ENV).is_some_and(|v| !v.is_empty()) {
+            return Ok(Self::NONE);
+        }
+        Ok(theme)
+    }
+
+    fn configured(preset: Option<&str>, colors: &BTreeMap<String, String>) -> anyhow::Result<Self> {
+        let mut theme = match preset.map(str::to_ascii_lowercase).as_deref() {
+            None | Some("dark") => Self::DARK,
+            Some("light") => Self::LIGHT,
+            Some("high-contrast" | "high_contrast") => Self::HIGH_CONTRAST,
+            Some("colorblind" | "colourblind") => Self::COLORBLIND,
+            Some("none") => Self::NONE,
+            Some(other) => bail!(
+                "Unknown theme \"{other}\", expected one of {}",
+                PRESETS.join(", ")
+            ),
+        };
+
+        for (name, value) in colors {
+            let color = Color::from_str(value).map_err(|_| {
+                anyhow!(
+                    "Invalid colour \"{value}\" for {name} in [theme], expected a name like light-red, a hex code like #ff8800 or a 256-colour index"
+                )
+            })?;
+            *theme
+                .color_mut(name)
+                .ok_or_else(|| anyhow!("Unknown colour \"{name}\" in [theme]"))? = color;
+        }
+        Ok(theme)
+    }
+
+    fn color_mut(&mut self, name: &str) -> Option<&mut Color> {
+        Some(match name {
+            "text" => &mut self.text,
+            "muted" => &mut self.muted,
+            "accent" => &mut self.accent,
+            "highlight" => &mut self.highlight,
+            "error" => &mut self.error,
+            "background" => &mut self.background,
+            "positive" => &mut self.positive,
+            "slightly_positive" => &mut self.slightly_positive,
+            "slightly_negative" => &mut self.slightly_negative,
+            "negative" => &mut self.negative,
+            "red" => &mut self.red,
+            "green" => &mut self.green,
+            "yellow" => &mut self.yellow,
+            "blue" => &mut self.blue,
+            "magenta" => &mut self.magenta,
+            "cyan" => &mut self.cyan,
+            _ => return None,
+        })
+    }
+}
+
+/// Sets the theme used for rendering. Only the first call has any effect.
+pub fn set_theme(theme: Theme) {
+    let _ = THEME.set(theme);
+}
+
+/// The active theme, or the dark one if none was set.
+pub fn theme() -> &'static Theme {
+    THEME.get().unwrap_or(&Theme::DARK)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies_overrides() {
+        let colors = BTreeMap::from([
+            ("negative".to_string(), "#d55e00".to_string()),
+            ("accent".to_string(), "light-blue".to_string()),
+        ]);
+        let theme = Theme::configured(Some("high-contrast"), &colors).unwrap();
+        assert_eq!(theme.negative, Color::Rgb(213, 94, 0));
+        assert_eq!(theme.accent, Color::LightBlue);
+        assert_eq!(theme.text, Theme::HIGH_CONTRAST.text);
+    }
+
+    #[test]
+    fn rejects_unknown_names() {
+        let none = BTreeMap::new();
+        assert!(Theme::configured(Some("solarized"), &none).is_err());
+
+        let unknown = BTreeMap::from([("border".to_string(), "red".to_string())]);
+        assert!(Theme::configured(None, &unknown).is_err());
+
+        let invalid = BTreeMap::from([("text".to_string(), "#12345".to_string())]);
+        assert!(Theme::configured(None, &invalid).is_err());
+    }
+}
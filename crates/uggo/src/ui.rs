@@ -1,38 +1,47 @@
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Margin, Rect},
-    style::{Color, Style, Stylize},
-    widgets::{Block, Clear, Paragraph},
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{Block, Clear, Paragraph, Wrap},
 };
 
 use ugg_types::{mappings::Mode, overview::Overview};
 
 use crate::components::{
-    ability_order, app_border, augments, build_select, champ_list, champ_name, champ_synergy,
-    items, matchups, mode_select, region_select, role_select, rune_path, search, shards, spells,
-    version_select,
+    ability_detail, ability_order, app_border, augments, build_select, cache_maintenance,
+    champ_list, champ_name, champ_synergy,
+    compact::{self, CompactPage},
+    compare, counter_picks, item_detail, items, matchup_list, matchups, mode_select, pool_select,
+    region_select, role_select, rune_detail, rune_path, search, shards, spells, status_bar,
+    tier_list, version_select,
 };
 
 use crate::context::{AppContext, State};
-
-const TOO_SMALL_MESSAGE: &str = "Please resize the window to at least 105x28! Ctrl+Q to exit.";
-#[allow(clippy::cast_possible_truncation)]
-const TOO_SMALL_MESSAGE_LENGTH: u16 = TOO_SMALL_MESSAGE.len() as u16;
+use crate::i18n::{Text, tr};
+use crate::mouse::{self, MouseAreas};
+use crate::theme::theme;
 
 macro_rules! show_list_popup {
-    ($frame:expr,$ui:expr,$layout:expr) => {
+    ($frame:expr,$ui:expr,$layout:expr,$areas:expr) => {
         let (list, mut list_state, minimum_area) = $ui;
         let safe_area = $layout.inner(Margin::new(
-            ($layout.width - minimum_area.width) / 2 - 1,
-            ($layout.height - minimum_area.height) / 2 - 1,
+            ($layout.width.saturating_sub(minimum_area.width) / 2).saturating_sub(1),
+            ($layout.height.saturating_sub(minimum_area.height) / 2).saturating_sub(1),
         ));
-        $frame.render_widget(Block::new().bg(Color::Black), $layout);
+        $frame.render_widget(Block::new().bg(theme().background), $layout);
         $frame.render_widget(Clear, safe_area);
         $frame.render_stateful_widget(list, safe_area.inner(Margin::new(1, 1)), &mut list_state);
+        $areas.popup = Some((safe_area.inner(Margin::new(2, 2)), list_state.offset()));
     };
 }
 
-fn render_default_overview(frame: &mut Frame, ctx: &AppContext, main_layout: Rect) {
+fn render_default_overview(
+    frame: &mut Frame,
+    ctx: &AppContext,
+    main_layout: Rect,
+    areas: &mut MouseAreas,
+) {
     if ctx.mode == Mode::Arena {
         return;
     }
@@ -70,6 +79,11 @@ fn render_default_overview(frame: &mut Frame, ctx: &AppContext, main_layout: Rec
                 champ_name::make(ctx, overview, selected),
                 overview_layout[0],
             );
+            areas.labels.extend(mouse::label_areas(
+                overview_layout[0].x,
+                overview_layout[0].y,
+                &champ_name::segments(ctx, selected),
+            ));
         }
 
         let item_columns = Layout::default()
@@ -90,7 +104,7 @@ fn render_default_overview(frame: &mut Frame, ctx: &AppContext, main_layout: Rec
             );
 
             frame.render_widget(
-                Paragraph::new(spells::make(ctx, &d.summoner_spells.spell_ids)),
+                Paragraph::new(spells::make(ctx, &d.summoner_spells, d.matches)),
                 Rect::new(
                     shard_ability_split[0].x + 1,
                     shard_ability_split[0].y + 4,
@@ -99,31 +113,49 @@ fn render_default_overview(frame: &mut Frame, ctx: &AppContext, main_layout: Rec
                 ),
             );
 
-            rune_path::make(d, &ctx.api.runes)
+            areas.rune_paths = [rune_split[0], rune_split[1]].map(|r| r.inner(Margin::new(1, 1)));
+            rune_path::make(d, &ctx.api.runes, ctx.rune_scroll_pos)
                 .into_iter()
                 .zip(rune_split.iter())
                 .for_each(|(w, r)| frame.render_widget(w, *r));
 
-            items::make_default(d, &ctx.api.items)
-                .into_iter()
-                .zip(item_columns.iter())
-                .for_each(|(w, r)| frame.render_widget(w, *r));
+            for (area, column) in areas.item_columns.iter_mut().zip(item_columns.iter()) {
+                *area = column.inner(Margin::new(1, 1));
+            }
+            items::make_default(
+                d,
+                &ctx.api.items,
+                ctx.item_scroll_pos,
+                ctx.build_sort,
+                &ctx.api.stat_settings,
+            )
+            .into_iter()
+            .zip(item_columns.iter())
+            .for_each(|(w, r)| frame.render_widget(w, *r));
         }
 
-        for (w, r) in ability_order::make(shard_ability_split[1].inner(Margin::new(1, 1)), overview)
-        {
+        for (w, r) in ability_order::make(
+            shard_ability_split[1].inner(Margin::new(1, 1)),
+            overview,
+            ctx.selected_champ_details.as_ref(),
+        ) {
             frame.render_widget(w, r);
         }
     }
 
     if let Some(matchups) = &ctx.selected_champ_matchups {
-        let [best, worst] = matchups::make(matchups, &ctx.champ_by_key);
+        let [best, worst] = matchups::make(matchups, &ctx.champ_by_key, &ctx.api.stat_settings);
         frame.render_widget(best, overview_layout[4]);
         frame.render_widget(worst, overview_layout[5]);
     }
 }
 
-fn render_arena_overview(frame: &mut Frame, ctx: &AppContext, main_layout: Rect) {
+fn render_arena_overview(
+    frame: &mut Frame,
+    ctx: &AppContext,
+    main_layout: Rect,
+    areas: &mut MouseAreas,
+) {
     if ctx.mode != Mode::Arena {
         return;
     }
@@ -171,6 +203,11 @@ fn render_arena_overview(frame: &mut Frame, ctx: &AppContext, main_layout: Rect)
                 champ_name::make(ctx, overview, selected),
                 overview_layout[0],
             );
+            areas.labels.extend(mouse::label_areas(
+                overview_layout[0].x,
+                overview_layout[0].y,
+                &champ_name::segments(ctx, selected),
+            ));
         }
         let augment_columns = Layout::default()
             .direction(Direction::Horizontal)
@@ -192,14 +229,25 @@ fn render_arena_overview(frame: &mut Frame, ctx: &AppContext, main_layout: Rect)
             .split(overview_layout[2]);
 
         if let Overview::Arena(d) = overview {
-            let augments = augments::make(d, &ctx.api.arena_augments);
+            let augments = augments::make(
+                d,
+                &ctx.api.arena_augments,
+                ctx.build_sort,
+                &ctx.api.stat_settings,
+            );
 
             augments
                 .into_iter()
                 .zip(augment_columns.iter())
                 .for_each(|(w, r)| frame.render_widget(w, *r));
 
-            let [regular @ .., prismatic] = items::make_arena(d, &ctx.api.items);
+            let [regular @ .., prismatic] = items::make_arena(
+                d,
+                &ctx.api.items,
+                ctx.item_scroll_pos,
+                ctx.build_sort,
+                &ctx.api.stat_settings,
+            );
 
             regular
                 .into_iter()
@@ -207,21 +255,143 @@ fn render_arena_overview(frame: &mut Frame, ctx: &AppContext, main_layout: Rect)
                 .for_each(|(w, r)| frame.render_widget(w, *r));
 
             frame.render_widget(prismatic, prismatic_synergies_layout[0]);
+            for (area, column) in areas
+                .item_columns
+                .iter_mut()
+                .zip(item_columns.iter().chain([&prismatic_synergies_layout[0]]))
+            {
+                *area = column.inner(Margin::new(1, 1));
+            }
             frame.render_widget(
-                champ_synergy::make(d, &ctx.champ_by_key),
+                champ_synergy::make(d, &ctx.champ_by_key, ctx.build_sort, &ctx.api.stat_settings),
                 prismatic_synergies_layout[1],
             );
         }
 
-        for (w, r) in
-            ability_order::make(ability_order_layout[0].inner(Margin::new(1, 1)), overview)
-        {
+        for (w, r) in ability_order::make(
+            ability_order_layout[0].inner(Margin::new(1, 1)),
+            overview,
+            ctx.selected_champ_details.as_ref(),
+        ) {
             frame.render_widget(w, r);
         }
     }
 }
 
-pub fn render(frame: &mut Frame, ctx: &AppContext) {
+fn render_champ_search(frame: &mut Frame, ctx: &AppContext, area: Rect, areas: &mut MouseAreas) {
+    let champion_search_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(area);
+
+    let (champ_list, mut champ_list_state) = champ_list::make(ctx);
+    frame.render_stateful_widget(champ_list, champion_search_layout[1], &mut champ_list_state);
+    areas.champ_list = (
+        champion_search_layout[1].inner(Margin::new(1, 1)),
+        champ_list_state.offset(),
+    );
+    areas.search = champion_search_layout[0];
+
+    if ctx.champ_list.is_empty() {
+        let text = Line::from(tr(Text::NoResults));
+        #[allow(clippy::cast_possible_truncation)]
+        let length = text.width() as u16;
+        let no_results_text = Paragraph::new(text).style(Style::default().fg(theme().error));
+        let no_results_offset = Rect::new(
+            champion_search_layout[1].x
+                + champion_search_layout[1].width.saturating_sub(length) / 2,
+            champion_search_layout[1].y + 2,
+            length.min(champion_search_layout[1].width),
+            1,
+        );
+        frame.render_widget(no_results_text, no_results_offset);
+    }
+
+    frame.render_widget(search::make(ctx), champion_search_layout[0]);
+}
+
+/// One section at a time, for terminals too small for the full overview.
+fn render_compact(frame: &mut Frame, ctx: &AppContext, area: Rect, areas: &mut MouseAreas) {
+    let compact_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // sections
+            Constraint::Length(1), // gap
+            Constraint::Min(0),    // page
+        ])
+        .split(area);
+    frame.render_widget(compact::make_tabs(ctx), compact_layout[0]);
+
+    let page = ctx.compact_page_shown();
+    if page == CompactPage::Champions {
+        render_champ_search(frame, ctx, compact_layout[2], areas);
+        return;
+    }
+    let (Some(overview), Some(selected)) = (&ctx.selected_champ_overview, &ctx.selected_champ)
+    else {
+        return;
+    };
+
+    let page_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // champ name
+            Constraint::Min(0),    // section
+        ])
+        .split(compact_layout[2]);
+    frame.render_widget(
+        Paragraph::new(
+            champ_name::segments(ctx, selected)
+                .into_iter()
+                .map(|(text, _)| text)
+                .collect::<String>(),
+        )
+        .style(Style::default().fg(theme().accent).bold())
+        .wrap(Wrap { trim: true }),
+        page_layout[0],
+    );
+
+    match (page, overview) {
+        (CompactPage::Runes, _) => {
+            frame.render_widget(compact::make_runes(ctx, overview), page_layout[1]);
+        }
+        (CompactPage::Items, _) => {
+            frame.render_widget(compact::make_items(ctx, overview), page_layout[1]);
+        }
+        (CompactPage::Abilities, _) => {
+            for (w, r) in ability_order::make(
+                page_layout[1],
+                overview,
+                ctx.selected_champ_details.as_ref(),
+            ) {
+                frame.render_widget(w, r.intersection(page_layout[1]));
+            }
+        }
+        (CompactPage::Matchups, Overview::Arena(d)) => {
+            frame.render_widget(
+                champ_synergy::make(d, &ctx.champ_by_key, ctx.build_sort, &ctx.api.stat_settings),
+                page_layout[1],
+            );
+        }
+        (CompactPage::Matchups, Overview::Default(_)) => {
+            if let Some(matchups) = &ctx.selected_champ_matchups {
+                let rows = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Length(2), Constraint::Length(2)])
+                    .split(page_layout[1]);
+                let [best, worst] =
+                    matchups::make(matchups, &ctx.champ_by_key, &ctx.api.stat_settings);
+                frame.render_widget(best, rows[0]);
+                frame.render_widget(worst, rows[1]);
+            }
+        }
+        (CompactPage::Champions, _) => {}
+    }
+}
+
+/// Draws a frame, returning where its clickable parts ended up.
+pub fn render(frame: &mut Frame, ctx: &AppContext) -> MouseAreas {
+    let mut areas = MouseAreas::default();
     let frame_size = frame.area();
 
     let app_border = Layout::default()
@@ -230,16 +400,32 @@ pub fn render(frame: &mut Frame, ctx: &AppContext) {
         .split(frame_size);
 
     frame.render_widget(app_border::make(ctx), app_border[0]);
+    let border_segments = app_border::title_segments(ctx);
+    #[allow(clippy::cast_possible_truncation)]
+    let border_title_width = border_segments
+        .iter()
+        .map(|(text, _)| Line::from(text.as_str()).width() as u16)
+        .sum::<u16>();
+    areas.labels = mouse::label_areas(
+        app_border[0].right().saturating_sub(border_title_width + 1),
+        app_border[0].bottom().saturating_sub(1),
+        &border_segments,
+    );
 
-    if frame_size.width <= 105 || frame_size.height <= 28 {
+    if frame_size.width < compact::MIN_WIDTH || frame_size.height < compact::MIN_HEIGHT {
+        let too_small_message = Line::from(tr(Text::TooSmall));
+        #[allow(clippy::cast_possible_truncation)]
+        let too_small_message_length = too_small_message.width() as u16;
         frame.render_widget(
-            Paragraph::new(TOO_SMALL_MESSAGE).style(Style::default().fg(Color::Red).bold()),
+            Paragraph::new(too_small_message)
+                .style(Style::default().fg(theme().error).bold())
+                .wrap(Wrap { trim: true }),
             app_border[0].inner(Margin::new(
-                (frame_size.width - TOO_SMALL_MESSAGE_LENGTH) / 2,
+                frame_size.width.saturating_sub(too_small_message_length) / 2 + 1,
                 frame_size.height / 2 - 1,
             )),
         );
-        return;
+        return MouseAreas::default();
     }
 
     if ctx.state == State::Logger {
@@ -250,74 +436,153 @@ pub fn render(frame: &mut Frame, ctx: &AppContext) {
             .split(app_border[0].inner(Margin::new(1, 1)));
         let logger = tui_logger::TuiLoggerSmartWidget::default().state(&ctx.logger_state);
         frame.render_widget(logger, container[0]);
-        return;
+        return areas;
     }
 
-    let main_layout = Layout::default()
+    // Popups and full screen views are drawn over this area.
+    let main_area = if frame_size.width <= 105 || frame_size.height <= 28 {
+        let compact_area = app_border[0].inner(Margin::new(2, 2));
+        render_compact(frame, ctx, compact_area, &mut areas);
+        compact_area
+    } else {
+        let main_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Length(if ctx.show_left_pane { 19 } else { 0 }),
+                Constraint::Min(0),
+            ])
+            .margin(1)
+            .split(app_border[0].inner(Margin::new(1, 1)));
+
+        render_champ_search(frame, ctx, main_layout[0], &mut areas);
+        match ctx.mode {
+            Mode::Arena => render_arena_overview(frame, ctx, main_layout[1], &mut areas),
+            _ => render_default_overview(frame, ctx, main_layout[1], &mut areas),
+        }
+        main_layout[1]
+    };
+
+    // The row between the main layout and the bottom border.
+    let status_row = app_border[0].inner(Margin::new(2, 1));
+    let status_row = Rect::new(status_row.x, status_row.bottom() - 1, status_row.width, 1);
+    let right_status = status_bar::make_right(ctx);
+    #[allow(clippy::cast_possible_truncation)]
+    let status_split = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
-            Constraint::Length(if ctx.show_left_pane { 19 } else { 0 }),
             Constraint::Min(0),
+            Constraint::Length(right_status.width() as u16),
         ])
-        .margin(1)
-        .split(app_border[0].inner(Margin::new(1, 1)));
+        .split(status_row);
+    frame.render_widget(status_bar::make_left(ctx), status_split[0]);
+    frame.render_widget(right_status, status_split[1]);
 
-    let champion_search_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Length(3), Constraint::Min(0)])
-        .split(main_layout[0]);
+    if ctx.state == State::ModeSelect {
+        show_list_popup!(frame, mode_select::make(ctx), main_area, areas);
+    }
 
-    let (champ_list, mut champ_list_state) = champ_list::make(ctx);
-    frame.render_stateful_widget(champ_list, champion_search_layout[1], &mut champ_list_state);
+    if ctx.state == State::VersionSelect {
+        show_list_popup!(frame, version_select::make(ctx), main_area, areas);
+    }
 
-    if ctx.champ_list.is_empty() {
-        let text = "No results :(";
-        let length = 13u16;
-        let no_results_text = Paragraph::new(text).style(Style::default().fg(Color::Red));
-        let no_results_offset = Rect::new(
-            champion_search_layout[1].x + (champion_search_layout[1].width - length) / 2,
-            champion_search_layout[1].y + 2,
-            length,
-            1,
-        );
-        frame.render_widget(no_results_text, no_results_offset);
+    if ctx.state == State::RegionSelect {
+        show_list_popup!(frame, region_select::make(ctx), main_area, areas);
     }
 
-    frame.render_widget(search::make(ctx), champion_search_layout[0]);
+    if ctx.state == State::RoleSelect {
+        show_list_popup!(frame, role_select::make(ctx), main_area, areas);
+    }
 
-    match ctx.mode {
-        Mode::Arena => render_arena_overview(frame, ctx, main_layout[1]),
-        _ => render_default_overview(frame, ctx, main_layout[1]),
+    if ctx.state == State::BuildSelect {
+        show_list_popup!(frame, build_select::make(ctx), main_area, areas);
     }
 
-    if ctx.state == State::ModeSelect {
-        show_list_popup!(frame, mode_select::make(ctx), main_layout[1]);
+    if ctx.state == State::PoolSelect {
+        show_list_popup!(frame, pool_select::make(ctx), main_area, areas);
     }
 
-    if ctx.state == State::VersionSelect {
-        show_list_popup!(frame, version_select::make(ctx), main_layout[1]);
+    if ctx.state == State::CacheMaintenance {
+        show_list_popup!(frame, cache_maintenance::make(ctx), main_area, areas);
     }
 
-    if ctx.state == State::RegionSelect {
-        show_list_popup!(frame, region_select::make(ctx), main_layout[1]);
+    if ctx.state == State::ItemDetail
+        && let Some((item_detail, minimum_area)) = item_detail::make(ctx)
+    {
+        let safe_area = main_area.inner(Margin::new(
+            main_area.width.saturating_sub(minimum_area.width) / 2,
+            main_area.height.saturating_sub(minimum_area.height) / 2,
+        ));
+        frame.render_widget(Clear, safe_area);
+        frame.render_widget(item_detail, safe_area);
     }
 
-    if ctx.state == State::RoleSelect {
-        show_list_popup!(frame, role_select::make(ctx), main_layout[1]);
+    if ctx.state == State::TierList {
+        let (table, mut table_state) = tier_list::make(ctx);
+        frame.render_widget(Clear, main_area);
+        frame.render_stateful_widget(table, main_area, &mut table_state);
     }
 
-    if ctx.state == State::BuildSelect {
-        show_list_popup!(frame, build_select::make(ctx), main_layout[1]);
+    if ctx.state == State::MatchupList {
+        let matchup_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(main_area);
+        let (table, mut table_state) = matchup_list::make(ctx);
+        frame.render_widget(Clear, main_area);
+        frame.render_widget(matchup_list::make_filter(ctx), matchup_layout[0]);
+        frame.render_stateful_widget(table, matchup_layout[1], &mut table_state);
+    }
+
+    if ctx.state == State::CounterPicks {
+        let (table, mut table_state) = counter_picks::make(ctx);
+        frame.render_widget(Clear, main_area);
+        frame.render_stateful_widget(table, main_area, &mut table_state);
+    }
+
+    if ctx.state == State::Compare
+        && let Some([pinned, current]) = compare::make(ctx)
+    {
+        let compare_split = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(main_area);
+        frame.render_widget(Clear, main_area);
+        frame.render_widget(pinned, compare_split[0]);
+        frame.render_widget(current, compare_split[1]);
+    }
+
+    if ctx.state == State::RuneDetail
+        && let Some((rune_detail, minimum_area)) = rune_detail::make(ctx)
+    {
+        let safe_area = main_area.inner(Margin::new(
+            main_area.width.saturating_sub(minimum_area.width) / 2,
+            main_area.height.saturating_sub(minimum_area.height) / 2,
+        ));
+        frame.render_widget(Clear, safe_area);
+        frame.render_widget(rune_detail, safe_area);
+    }
+
+    if ctx.state == State::AbilityDetail
+        && let Some((ability_detail, minimum_area)) = ability_detail::make(ctx)
+    {
+        let safe_area = main_area.inner(Margin::new(
+            main_area.width.saturating_sub(minimum_area.width) / 2,
+            main_area.height.saturating_sub(minimum_area.height) / 2,
+        ));
+        frame.render_widget(Clear, safe_area);
+        frame.render_widget(ability_detail, safe_area);
     }
 
     if ctx.state == State::HelpMenu {
-        let (help_menu, minimum_area) = crate::components::help_menu::make();
-        let safe_area = main_layout[1].inner(Margin::new(
-            (main_layout[1].width - minimum_area.width) / 2 - 1,
-            (main_layout[1].height - minimum_area.height) / 2 - 1,
+        let (help_menu, minimum_area) = crate::components::help_menu::make(ctx, main_area.height);
+        let safe_area = main_area.inner(Margin::new(
+            (main_area.width.saturating_sub(minimum_area.width) / 2).saturating_sub(1),
+            (main_area.height.saturating_sub(minimum_area.height) / 2).saturating_sub(1),
         ));
-        frame.render_widget(Block::new().bg(Color::Black), main_layout[1]);
+        frame.render_widget(Block::new().bg(theme().background), main_area);
         frame.render_widget(Clear, safe_area);
         frame.render_widget(help_menu, safe_area.inner(Margin::new(1, 1)));
     }
+
+    areas
 }
@@ -1,7 +1,17 @@
+#[cfg(test)]
+use ddragon::models::champions::ChampionShort;
 use ddragon::models::runes::RuneElement;
+use ratatui::{
+    style::{Color, Style},
+    text::Span,
+};
 use std::collections::HashMap;
 
-use ugg_types::rune::RuneExtended;
+use ugg_types::{rune::RuneExtended, stats::StatSettings};
+use uggo_ugg_api::CacheUsage;
+
+use crate::context::BuildSort;
+use crate::theme::theme;
 
 pub fn group_runes<'a>(
     rune_ids: &Vec<i64>,
@@ -39,3 +49,151 @@ pub fn generate_perk_array(
     perk_list.append(&mut shards.to_vec());
     (runes[0].1[0].parent_id, runes[1].1[0].parent_id, perk_list)
 }
+
+/// Converts ddragon's HTML-ish descriptions into plain text, turning line
+/// breaks into newlines and dropping every other tag.
+pub fn strip_html(text: &str) -> String {
+    let mut plain = String::with_capacity(text.len());
+    let mut tag = String::new();
+    let mut in_tag = false;
+
+    for c in text.chars() {
+        match c {
+            '<' => {
+                in_tag = true;
+                tag.clear();
+            }
+            '>' if in_tag => {
+                in_tag = false;
+                let name = tag.trim_matches('/').trim().to_lowercase();
+                if name == "br" || name == "li" || (name == "p" && !plain.is_empty()) {
+                    plain.push('\n');
+                }
+            }
+            _ if in_tag => tag.push(c),
+            _ => plain.push(c),
+        }
+    }
+
+    plain
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .lines()
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+#[allow(clippy::cast_precision_loss)]
+pub fn ratio(numerator: i64, denominator: i64) -> f64 {
+    if denominator <= 0 {
+        0.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
+
+pub fn win_rate_color(win_rate: f64) -> Color {
+    let theme = theme();
+    match win_rate {
+        wr if wr >= 0.53 => theme.positive,
+        wr if wr >= 0.51 => theme.slightly_positive,
+        wr if wr >= 0.49 => theme.text,
+        wr if wr >= 0.47 => theme.slightly_negative,
+        _ => theme.negative,
+    }
+}
+
+/// Renders a colour-coded win rate followed by the pick share among
+/// `total` games, e.g. `52.1% · 12.3%`.
+pub fn stat_spans<'a>(wins: i64, matches: i64, total: i64) -> Vec<Span<'a>> {
+    let win_rate = ratio(wins, matches);
+    vec![
+        Span::styled(
+            format!("{:.1}%", win_rate * 100.0),
+            Style::default().fg(win_rate_color(win_rate)),
+        ),
+        Span::styled(
+            format!(" · {:.1}%", ratio(matches, total) * 100.0),
+            Style::default().fg(theme().muted),
+        ),
+    ]
+}
+
+/// Sorts build entries best-first by either smoothed win rate or popularity,
+/// given a way to get each entry's `(wins, matches)`.
+pub fn sort_by_stat<T>(
+    entries: &mut [T],
+    sort: BuildSort,
+    settings: &StatSettings,
+    stat: impl Fn(&T) -> (i64, i64),
+) {
+    match sort {
+        BuildSort::WinRate => entries.sort_by(|a, b| {
+            let (a_wins, a_matches) = stat(a);
+            let (b_wins, b_matches) = stat(b);
+            settings
+                .smoothed_win_rate(b_wins, b_matches)
+                .total_cmp(&settings.smoothed_win_rate(a_wins, a_matches))
+        }),
+        BuildSort::Popularity => entries.sort_by_key(|e| std::cmp::Reverse(stat(e).1)),
+    }
+}
+
+/// Formats a byte count for people, e.g. `1.4 MB`.
+#[allow(clippy::cast_precision_loss)]
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+/// How many bytes the entries in `usage` take up together.
+pub fn cache_size<'a>(usage: impl Iterator<Item = &'a CacheUsage>) -> u64 {
+    usage.map(|u| u.bytes).sum()
+}
+
+/// A champion with only the fields uggo looks at filled in.
+#[cfg(test)]
+pub fn test_champion(id: &str, key: &str, name: &str) -> ChampionShort {
+    use ddragon::models::{champion::Info, shared::Image};
+
+    ChampionShort {
+        version: String::new(),
+        id: id.to_string(),
+        key: key.to_string(),
+        name: name.to_string(),
+        title: String::new(),
+        blurb: String::new(),
+        info: Info {
+            attack: 0,
+            defense: 0,
+            magic: 0,
+            difficulty: 0,
+        },
+        image: Image {
+            full: String::new(),
+            sprite: String::new(),
+            group: String::new(),
+            x: 0,
+            y: 0,
+            w: 0,
+            h: 0,
+        },
+        tags: Vec::new(),
+        partype: String::new(),
+        stats: HashMap::new(),
+    }
+}